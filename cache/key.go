@@ -16,18 +16,67 @@ import (
 )
 
 // GenerateKey generates a cache key for a file and tool combination.
+// It runs the tool to get its version; callers generating keys for many
+// files should probe the version once and use GenerateKeyWithVersion.
 func GenerateKey(filePath string, tool tools.QualityTool, options tools.ExecuteOptions) (CacheKey, error) {
+	toolVersion, err := tool.GetVersion()
+	if err != nil {
+		// If version cannot be determined, use "unknown"
+		// This will cause cache misses, which is safe
+		toolVersion = "unknown"
+	}
+
+	return GenerateKeyWithVersion(filePath, tool, toolVersion, options)
+}
+
+// GenerateKeyWithVersion generates a cache key for a file and tool
+// combination using an already known tool version.
+func GenerateKeyWithVersion(filePath string, tool tools.QualityTool, toolVersion string, options tools.ExecuteOptions) (CacheKey, error) {
 	// 1. Calculate file hash
 	fileHash, err := hashFile(filePath)
 	if err != nil {
 		return CacheKey{}, fmt.Errorf("failed to hash file %s: %w", filePath, err)
 	}
 
-	// 2. Get tool version
-	toolVersion, err := tool.GetVersion()
+	return toolKey(filePath, fileHash, tool, toolVersion, options)
+}
+
+// GenerateTaskKey generates a cache key for a whole task of a tool whose
+// result is not split by file (see tools.ProjectScoped). It covers the
+// contents of every file and of inputs such as lockfiles; missing inputs are
+// skipped, so creating one invalidates the key.
+func GenerateTaskKey(files, inputs []string, tool tools.QualityTool, toolVersion string, options tools.ExecuteOptions) (CacheKey, error) {
+	// 1. Calculate the combined hash of the task's files and inputs
+	sortedFiles := make([]string, len(files))
+	copy(sortedFiles, files)
+	sort.Strings(sortedFiles)
+
+	hasher := sha256.New()
+	for _, file := range sortedFiles {
+		fileHash, err := hashFile(file)
+		if err != nil {
+			return CacheKey{}, fmt.Errorf("failed to hash file %s: %w", file, err)
+		}
+		fmt.Fprintf(hasher, "file:%s=%s\n", file, fileHash)
+	}
+	inputsHash, err := hashFiles(inputs)
 	if err != nil {
-		// If version cannot be determined, use "unknown"
-		// This will cause cache misses, which is safe
+		return CacheKey{}, fmt.Errorf("failed to hash project inputs: %w", err)
+	}
+	fmt.Fprintf(hasher, "inputs:%s", inputsHash)
+
+	root := options.ProjectRoot
+	if root == "" {
+		root = "."
+	}
+	return toolKey(root, hex.EncodeToString(hasher.Sum(nil)), tool, toolVersion, options)
+}
+
+// toolKey completes a cache key for path, whose content hashes to contentHash,
+// with the tool's version, configuration and options.
+func toolKey(path, contentHash string, tool tools.QualityTool, toolVersion string, options tools.ExecuteOptions) (CacheKey, error) {
+	// 2. Tool version; empty is treated like an unknown version
+	if toolVersion == "" {
 		toolVersion = "unknown"
 	}
 
@@ -38,18 +87,22 @@ func GenerateKey(filePath string, tool tools.QualityTool, options tools.ExecuteO
 		return CacheKey{}, fmt.Errorf("failed to hash config files: %w", err)
 	}
 
-	// 4. Calculate options hash
+	// 4. Calculate options hash, including the tool's own settings
 	optionsHash := hashOptions(options)
+	if configurable, ok := tool.(tools.Configurable); ok {
+		sum := sha256.Sum256([]byte(optionsHash + "|" + configurable.SettingsDigest()))
+		optionsHash = hex.EncodeToString(sum[:])
+	}
 
-	// 5. Get absolute file path
-	absFilePath, err := filepath.Abs(filePath)
+	// 5. Get absolute path
+	absPath, err := filepath.Abs(path)
 	if err != nil {
-		absFilePath = filePath
+		absPath = path
 	}
 
 	return CacheKey{
-		FilePath:    absFilePath,
-		FileHash:    fileHash,
+		FilePath:    absPath,
+		FileHash:    contentHash,
 		ToolName:    tool.Name(),
 		ToolVersion: toolVersion,
 		ConfigHash:  configHash,
@@ -123,6 +176,16 @@ func hashOptions(options tools.ExecuteOptions) string {
 		hasher.Write([]byte("lint-only:true"))
 	}
 
+	// Check flag
+	if options.Check {
+		hasher.Write([]byte("check:true"))
+	}
+
+	// Base commit
+	if options.BaseRef != "" {
+		hasher.Write([]byte("base:" + options.BaseRef))
+	}
+
 	// ExtraArgs (sorted for determinism)
 	if len(options.ExtraArgs) > 0 {
 		sortedArgs := make([]string, len(options.ExtraArgs))
@@ -80,6 +80,108 @@ func TestGenerateKey(t *testing.T) {
 	}
 }
 
+func TestGenerateKeyWithVersion(t *testing.T) {
+	tmpDir := t.TempDir()
+	testFile := filepath.Join(tmpDir, "test.go")
+	if err := os.WriteFile(testFile, []byte("package main\n"), 0644); err != nil {
+		t.Fatalf("Failed to create test file: %v", err)
+	}
+
+	// The given version is used instead of asking the tool
+	tool := &mockTool{name: "gofumpt", version: "v0.7.0"}
+	key, err := GenerateKeyWithVersion(testFile, tool, "0.6.0", tools.ExecuteOptions{})
+	if err != nil {
+		t.Fatalf("GenerateKeyWithVersion failed: %v", err)
+	}
+	if key.ToolVersion != "0.6.0" {
+		t.Errorf("ToolVersion = %s, want 0.6.0", key.ToolVersion)
+	}
+
+	key, err = GenerateKeyWithVersion(testFile, tool, "", tools.ExecuteOptions{})
+	if err != nil {
+		t.Fatalf("GenerateKeyWithVersion failed: %v", err)
+	}
+	if key.ToolVersion != "unknown" {
+		t.Errorf("ToolVersion = %s, want unknown", key.ToolVersion)
+	}
+}
+
+// configurableTool is a mockTool with settings from the gz-quality config.
+type configurableTool struct {
+	mockTool
+	digest string
+}
+
+func (m *configurableTool) SettingsDigest() string { return m.digest }
+
+func TestGenerateKeyWithVersion_Settings(t *testing.T) {
+	tmpDir := t.TempDir()
+	testFile := filepath.Join(tmpDir, "lib.rs")
+	if err := os.WriteFile(testFile, []byte("pub fn f() {}\n"), 0644); err != nil {
+		t.Fatalf("Failed to create test file: %v", err)
+	}
+
+	// Changing a tool's own settings changes its cache keys
+	hashes := make(map[string]bool)
+	for _, digest := range []string{"deny:[warnings]", "deny:[clippy::pedantic]"} {
+		tool := &configurableTool{mockTool: mockTool{name: "clippy"}, digest: digest}
+		key, err := GenerateKeyWithVersion(testFile, tool, "0.1.80", tools.ExecuteOptions{})
+		if err != nil {
+			t.Fatalf("GenerateKeyWithVersion failed: %v", err)
+		}
+		hashes[key.OptionsHash] = true
+	}
+	if len(hashes) != 2 {
+		t.Errorf("expected different options hashes for different settings")
+	}
+}
+
+func TestGenerateTaskKey(t *testing.T) {
+	tmpDir := t.TempDir()
+	lib := filepath.Join(tmpDir, "lib.rs")
+	main := filepath.Join(tmpDir, "main.rs")
+	lock := filepath.Join(tmpDir, "Cargo.lock")
+	for path, content := range map[string]string{lib: "pub fn f() {}\n", main: "fn main() {}\n", lock: "version = 3\n"} {
+		if err := os.WriteFile(path, []byte(content), 0644); err != nil {
+			t.Fatalf("Failed to create %s: %v", path, err)
+		}
+	}
+
+	tool := &mockTool{name: "cargo-audit"}
+	options := tools.ExecuteOptions{ProjectRoot: tmpDir}
+	key := func(files ...string) CacheKey {
+		t.Helper()
+		key, err := GenerateTaskKey(files, []string{lock, filepath.Join(tmpDir, "missing.lock")}, tool, "0.21.0", options)
+		if err != nil {
+			t.Fatalf("GenerateTaskKey failed: %v", err)
+		}
+		return key
+	}
+
+	first := key(lib, main)
+	if first.FilePath != tmpDir {
+		t.Errorf("expected the task key to point at the project root, got %s", first.FilePath)
+	}
+	if key(main, lib) != first {
+		t.Errorf("expected the key not to depend on file order")
+	}
+	if key(lib) == first {
+		t.Errorf("expected a different key for different files")
+	}
+
+	// A changed lockfile invalidates the task's key
+	if err := os.WriteFile(lock, []byte("version = 4\n"), 0644); err != nil {
+		t.Fatalf("Failed to update lockfile: %v", err)
+	}
+	if key(lib, main) == first {
+		t.Errorf("expected a different key after the lockfile changed")
+	}
+
+	if _, err := GenerateTaskKey([]string{filepath.Join(tmpDir, "gone.rs")}, nil, tool, "0.21.0", options); err == nil {
+		t.Errorf("expected an error for a missing task file")
+	}
+}
+
 func TestGenerateKey_DifferentContent(t *testing.T) {
 	tmpDir := t.TempDir()
 
@@ -316,6 +418,18 @@ func TestHashOptions(t *testing.T) {
 			opt2: tools.ExecuteOptions{Fix: false},
 			same: false,
 		},
+		{
+			name: "different check flag",
+			opt1: tools.ExecuteOptions{Check: true},
+			opt2: tools.ExecuteOptions{},
+			same: false,
+		},
+		{
+			name: "different base ref",
+			opt1: tools.ExecuteOptions{BaseRef: "0a1b2c"},
+			opt2: tools.ExecuteOptions{BaseRef: "3d4e5f"},
+			same: false,
+		},
 		{
 			name: "different extra args",
 			opt1: tools.ExecuteOptions{ExtraArgs: []string{"--verbose"}},
@@ -103,8 +103,11 @@ func (cm *CacheManager) Set(key CacheKey, result *tools.Result) error {
 		return fmt.Errorf("invalid cache key: %w", err)
 	}
 
-	// Only cache successful results
-	if !result.Success {
+	// Only cache completed runs (clean or findings); tool errors, timeouts
+	// and skipped tools must be retried next time
+	switch result.EffectiveStatus() {
+	case tools.StatusClean, tools.StatusIssues:
+	default:
 		return nil
 	}
 
@@ -310,6 +310,53 @@ func TestCacheManager_OnlySuccessful(t *testing.T) {
 	}
 }
 
+func TestCacheManager_FindingsCachedFailuresNot(t *testing.T) {
+	tmpDir := t.TempDir()
+	cacheDir := filepath.Join(tmpDir, "cache")
+	filesDir := filepath.Join(tmpDir, "files")
+	os.MkdirAll(filesDir, 0755)
+
+	manager, err := NewCacheManager(cacheDir, 100*1024*1024, 24*time.Hour)
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer manager.Close()
+
+	testFile := filepath.Join(filesDir, "test.go")
+	os.WriteFile(testFile, []byte("package main"), 0644)
+
+	tool := &mockTool{name: "golangci-lint", version: "v1.61.0"}
+	key, _ := GenerateKey(testFile, tool, tools.ExecuteOptions{})
+
+	tests := []struct {
+		status tools.Status
+		cached bool
+	}{
+		{tools.StatusClean, true},
+		{tools.StatusIssues, true},
+		{tools.StatusToolError, false},
+		{tools.StatusTimeout, false},
+		{tools.StatusCancelled, false},
+		{tools.StatusSkipped, false},
+	}
+
+	for _, tt := range tests {
+		t.Run(string(tt.status), func(t *testing.T) {
+			_ = manager.Invalidate(key)
+
+			result := &tools.Result{Success: !tt.status.IsFailure(), Status: tt.status}
+			if err := manager.Set(key, result); err != nil {
+				t.Fatal(err)
+			}
+
+			_, err := manager.Get(key)
+			if got := err == nil; got != tt.cached {
+				t.Errorf("status %s: cached = %v, want %v", tt.status, got, tt.cached)
+			}
+		})
+	}
+}
+
 func TestCacheManager_Disabled(t *testing.T) {
 	manager := NewDisabledCacheManager()
 
@@ -7,8 +7,12 @@ import (
 	"fmt"
 	"os"
 	"path/filepath"
+	"strings"
+	"time"
 
 	yaml "gopkg.in/yaml.v3"
+
+	"github.com/Gizzahub/gzh-cli-quality/internal/semver"
 )
 
 // Config represents the quality command configuration.
@@ -33,6 +37,57 @@ type Config struct {
 
 	// Include contains patterns to include in processing
 	Include []string `yaml:"include"`
+
+	// CustomTools declares additional tools without writing Go code
+	CustomTools []CustomToolConfig `yaml:"custom_tools"`
+
+	// PluginDirs lists directories searched for plugin executables
+	PluginDirs []string `yaml:"plugin_dirs"`
+
+	// VersionIndex is a YAML file listing the latest known version of each tool
+	VersionIndex string `yaml:"version_index"`
+
+	// Rust configures lint levels, targets and features of the cargo-based tools
+	Rust RustConfig `yaml:"rust"`
+}
+
+// RustConfig represents Rust-specific configuration.
+type RustConfig struct {
+	// Deny, Warn and Allow list lints passed to clippy as -D, -W and -A
+	// (e.g., "warnings", "clippy::pedantic", "unsafe_code")
+	Deny  []string `yaml:"deny"`
+	Warn  []string `yaml:"warn"`
+	Allow []string `yaml:"allow"`
+
+	// AllTargets also lints tests, benches and examples (--all-targets)
+	AllTargets bool `yaml:"all_targets"`
+
+	// AllFeatures, NoDefaultFeatures and Features select the features of a single run
+	AllFeatures       bool     `yaml:"all_features"`
+	NoDefaultFeatures bool     `yaml:"no_default_features"`
+	Features          []string `yaml:"features"`
+
+	// FeatureMatrix runs clippy once per feature set and merges the findings
+	FeatureMatrix []RustFeatureSet `yaml:"feature_matrix"`
+
+	// MissingDocs makes the rustdoc tool deny undocumented public items
+	MissingDocs bool `yaml:"missing_docs"`
+
+	// AdvisoryDB is a locally stored RustSec advisory database cargo-audit
+	// and cargo-deny use without fetching, for offline runs
+	AdvisoryDB string `yaml:"advisory_db"`
+}
+
+// RustFeatureSet is one entry of the Rust feature matrix.
+type RustFeatureSet struct {
+	// AllFeatures enables every feature (--all-features)
+	AllFeatures bool `yaml:"all_features"`
+
+	// NoDefaultFeatures disables the default feature (--no-default-features)
+	NoDefaultFeatures bool `yaml:"no_default_features"`
+
+	// Features lists features to enable (--features)
+	Features []string `yaml:"features"`
 }
 
 // CacheConfig represents cache configuration.
@@ -66,6 +121,12 @@ type ToolConfig struct {
 
 	// Priority affects execution order (higher = earlier)
 	Priority int `yaml:"priority"`
+
+	// Timeout overrides the global timeout for this tool's tasks (e.g., "30s", "15m")
+	Timeout string `yaml:"timeout"`
+
+	// Version is a semver constraint the installed tool must satisfy (e.g., ">=0.6, <0.7", "^1.55")
+	Version string `yaml:"version"`
 }
 
 // LanguageConfig represents configuration for a language.
@@ -80,6 +141,65 @@ type LanguageConfig struct {
 	Extensions []string `yaml:"extensions"`
 }
 
+// CustomToolConfig declares a tool that is registered alongside the built-in ones.
+type CustomToolConfig struct {
+	// Name is the tool name used in output and in the tools section
+	Name string `yaml:"name"`
+
+	// Language is the language the tool handles (e.g., "Go", "Shell")
+	Language string `yaml:"language"`
+
+	// Type is "format", "lint" or "both" (default "lint")
+	Type string `yaml:"type"`
+
+	// Executable is the command to run
+	Executable string `yaml:"executable"`
+
+	// Args is the argument template; "{files}", "{fix}" and "{config}" expand
+	// to the files, FixArgs and ConfigArgs, and "{config_file}" and
+	// "{project_root}" are replaced inside any argument
+	Args []string `yaml:"args"`
+
+	// FixArgs are inserted at "{fix}" when fixing
+	FixArgs []string `yaml:"fix_args"`
+
+	// ConfigArgs are inserted at "{config}" when a config file is found
+	ConfigArgs []string `yaml:"config_args"`
+
+	// Extensions lists the file extensions the tool processes
+	Extensions []string `yaml:"extensions"`
+
+	// ConfigPatterns lists config file names the tool looks for
+	ConfigPatterns []string `yaml:"config_patterns"`
+
+	// FindingExitCodes lists exit codes that mean "issues found" rather than failure
+	FindingExitCodes []int `yaml:"finding_exit_codes"`
+
+	// Install is the command that installs the tool
+	Install []string `yaml:"install"`
+
+	// Output describes how to parse the tool's output
+	Output CustomOutputConfig `yaml:"output"`
+}
+
+// CustomOutputConfig describes a custom tool's output format.
+type CustomOutputConfig struct {
+	// Format is "regex", "json" or "sarif"
+	Format string `yaml:"format"`
+
+	// Pattern is the regex with named groups (file, line, column, severity, rule, message)
+	Pattern string `yaml:"pattern"`
+
+	// Severity is the default severity when none is captured
+	Severity string `yaml:"severity"`
+
+	// Root is the dot path to the issue array in JSON output
+	Root string `yaml:"root"`
+
+	// Fields maps issue fields to dot paths inside each JSON issue
+	Fields map[string]string `yaml:"fields"`
+}
+
 // DefaultConfig returns a default configuration.
 func DefaultConfig() *Config {
 	return &Config{
@@ -189,6 +309,22 @@ func LoadConfig(configPath string) (*Config, error) {
 		return nil, fmt.Errorf("failed to parse config file %s: %w", configPath, err)
 	}
 
+	if err := config.validateTimeouts(); err != nil {
+		return nil, fmt.Errorf("invalid config file %s: %w", configPath, err)
+	}
+
+	if err := config.validateCustomTools(); err != nil {
+		return nil, fmt.Errorf("invalid config file %s: %w", configPath, err)
+	}
+
+	if err := config.validateVersions(); err != nil {
+		return nil, fmt.Errorf("invalid config file %s: %w", configPath, err)
+	}
+
+	if err := config.validateRust(); err != nil {
+		return nil, fmt.Errorf("invalid config file %s: %w", configPath, err)
+	}
+
 	return config, nil
 }
 
@@ -274,6 +410,162 @@ func (c *Config) GetLanguageConfig(language string) LanguageConfig {
 	}
 }
 
+// GetTimeout returns the global tool execution timeout, or defaultVal if unset.
+func (c *Config) GetTimeout(defaultVal time.Duration) time.Duration {
+	if c.Timeout == "" {
+		return defaultVal
+	}
+	d, err := ParseDuration(c.Timeout)
+	if err != nil {
+		return defaultVal
+	}
+	return d
+}
+
+// GetToolTimeout returns the timeout configured for a specific tool.
+// Zero means the tool has no timeout of its own and only the global one applies.
+func (c *Config) GetToolTimeout(toolName string) time.Duration {
+	timeout := c.GetToolConfig(toolName).Timeout
+	if timeout == "" {
+		return 0
+	}
+	d, err := ParseDuration(timeout)
+	if err != nil {
+		return 0
+	}
+	return d
+}
+
+// ToolTimeouts returns the per-tool timeouts keyed by tool name.
+func (c *Config) ToolTimeouts() map[string]time.Duration {
+	timeouts := make(map[string]time.Duration)
+	for name := range c.Tools {
+		if d := c.GetToolTimeout(name); d > 0 {
+			timeouts[name] = d
+		}
+	}
+	return timeouts
+}
+
+// validateTimeouts checks that the global and per-tool timeouts parse.
+func (c *Config) validateTimeouts() error {
+	if c.Timeout != "" {
+		if _, err := ParseDuration(c.Timeout); err != nil {
+			return fmt.Errorf("timeout: %w", err)
+		}
+	}
+	for name, tool := range c.Tools {
+		if tool.Timeout == "" {
+			continue
+		}
+		if _, err := ParseDuration(tool.Timeout); err != nil {
+			return fmt.Errorf("tools.%s.timeout: %w", name, err)
+		}
+	}
+	return nil
+}
+
+// validateVersions checks that the per-tool version constraints parse.
+func (c *Config) validateVersions() error {
+	for name, tool := range c.Tools {
+		if tool.Version == "" {
+			continue
+		}
+		if _, err := semver.ParseConstraint(tool.Version); err != nil {
+			return fmt.Errorf("tools.%s.version: %w", name, err)
+		}
+	}
+	return nil
+}
+
+// validateRust checks the lint names and feature sets of the rust section.
+func (c *Config) validateRust() error {
+	levels := []struct {
+		name  string
+		lints []string
+	}{
+		{"deny", c.Rust.Deny},
+		{"warn", c.Rust.Warn},
+		{"allow", c.Rust.Allow},
+	}
+	for _, level := range levels {
+		for _, lint := range level.lints {
+			if lint == "" || strings.HasPrefix(lint, "-") || strings.ContainsAny(lint, " \t") {
+				return fmt.Errorf("rust.%s: invalid lint name %q", level.name, lint)
+			}
+		}
+	}
+
+	for i, set := range c.Rust.FeatureMatrix {
+		if set.AllFeatures && len(set.Features) > 0 {
+			return fmt.Errorf("rust.feature_matrix[%d]: all_features and features are mutually exclusive", i)
+		}
+	}
+	if c.Rust.AllFeatures && len(c.Rust.Features) > 0 {
+		return fmt.Errorf("rust: all_features and features are mutually exclusive")
+	}
+	return nil
+}
+
+// validateCustomTools checks that every custom tool is complete and uniquely named.
+func (c *Config) validateCustomTools() error {
+	seen := make(map[string]bool)
+	for i, tool := range c.CustomTools {
+		if tool.Name == "" {
+			return fmt.Errorf("custom_tools[%d]: name is required", i)
+		}
+		if seen[tool.Name] {
+			return fmt.Errorf("custom_tools[%d]: duplicate name %q", i, tool.Name)
+		}
+		seen[tool.Name] = true
+
+		if tool.Executable == "" {
+			return fmt.Errorf("custom_tools.%s: executable is required", tool.Name)
+		}
+
+		switch tool.Type {
+		case "", "format", "lint", "both":
+		default:
+			return fmt.Errorf("custom_tools.%s: unknown type %q (expected format, lint or both)", tool.Name, tool.Type)
+		}
+
+		switch tool.Output.Format {
+		case "regex":
+			if tool.Output.Pattern == "" {
+				return fmt.Errorf("custom_tools.%s: output.pattern is required for regex output", tool.Name)
+			}
+		case "json":
+			if len(tool.Output.Fields) == 0 {
+				return fmt.Errorf("custom_tools.%s: output.fields is required for json output", tool.Name)
+			}
+		case "", "sarif":
+		default:
+			return fmt.Errorf("custom_tools.%s: unknown output format %q (expected regex, json or sarif)", tool.Name, tool.Output.Format)
+		}
+	}
+	return nil
+}
+
+// ParseDuration parses a duration string like "7d", "24h" or "30s".
+// In addition to time.ParseDuration units it accepts a whole-day "d" suffix.
+func ParseDuration(s string) (time.Duration, error) {
+	if s != "" && s[len(s)-1] == 'd' {
+		days := 0
+		if _, err := fmt.Sscanf(s, "%dd", &days); err == nil && days > 0 {
+			return time.Duration(days) * 24 * time.Hour, nil
+		}
+	}
+
+	d, err := time.ParseDuration(s)
+	if err != nil {
+		return 0, err
+	}
+	if d <= 0 {
+		return 0, fmt.Errorf("duration must be positive: %q", s)
+	}
+	return d, nil
+}
+
 // IsToolEnabled checks if a tool is enabled.
 func (c *Config) IsToolEnabled(toolName string) bool {
 	return c.GetToolConfig(toolName).Enabled
@@ -341,6 +633,61 @@ func (c *Config) ShouldInclude(filePath string) bool {
 	return false
 }
 
+// GetPluginDirs returns the directories searched for plugins.
+// If not configured, returns .gzquality/plugins and the user plugin directory
+// (e.g. ~/.config/gz-quality/plugins).
+func (c *Config) GetPluginDirs() []string {
+	if len(c.PluginDirs) > 0 {
+		return c.PluginDirs
+	}
+
+	dirs := []string{filepath.Join(".gzquality", "plugins")}
+	if configDir, err := os.UserConfigDir(); err == nil {
+		dirs = append(dirs, filepath.Join(configDir, "gz-quality", "plugins"))
+	}
+	return dirs
+}
+
+// GetVersionIndexPath returns the version index file used by the outdated command.
+// If not configured, returns .gzquality/versions.yml
+func (c *Config) GetVersionIndexPath() string {
+	if c.VersionIndex != "" {
+		return c.VersionIndex
+	}
+	return filepath.Join(".gzquality", "versions.yml")
+}
+
+// versionIndexFile is the format of the version index file.
+type versionIndexFile struct {
+	// Tools maps tool names to their latest known version
+	Tools map[string]string `yaml:"tools"`
+}
+
+// LoadVersionIndex reads a version index file and returns the latest known
+// version of each tool.
+func LoadVersionIndex(path string) (map[string]string, error) {
+	data, err := os.ReadFile(path)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read version index: %w", err)
+	}
+
+	var index versionIndexFile
+	if err := yaml.Unmarshal(data, &index); err != nil {
+		return nil, fmt.Errorf("failed to parse version index %s: %w", path, err)
+	}
+
+	for name, version := range index.Tools {
+		if _, err := semver.Parse(version); err != nil {
+			return nil, fmt.Errorf("version index %s: tools.%s: %w", path, name, err)
+		}
+	}
+	if index.Tools == nil {
+		index.Tools = make(map[string]string)
+	}
+
+	return index.Tools, nil
+}
+
 // GetCacheDirectory returns the cache directory path.
 // If not configured, returns the default path: ~/.cache/gz-quality
 func (c *Config) GetCacheDirectory() string {
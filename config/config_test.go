@@ -7,6 +7,7 @@ import (
 	"os"
 	"path/filepath"
 	"testing"
+	"time"
 
 	"github.com/stretchr/testify/assert"
 	"github.com/stretchr/testify/require"
@@ -117,6 +118,207 @@ invalid: [unclosed
 	assert.Contains(t, err.Error(), "failed to parse config file")
 }
 
+func TestLoadConfig_Timeouts(t *testing.T) {
+	tmpDir := t.TempDir()
+	configPath := filepath.Join(tmpDir, ".gzquality.yml")
+
+	testConfig := `timeout: "20m"
+tools:
+  tsc:
+    enabled: true
+    timeout: "15m"
+  gofumpt:
+    enabled: true
+    timeout: "30s"
+  eslint:
+    enabled: true
+`
+	require.NoError(t, os.WriteFile(configPath, []byte(testConfig), 0o644))
+
+	config, err := LoadConfig(configPath)
+	require.NoError(t, err)
+
+	assert.Equal(t, 20*time.Minute, config.GetTimeout(10*time.Minute))
+	assert.Equal(t, 15*time.Minute, config.GetToolTimeout("tsc"))
+	assert.Equal(t, 30*time.Second, config.GetToolTimeout("gofumpt"))
+	assert.Zero(t, config.GetToolTimeout("eslint"))
+	assert.Zero(t, config.GetToolTimeout("unknown"))
+	assert.Equal(t, map[string]time.Duration{
+		"tsc":     15 * time.Minute,
+		"gofumpt": 30 * time.Second,
+	}, config.ToolTimeouts())
+}
+
+func TestLoadConfig_InvalidTimeout(t *testing.T) {
+	tests := []struct {
+		name     string
+		yaml     string
+		expected string
+	}{
+		{name: "global", yaml: "timeout: \"soon\"\n", expected: "timeout"},
+		{name: "per tool", yaml: "tools:\n  clippy:\n    timeout: \"-5m\"\n", expected: "tools.clippy.timeout"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			configPath := filepath.Join(t.TempDir(), ".gzquality.yml")
+			require.NoError(t, os.WriteFile(configPath, []byte(tt.yaml), 0o644))
+
+			_, err := LoadConfig(configPath)
+			require.Error(t, err)
+			assert.Contains(t, err.Error(), tt.expected)
+		})
+	}
+}
+
+func TestLoadConfig_Versions(t *testing.T) {
+	configPath := filepath.Join(t.TempDir(), ".gzquality.yml")
+	require.NoError(t, os.WriteFile(configPath, []byte("tools:\n  ruff:\n    enabled: true\n    version: \">=0.6, <0.7\"\n"), 0o644))
+
+	config, err := LoadConfig(configPath)
+	require.NoError(t, err)
+	assert.Equal(t, ">=0.6, <0.7", config.GetToolConfig("ruff").Version)
+
+	require.NoError(t, os.WriteFile(configPath, []byte("tools:\n  ruff:\n    version: latest\n"), 0o644))
+	_, err = LoadConfig(configPath)
+	require.Error(t, err)
+	assert.Contains(t, err.Error(), "tools.ruff.version")
+}
+
+func TestLoadConfig_CustomTools(t *testing.T) {
+	configPath := filepath.Join(t.TempDir(), ".gzquality.yml")
+
+	testConfig := `custom_tools:
+  - name: hadolint
+    language: Docker
+    type: lint
+    executable: hadolint
+    args: ["{config}", "{files}"]
+    config_args: ["--config", "{config_file}"]
+    extensions: [".dockerfile"]
+    config_patterns: [".hadolint.yaml"]
+    finding_exit_codes: [1]
+    install: ["brew", "install", "hadolint"]
+    output:
+      format: regex
+      pattern: '^(?P<file>[^:]+):(?P<line>\d+) (?P<rule>\S+) (?P<message>.+)$'
+      severity: warning
+  - name: sqlfluff
+    executable: sqlfluff
+    output:
+      format: json
+      root: "0.violations"
+      fields:
+        line: start_line_no
+        message: description
+`
+	require.NoError(t, os.WriteFile(configPath, []byte(testConfig), 0o644))
+
+	config, err := LoadConfig(configPath)
+	require.NoError(t, err)
+
+	require.Len(t, config.CustomTools, 2)
+	hadolint := config.CustomTools[0]
+	assert.Equal(t, "hadolint", hadolint.Name)
+	assert.Equal(t, "Docker", hadolint.Language)
+	assert.Equal(t, []string{"{config}", "{files}"}, hadolint.Args)
+	assert.Equal(t, []string{"--config", "{config_file}"}, hadolint.ConfigArgs)
+	assert.Equal(t, []int{1}, hadolint.FindingExitCodes)
+	assert.Equal(t, "regex", hadolint.Output.Format)
+	assert.Equal(t, `^(?P<file>[^:]+):(?P<line>\d+) (?P<rule>\S+) (?P<message>.+)$`, hadolint.Output.Pattern)
+	assert.Equal(t, "description", config.CustomTools[1].Output.Fields["message"])
+}
+
+func TestLoadConfig_InvalidCustomTools(t *testing.T) {
+	tests := []struct {
+		name     string
+		yaml     string
+		expected string
+	}{
+		{name: "missing name", yaml: "custom_tools:\n  - executable: x\n", expected: "custom_tools[0]: name is required"},
+		{name: "missing executable", yaml: "custom_tools:\n  - name: x\n", expected: "custom_tools.x: executable is required"},
+		{name: "duplicate name", yaml: "custom_tools:\n  - {name: x, executable: x}\n  - {name: x, executable: y}\n", expected: "duplicate name"},
+		{name: "unknown type", yaml: "custom_tools:\n  - {name: x, executable: x, type: check}\n", expected: "unknown type"},
+		{name: "regex without pattern", yaml: "custom_tools:\n  - {name: x, executable: x, output: {format: regex}}\n", expected: "output.pattern"},
+		{name: "json without fields", yaml: "custom_tools:\n  - {name: x, executable: x, output: {format: json}}\n", expected: "output.fields"},
+		{name: "unknown format", yaml: "custom_tools:\n  - {name: x, executable: x, output: {format: xml}}\n", expected: "unknown output format"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			configPath := filepath.Join(t.TempDir(), ".gzquality.yml")
+			require.NoError(t, os.WriteFile(configPath, []byte(tt.yaml), 0o644))
+
+			_, err := LoadConfig(configPath)
+			require.Error(t, err)
+			assert.Contains(t, err.Error(), tt.expected)
+		})
+	}
+}
+
+func TestLoadConfig_Rust(t *testing.T) {
+	configPath := filepath.Join(t.TempDir(), ".gzquality.yml")
+
+	testConfig := `rust:
+  deny: [warnings, clippy::pedantic]
+  allow: [clippy::module_name_repetitions]
+  all_targets: true
+  no_default_features: true
+  features: [serde]
+  feature_matrix:
+    - no_default_features: true
+    - all_features: true
+`
+	require.NoError(t, os.WriteFile(configPath, []byte(testConfig), 0o644))
+
+	config, err := LoadConfig(configPath)
+	require.NoError(t, err)
+
+	assert.Equal(t, []string{"warnings", "clippy::pedantic"}, config.Rust.Deny)
+	assert.Equal(t, []string{"clippy::module_name_repetitions"}, config.Rust.Allow)
+	assert.True(t, config.Rust.AllTargets)
+	assert.True(t, config.Rust.NoDefaultFeatures)
+	assert.Equal(t, []string{"serde"}, config.Rust.Features)
+	assert.Equal(t, []RustFeatureSet{{NoDefaultFeatures: true}, {AllFeatures: true}}, config.Rust.FeatureMatrix)
+
+	invalid := map[string]string{
+		"rust:\n  deny: [\"-D warnings\"]\n":                                    "rust.deny: invalid lint name",
+		"rust:\n  feature_matrix:\n    - {all_features: true, features: [a]}\n": "rust.feature_matrix[0]",
+	}
+	for yaml, expected := range invalid {
+		require.NoError(t, os.WriteFile(configPath, []byte(yaml), 0o644))
+		_, err := LoadConfig(configPath)
+		require.Error(t, err)
+		assert.Contains(t, err.Error(), expected)
+	}
+}
+
+func TestParseDuration(t *testing.T) {
+	tests := []struct {
+		input    string
+		expected time.Duration
+		wantErr  bool
+	}{
+		{input: "30s", expected: 30 * time.Second},
+		{input: "15m", expected: 15 * time.Minute},
+		{input: "7d", expected: 7 * 24 * time.Hour},
+		{input: "0s", wantErr: true},
+		{input: "abc", wantErr: true},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.input, func(t *testing.T) {
+			d, err := ParseDuration(tt.input)
+			if tt.wantErr {
+				assert.Error(t, err)
+				return
+			}
+			require.NoError(t, err)
+			assert.Equal(t, tt.expected, d)
+		})
+	}
+}
+
 func TestLoadConfig_NonExistentFile(t *testing.T) {
 	_, err := LoadConfig("/nonexistent/path/.gzquality.yml")
 	assert.Error(t, err)
@@ -367,6 +569,37 @@ func TestGetLanguageConfig_NotExists(t *testing.T) {
 	assert.Empty(t, langConfig.PreferredTools)
 }
 
+func TestGetPluginDirs(t *testing.T) {
+	config := DefaultConfig()
+
+	dirs := config.GetPluginDirs()
+	require.NotEmpty(t, dirs)
+	assert.Equal(t, filepath.Join(".gzquality", "plugins"), dirs[0])
+
+	config.PluginDirs = []string{"/opt/gzq-plugins"}
+	assert.Equal(t, []string{"/opt/gzq-plugins"}, config.GetPluginDirs())
+}
+
+func TestLoadVersionIndex(t *testing.T) {
+	config := DefaultConfig()
+	assert.Equal(t, filepath.Join(".gzquality", "versions.yml"), config.GetVersionIndexPath())
+
+	tmpDir := t.TempDir()
+	path := filepath.Join(tmpDir, "versions.yml")
+	require.NoError(t, os.WriteFile(path, []byte("tools:\n  gofumpt: 0.7.0\n  ruff: v0.6.9\n"), 0o644))
+
+	index, err := LoadVersionIndex(path)
+	require.NoError(t, err)
+	assert.Equal(t, map[string]string{"gofumpt": "0.7.0", "ruff": "v0.6.9"}, index)
+
+	require.NoError(t, os.WriteFile(path, []byte("tools:\n  gofumpt: latest\n"), 0o644))
+	_, err = LoadVersionIndex(path)
+	assert.ErrorContains(t, err, "tools.gofumpt")
+
+	_, err = LoadVersionIndex(filepath.Join(tmpDir, "missing.yml"))
+	assert.Error(t, err)
+}
+
 func TestShouldInclude_NoPatterns(t *testing.T) {
 	config := &Config{}
 
@@ -144,6 +144,9 @@ type ProjectAnalyzer struct {
 	langDetector   *FileTypeDetector
 	toolDetector   *SystemToolDetector
 	configDetector *ConfigFileDetector
+
+	// prober, when set, answers tool availability for the whole run
+	prober *tools.Prober
 }
 
 // NewProjectAnalyzer creates a new project analyzer.
@@ -155,6 +158,20 @@ func NewProjectAnalyzer() *ProjectAnalyzer {
 	}
 }
 
+// SetProber makes the analyzer check tool availability through a prober
+// shared with the rest of the run instead of its own PATH lookup.
+func (a *ProjectAnalyzer) SetProber(prober *tools.Prober) {
+	a.prober = prober
+}
+
+// isToolAvailable checks a tool through the prober, if one is set.
+func (a *ProjectAnalyzer) isToolAvailable(tool tools.QualityTool) bool {
+	if a.prober != nil {
+		return a.prober.IsAvailable(tool)
+	}
+	return a.toolDetector.IsToolAvailable(tool.Name())
+}
+
 // AnalysisResult contains the results of project analysis.
 type AnalysisResult struct {
 	// ProjectRoot is the root directory of the project
@@ -174,6 +191,10 @@ type AnalysisResult struct {
 
 	// Issues contains any problems detected during analysis
 	Issues []string
+
+	// RustCrates lists the crates owning the Rust files with their edition
+	// and pinned toolchain
+	RustCrates []tools.RustCrate
 }
 
 // AnalyzeProject performs comprehensive project analysis.
@@ -208,7 +229,7 @@ func (a *ProjectAnalyzer) AnalyzeProject(projectRoot string, registry tools.Tool
 		recommendations := make([]string, 0)
 
 		for _, tool := range langTools {
-			if a.toolDetector.IsToolAvailable(tool.Name()) {
+			if a.isToolAvailable(tool) {
 				result.AvailableTools = append(result.AvailableTools, tool.Name())
 				recommendations = append(recommendations, tool.Name())
 			}
@@ -224,6 +245,11 @@ func (a *ProjectAnalyzer) AnalyzeProject(projectRoot string, registry tools.Tool
 	// Find configuration files
 	result.ConfigFiles = a.configDetector.FindConfigs(projectRoot, allTools)
 
+	// Rust tools run with the edition and toolchain of each crate
+	if rustFiles := result.Languages["Rust"]; len(rustFiles) > 0 {
+		result.RustCrates = tools.RustCrates(rustFiles, projectRoot)
+	}
+
 	// Remove duplicates from available tools
 	result.AvailableTools = removeDuplicates(result.AvailableTools)
 
@@ -197,6 +197,35 @@ func TestAnalyzeProject_MultiLanguage(t *testing.T) {
 	assert.Contains(t, result.Languages, "JavaScript")
 }
 
+func TestAnalyzeProject_RustCrates(t *testing.T) {
+	analyzer := NewProjectAnalyzer()
+	tmpDir := t.TempDir()
+
+	files := map[string]string{
+		"Cargo.toml":               "[workspace]\nmembers = [\"app\", \"legacy\"]\n\n[workspace.package]\nedition = \"2021\"\n",
+		"rust-toolchain.toml":      "[toolchain]\nchannel = \"1.79.0\"\n",
+		"app/Cargo.toml":           "[package]\nname = \"app\"\nedition.workspace = true\n",
+		"app/src/main.rs":          "fn main() {}\n",
+		"legacy/Cargo.toml":        "[package]\nname = \"legacy\"\n",
+		"legacy/src/lib.rs":        "pub fn f() {}\n",
+		"legacy/rust-toolchain":    "nightly-2024-05-01\n",
+		"legacy/src/nested/mod.rs": "\n",
+	}
+	for name, content := range files {
+		path := filepath.Join(tmpDir, filepath.FromSlash(name))
+		require.NoError(t, os.MkdirAll(filepath.Dir(path), 0o755))
+		require.NoError(t, os.WriteFile(path, []byte(content), 0o644))
+	}
+
+	result, err := analyzer.AnalyzeProject(tmpDir, tools.NewRegistry())
+	require.NoError(t, err)
+
+	assert.Equal(t, []tools.RustCrate{
+		{Name: "app", Dir: filepath.Join(tmpDir, "app"), Edition: "2021", Toolchain: "1.79.0"},
+		{Name: "legacy", Dir: filepath.Join(tmpDir, "legacy"), Edition: "2015", Toolchain: "nightly-2024-05-01"},
+	}, result.RustCrates)
+}
+
 func TestAnalyzeProject_NoTools(t *testing.T) {
 	analyzer := NewProjectAnalyzer()
 	tmpDir := t.TempDir()
@@ -0,0 +1,122 @@
+// Copyright (c) 2025 Archmagece
+// SPDX-License-Identifier: MIT
+
+package executor
+
+import (
+	"fmt"
+	"runtime"
+	"sort"
+
+	"github.com/Gizzahub/gzh-cli-quality/tools"
+)
+
+// DefaultMaxArgBytes is the default argv budget per command. It stays well
+// below ARG_MAX (which also has to fit the environment) and matches xargs.
+var DefaultMaxArgBytes = defaultMaxArgBytes()
+
+func defaultMaxArgBytes() int {
+	if runtime.GOOS == "windows" {
+		return 30 * 1024 // CreateProcess limits the command line to 32767 characters
+	}
+	return 128 * 1024
+}
+
+// splitTask splits a file-scoped task whose argv would exceed maxBytes into
+// batches that each fit. Tools that do not put files on the command line
+// (tools.FilelessCommand, e.g. cargo clippy, govulncheck) are never split.
+// All batches share a Group derived from id so the executor can merge their
+// results.
+//
+// The argv is estimated as the command built without files plus the files,
+// so planning builds each command once and never with the task's files.
+func splitTask(task tools.Task, id, maxBytes int) []tools.Task {
+	builder, ok := task.Tool.(tools.CommandBuilder)
+	if !ok || len(task.Files) <= 1 {
+		return []tools.Task{task}
+	}
+	if fileless, ok := task.Tool.(tools.FilelessCommand); ok && fileless.OmitsFiles() {
+		return []tools.Task{task}
+	}
+	if maxBytes <= 0 {
+		maxBytes = DefaultMaxArgBytes
+	}
+
+	budget := maxBytes - argvSize(builder.BuildCommand(nil, task.Options).Args)
+	if argvSize(task.Files) <= budget {
+		return []tools.Task{task}
+	}
+
+	// Keep files of one directory together so package-based tools see them at once
+	files := append([]string(nil), task.Files...)
+	sort.Strings(files)
+
+	group := fmt.Sprintf("%s#%d", task.Tool.Name(), id)
+
+	var batches []tools.Task
+	var current []string
+	size := 0
+	for _, file := range files {
+		fileSize := len(file) + 1
+		if len(current) > 0 && size+fileSize > budget {
+			batches = append(batches, batchOf(task, group, current))
+			current, size = nil, 0
+		}
+		current = append(current, file)
+		size += fileSize
+	}
+	if len(current) > 0 {
+		batches = append(batches, batchOf(task, group, current))
+	}
+
+	return batches
+}
+
+// batchOf copies task with the given group and files.
+func batchOf(task tools.Task, group string, files []string) tools.Task {
+	batch := task
+	batch.Group = group
+	batch.Files = files
+	return batch
+}
+
+// argvSize returns the bytes args occupy in the exec argument block.
+func argvSize(args []string) int {
+	size := 0
+	for _, arg := range args {
+		size += len(arg) + 1 // NUL terminator
+	}
+	return size
+}
+
+// mergeBatches merges the results of tasks sharing a Group into one result
+// per group, keeping the position of the group's first task.
+func mergeBatches(tasks []tools.Task, results []*tools.Result) []*tools.Result {
+	merged := make([]*tools.Result, 0, len(results))
+	groups := make(map[string][]*tools.Result)
+	first := make(map[string]int)
+
+	for i, task := range tasks {
+		if task.Group == "" {
+			merged = append(merged, results[i])
+			continue
+		}
+		if _, seen := first[task.Group]; !seen {
+			first[task.Group] = len(merged)
+			merged = append(merged, nil)
+		}
+		groups[task.Group] = append(groups[task.Group], results[i])
+	}
+
+	for i, task := range tasks {
+		if task.Group == "" {
+			continue
+		}
+		if batchResults, ok := groups[task.Group]; ok {
+			merged[first[task.Group]] = mergeResults(batchResults, tasks[i].Tool)
+			delete(groups, task.Group)
+		}
+	}
+
+	return merged
+}
@@ -0,0 +1,154 @@
+// Copyright (c) 2025 Archmagece
+// SPDX-License-Identifier: MIT
+
+package executor
+
+import (
+	"context"
+	"fmt"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/Gizzahub/gzh-cli-quality/internal/testutil"
+	"github.com/Gizzahub/gzh-cli-quality/tools"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func manyFiles(n int) []string {
+	files := make([]string, n)
+	for i := range files {
+		files[i] = fmt.Sprintf("/repo/packages/web/src/components/Component%05d.ts", i)
+	}
+	return files
+}
+
+func TestSplitTask_BatchesByArgvSize(t *testing.T) {
+	tool := tools.NewESLintTool()
+	files := manyFiles(2000)
+	task := tools.Task{Tool: tool, Files: files, Options: tools.ExecuteOptions{ProjectRoot: "/repo"}, Priority: 5}
+
+	batches := splitTask(task, 3, 8*1024)
+
+	require.Greater(t, len(batches), 1)
+	var seen []string
+	for _, batch := range batches {
+		assert.Equal(t, "eslint#3", batch.Group)
+		assert.Equal(t, 5, batch.Priority)
+		assert.LessOrEqual(t, argvSize(tool.BuildCommand(batch.Files, batch.Options).Args), 8*1024)
+		seen = append(seen, batch.Files...)
+	}
+	assert.ElementsMatch(t, files, seen)
+}
+
+func TestSplitTask_SmallTaskUnchanged(t *testing.T) {
+	task := tools.Task{Tool: tools.NewESLintTool(), Files: manyFiles(10)}
+
+	batches := splitTask(task, 0, 0)
+
+	require.Len(t, batches, 1)
+	assert.Empty(t, batches[0].Group)
+	assert.Equal(t, task.Files, batches[0].Files)
+}
+
+func TestSplitTask_ProjectScopedToolNotSplit(t *testing.T) {
+	// govulncheck always runs on ./... so its argv does not grow with the file list
+	task := tools.Task{Tool: tools.NewGovulncheckTool(), Files: manyFiles(5000)}
+
+	batches := splitTask(task, 0, 4*1024)
+
+	require.Len(t, batches, 1)
+	assert.Len(t, batches[0].Files, 5000)
+}
+
+func TestSplitTask_FilelessCommandNotBuilt(t *testing.T) {
+	// Building the clippy command runs cargo metadata, which planning must not do
+	dir := t.TempDir()
+	marker := filepath.Join(dir, "ran")
+	tool := tools.NewClippyTool()
+	tool.SetExecutable(testutil.WriteExecutable(t, dir, "cargo", "touch "+marker+"\n"))
+	task := tools.Task{Tool: tool, Files: manyFiles(5000), Options: tools.ExecuteOptions{ProjectRoot: dir}}
+
+	batches := splitTask(task, 0, 4*1024)
+
+	require.Len(t, batches, 1)
+	assert.NoFileExists(t, marker)
+}
+
+func TestExecutionPlanner_CreatePlan_SplitsLargeTasks(t *testing.T) {
+	tool := tools.NewESLintTool()
+	files := manyFiles(3000)
+
+	analyzer := &mockAnalyzer{
+		analyzeFunc: func(projectRoot string, reg tools.ToolRegistry) (*AnalysisResult, error) {
+			return &AnalysisResult{
+				ProjectRoot: projectRoot,
+				Languages:   map[string][]string{"JavaScript": files},
+				ConfigFiles: map[string]string{},
+			}, nil
+		},
+		selectionFunc: func(result *AnalysisResult, reg tools.ToolRegistry) map[string][]tools.QualityTool {
+			return map[string][]tools.QualityTool{"JavaScript": {tool}}
+		},
+	}
+	registry := &mockRegistry{tools: map[string]tools.QualityTool{"eslint": tool}}
+
+	plan, err := NewExecutionPlanner(analyzer).CreatePlan("/repo", registry, PlanOptions{MaxArgBytes: 16 * 1024})
+
+	require.NoError(t, err)
+	assert.Greater(t, len(plan.Tasks), 1)
+	assert.Equal(t, len(files), plan.TotalFiles)
+}
+
+func TestParallelExecutor_ExecuteParallel_MergesBatches(t *testing.T) {
+	executor := NewParallelExecutor(4, time.Minute)
+
+	batched := &mockTool{
+		name:     "eslint",
+		language: "JavaScript",
+		toolType: tools.LINT,
+		executeFunc: func(ctx context.Context, files []string, options tools.ExecuteOptions) (*tools.Result, error) {
+			return &tools.Result{
+				Tool:           "eslint",
+				Success:        true,
+				Status:         tools.StatusIssues,
+				ExitCode:       1,
+				FilesProcessed: len(files),
+				Issues:         []tools.Issue{{File: files[0], Rule: "no-unused-vars"}},
+			}, nil
+		},
+		validateFunc: func() error { return nil },
+	}
+	other := &mockTool{
+		name:     "prettier",
+		language: "JavaScript",
+		toolType: tools.FORMAT,
+		executeFunc: func(ctx context.Context, files []string, options tools.ExecuteOptions) (*tools.Result, error) {
+			return &tools.Result{Tool: "prettier", Success: true, FilesProcessed: len(files)}, nil
+		},
+		validateFunc: func() error { return nil },
+	}
+
+	plan := &tools.ExecutionPlan{
+		Tasks: []tools.Task{
+			{Tool: batched, Files: []string{"a.js", "b.js"}, Priority: 5, Group: "eslint#0"},
+			{Tool: other, Files: []string{"a.js"}, Priority: 10},
+			{Tool: batched, Files: []string{"c.js"}, Priority: 5, Group: "eslint#0"},
+			{Tool: batched, Files: []string{"d.js", "e.js"}, Priority: 5, Group: "eslint#0"},
+		},
+	}
+
+	results, err := executor.ExecuteParallel(context.Background(), plan, 4)
+
+	require.NoError(t, err)
+	require.Len(t, results, 2)
+	assert.Equal(t, "prettier", results[0].Tool)
+
+	merged := results[1]
+	assert.Equal(t, "eslint", merged.Tool)
+	assert.Equal(t, 5, merged.FilesProcessed)
+	assert.Len(t, merged.Issues, 3)
+	assert.Equal(t, tools.StatusIssues, merged.Status)
+	assert.True(t, merged.Success)
+}
@@ -438,7 +438,7 @@ func BenchmarkFilterIssuesByFile(b *testing.B) {
 
 	b.ResetTimer()
 	for i := 0; i < b.N; i++ {
-		_ = filterIssuesByFile(issues, "file1.go")
+		_ = filterIssuesByFile(issues, "file1.go", "")
 	}
 }
 
@@ -457,6 +457,6 @@ func BenchmarkFilterIssuesByFile_Large(b *testing.B) {
 
 	b.ResetTimer()
 	for i := 0; i < b.N; i++ {
-		_ = filterIssuesByFile(issues, "a.go")
+		_ = filterIssuesByFile(issues, "a.go", "")
 	}
 }
@@ -364,3 +364,255 @@ func TestExecutor_FailedResultNotCached(t *testing.T) {
 	assert.Equal(t, 2, tool.execCount, "Failed results should not be cached")
 	assert.False(t, results[0].Cached)
 }
+
+// versionCountingTool counts how often its version is probed
+type versionCountingTool struct {
+	*mockCacheableTool
+	versionCalls int
+}
+
+func (m *versionCountingTool) GetVersion() (string, error) {
+	m.versionCalls++
+	return m.mockCacheableTool.GetVersion()
+}
+
+func TestExecutor_WithCache_ProbesVersionOnce(t *testing.T) {
+	cacheManager, err := cache.NewCacheManager(filepath.Join(t.TempDir(), "cache"), 100*1024*1024, 24*time.Hour)
+	require.NoError(t, err)
+	defer cacheManager.Close()
+
+	tmpDir := t.TempDir()
+	var files []string
+	for _, name := range []string{"a.go", "b.go", "c.go"} {
+		path := filepath.Join(tmpDir, name)
+		require.NoError(t, os.WriteFile(path, []byte("package main\n"), 0o644))
+		files = append(files, path)
+	}
+
+	executor := NewParallelExecutorWithCache(4, 5*time.Minute, cacheManager)
+	tool := &versionCountingTool{mockCacheableTool: newMockCacheableTool("gofumpt", "Go")}
+	plan := &tools.ExecutionPlan{
+		Tasks: []tools.Task{{Tool: tool, Files: files, Options: tools.ExecuteOptions{ProjectRoot: tmpDir}}},
+	}
+
+	// A miss and a hit over three files still probe the version only once
+	for i := 0; i < 2; i++ {
+		_, err := executor.ExecuteParallel(context.Background(), plan, 1)
+		require.NoError(t, err)
+	}
+	assert.Equal(t, 1, tool.execCount)
+	assert.Equal(t, 1, tool.versionCalls)
+
+	// A prober shared with the rest of the run already knows the version
+	prober := tools.NewProber("")
+	_, err = prober.Version(tool)
+	require.NoError(t, err)
+	executor.SetProber(prober)
+	_, err = executor.ExecuteParallel(context.Background(), plan, 1)
+	require.NoError(t, err)
+	assert.Equal(t, 2, tool.versionCalls)
+}
+
+// relativeIssueTool reports one finding per file with a project-relative path,
+// as clippy and golangci-lint do, plus findings in extraFiles
+type relativeIssueTool struct {
+	*mockCacheableTool
+	extraFiles []string
+}
+
+func (m *relativeIssueTool) Execute(ctx context.Context, files []string, options tools.ExecuteOptions) (*tools.Result, error) {
+	result, err := m.mockCacheableTool.Execute(ctx, files, options)
+	for _, file := range files {
+		rel, relErr := filepath.Rel(options.ProjectRoot, file)
+		if relErr != nil {
+			return nil, relErr
+		}
+		result.Issues = append(result.Issues, tools.Issue{File: rel, Line: 1, Severity: "warning", Rule: "R1"})
+	}
+	for _, file := range m.extraFiles {
+		result.Issues = append(result.Issues, tools.Issue{File: file, Line: 1, Severity: "error", Rule: "R2"})
+	}
+	result.ExitCode = 1
+	result.Status = tools.StatusIssues
+	return result, err
+}
+
+// relativeIssuePlan creates files a.go, b.go and c.go and a plan running tool on them
+func relativeIssuePlan(t *testing.T, tool tools.QualityTool) *tools.ExecutionPlan {
+	t.Helper()
+
+	tmpDir := t.TempDir()
+	var files []string
+	for _, name := range []string{"a.go", "b.go", "c.go"} {
+		path := filepath.Join(tmpDir, name)
+		require.NoError(t, os.WriteFile(path, []byte("package main\n"), 0o644))
+		files = append(files, path)
+	}
+
+	return &tools.ExecutionPlan{
+		Tasks: []tools.Task{{Tool: tool, Files: files, Options: tools.ExecuteOptions{ProjectRoot: tmpDir}}},
+	}
+}
+
+func TestExecutor_WithCache_KeepsRelativePathFindings(t *testing.T) {
+	cacheManager, err := cache.NewCacheManager(filepath.Join(t.TempDir(), "cache"), 100*1024*1024, 24*time.Hour)
+	require.NoError(t, err)
+	defer cacheManager.Close()
+
+	executor := NewParallelExecutorWithCache(4, 5*time.Minute, cacheManager)
+	tool := &relativeIssueTool{mockCacheableTool: newMockCacheableTool("golangci-lint", "Go")}
+	plan := relativeIssuePlan(t, tool)
+
+	first, err := executor.ExecuteParallel(context.Background(), plan, 1)
+	require.NoError(t, err)
+	require.Len(t, first, 1)
+	assert.Len(t, first[0].Issues, 3)
+
+	// The rerun comes from the cache and still reports every finding
+	second, err := executor.ExecuteParallel(context.Background(), plan, 1)
+	require.NoError(t, err)
+	require.Len(t, second, 1)
+	assert.Equal(t, 1, tool.execCount)
+	assert.True(t, second[0].Cached)
+	assert.Equal(t, tools.StatusIssues, second[0].Status)
+	assert.ElementsMatch(t, first[0].Issues, second[0].Issues)
+}
+
+func TestExecutor_WithCache_FindingsOutsideFilesNotCachedPerFile(t *testing.T) {
+	cacheManager, err := cache.NewCacheManager(filepath.Join(t.TempDir(), "cache"), 100*1024*1024, 24*time.Hour)
+	require.NoError(t, err)
+	defer cacheManager.Close()
+
+	executor := NewParallelExecutorWithCache(4, 5*time.Minute, cacheManager)
+	tool := &relativeIssueTool{mockCacheableTool: newMockCacheableTool("golangci-lint", "Go"), extraFiles: []string{"go.mod"}}
+	plan := relativeIssuePlan(t, tool)
+
+	for i := 0; i < 2; i++ {
+		results, err := executor.ExecuteParallel(context.Background(), plan, 1)
+		require.NoError(t, err)
+		require.Len(t, results, 1)
+		assert.False(t, results[0].Cached)
+		assert.Len(t, results[0].Issues, 4, "the go.mod finding must not be lost")
+	}
+	assert.Equal(t, 2, tool.execCount)
+}
+
+// projectScopedTool reports a finding against a lockfile that is not one of
+// the files it runs on, as cargo-audit does
+type projectScopedTool struct {
+	*mockCacheableTool
+	lockfile string
+}
+
+func (m *projectScopedTool) Execute(ctx context.Context, files []string, options tools.ExecuteOptions) (*tools.Result, error) {
+	result, err := m.mockCacheableTool.Execute(ctx, files, options)
+	result.Issues = []tools.Issue{{File: "Cargo.lock", Line: 3, Severity: "error", Rule: "RUSTSEC-2024-0001"}}
+	result.ExitCode = 1
+	result.Status = tools.StatusIssues
+	return result, err
+}
+
+func (m *projectScopedTool) ProjectInputs(files []string, projectRoot string) []string {
+	return []string{m.lockfile}
+}
+
+func TestExecutor_WithCache_ProjectScopedToolCachedPerTask(t *testing.T) {
+	cacheManager, err := cache.NewCacheManager(filepath.Join(t.TempDir(), "cache"), 100*1024*1024, 24*time.Hour)
+	require.NoError(t, err)
+	defer cacheManager.Close()
+
+	executor := NewParallelExecutorWithCache(4, 5*time.Minute, cacheManager)
+	tool := &projectScopedTool{mockCacheableTool: newMockCacheableTool("cargo-audit", "Rust")}
+	plan := relativeIssuePlan(t, tool)
+	tool.lockfile = filepath.Join(plan.Tasks[0].Options.ProjectRoot, "Cargo.lock")
+	require.NoError(t, os.WriteFile(tool.lockfile, []byte("version = 3\n"), 0o644))
+
+	first, err := executor.ExecuteParallel(context.Background(), plan, 1)
+	require.NoError(t, err)
+	require.Len(t, first, 1)
+	require.Len(t, first[0].Issues, 1)
+
+	// The cached rerun keeps the lockfile finding
+	second, err := executor.ExecuteParallel(context.Background(), plan, 1)
+	require.NoError(t, err)
+	require.Len(t, second, 1)
+	assert.Equal(t, 1, tool.execCount)
+	assert.True(t, second[0].Cached)
+	assert.Equal(t, tools.StatusIssues, second[0].Status)
+	assert.Equal(t, first[0].Issues, second[0].Issues)
+
+	// A changed lockfile runs the tool again
+	require.NoError(t, os.WriteFile(tool.lockfile, []byte("version = 4\n"), 0o644))
+	third, err := executor.ExecuteParallel(context.Background(), plan, 1)
+	require.NoError(t, err)
+	assert.Equal(t, 2, tool.execCount)
+	assert.False(t, third[0].Cached)
+}
+
+// crateWideTool reports on the whole crate whichever of its files it runs on,
+// as cargo clippy does: a.rs always has a finding, and a second one while
+// b.rs, which it uses, is broken
+type crateWideTool struct {
+	*mockCacheableTool
+}
+
+func (m *crateWideTool) Execute(ctx context.Context, files []string, options tools.ExecuteOptions) (*tools.Result, error) {
+	result, err := m.mockCacheableTool.Execute(ctx, files, options)
+	result.Issues = []tools.Issue{{File: "src/a.rs", Line: 1, Severity: "warning", Rule: "R1"}}
+	if data, readErr := os.ReadFile(filepath.Join(options.ProjectRoot, "src", "b.rs")); readErr == nil && string(data) == "broken\n" {
+		result.Issues = append(result.Issues, tools.Issue{File: "src/a.rs", Line: 2, Severity: "error", Rule: "R2"})
+	}
+	result.ExitCode = 1
+	result.Status = tools.StatusIssues
+	return result, err
+}
+
+func (m *crateWideTool) OmitsFiles() bool { return true }
+
+func (m *crateWideTool) ProjectInputs(files []string, projectRoot string) []string {
+	return tools.NewClippyTool().ProjectInputs(files, projectRoot)
+}
+
+func TestExecutor_WithCache_CrateWideToolPartialHit(t *testing.T) {
+	cacheManager, err := cache.NewCacheManager(filepath.Join(t.TempDir(), "cache"), 100*1024*1024, 24*time.Hour)
+	require.NoError(t, err)
+	defer cacheManager.Close()
+
+	tmpDir := t.TempDir()
+	require.NoError(t, os.MkdirAll(filepath.Join(tmpDir, "src"), 0o755))
+	require.NoError(t, os.WriteFile(filepath.Join(tmpDir, "Cargo.toml"), []byte("[package]\nname = \"demo\"\n"), 0o644))
+	aRs := filepath.Join(tmpDir, "src", "a.rs")
+	bRs := filepath.Join(tmpDir, "src", "b.rs")
+	require.NoError(t, os.WriteFile(aRs, []byte("fn a() {}\n"), 0o644))
+	require.NoError(t, os.WriteFile(bRs, []byte("fn b() {}\n"), 0o644))
+
+	executor := NewParallelExecutorWithCache(4, 5*time.Minute, cacheManager)
+	tool := &crateWideTool{mockCacheableTool: newMockCacheableTool("clippy", "Rust")}
+	options := tools.ExecuteOptions{ProjectRoot: tmpDir}
+	plan := func(files ...string) *tools.ExecutionPlan {
+		return &tools.ExecutionPlan{Tasks: []tools.Task{{Tool: tool, Files: files, Options: options}}}
+	}
+
+	first, err := executor.ExecuteParallel(context.Background(), plan(aRs, bRs), 1)
+	require.NoError(t, err)
+	require.Len(t, first[0].Issues, 1)
+
+	// Only b.rs changed: the run must not reuse a.rs' findings next to fresh ones
+	require.NoError(t, os.WriteFile(bRs, []byte("broken\n"), 0o644))
+	second, err := executor.ExecuteParallel(context.Background(), plan(aRs, bRs), 1)
+	require.NoError(t, err)
+	assert.False(t, second[0].Cached)
+	assert.Len(t, second[0].Issues, 2, "findings must not be duplicated")
+
+	// a.rs alone is unchanged, yet its findings depend on b.rs
+	third, err := executor.ExecuteParallel(context.Background(), plan(aRs), 1)
+	require.NoError(t, err)
+	assert.False(t, third[0].Cached)
+	assert.Len(t, third[0].Issues, 2, "a stale a.rs result must not be served")
+
+	// Unchanged sources hit the cache
+	fourth, err := executor.ExecuteParallel(context.Background(), plan(aRs), 1)
+	require.NoError(t, err)
+	assert.True(t, fourth[0].Cached)
+	assert.Equal(t, 3, tool.execCount)
+}
@@ -8,6 +8,7 @@ import (
 	"os"
 	"os/exec"
 	"path/filepath"
+	"sync"
 	"testing"
 	"time"
 
@@ -345,6 +346,305 @@ func TestParallelExecutor_ExecuteParallel_Timeout(t *testing.T) {
 	assert.Contains(t, err.Error(), "timed out")
 }
 
+func TestParallelExecutor_ExecuteParallel_TimeoutReportsEveryTask(t *testing.T) {
+	executor := NewParallelExecutor(1, 50*time.Millisecond)
+
+	blocking := &mockTool{
+		name:     "blocking-tool",
+		language: "Go",
+		toolType: tools.LINT,
+		executeFunc: func(ctx context.Context, files []string, options tools.ExecuteOptions) (*tools.Result, error) {
+			<-ctx.Done()
+			return &tools.Result{
+				Tool:    "blocking-tool",
+				Success: false,
+				Error:   tools.InterruptionReason(ctx.Err()),
+			}, nil
+		},
+		validateFunc: func() error { return nil },
+	}
+	queued := &mockTool{
+		name:     "queued-tool",
+		language: "Go",
+		toolType: tools.LINT,
+		executeFunc: func(ctx context.Context, files []string, options tools.ExecuteOptions) (*tools.Result, error) {
+			t.Error("queued task must not start after timeout")
+			return &tools.Result{Tool: "queued-tool", Success: true}, nil
+		},
+		validateFunc: func() error { return nil },
+	}
+
+	plan := &tools.ExecutionPlan{
+		Tasks: []tools.Task{
+			{Tool: blocking, Files: []string{"a.go"}, Priority: 10},
+			{Tool: queued, Files: []string{"b.go"}, Priority: 5},
+		},
+	}
+
+	results, err := executor.ExecuteParallel(context.Background(), plan, 1)
+
+	require.Error(t, err)
+	assert.Contains(t, err.Error(), "timed out")
+	require.Len(t, results, 2)
+	for _, result := range results {
+		assert.False(t, result.Success)
+		assert.Equal(t, "timed out", result.Error)
+		assert.Equal(t, tools.StatusTimeout, result.Status)
+	}
+}
+
+func TestParallelExecutor_ExecuteParallel_CancelReportsCancelled(t *testing.T) {
+	executor := NewParallelExecutor(1, time.Minute)
+
+	ctx, cancel := context.WithCancel(context.Background())
+	tool := &mockTool{
+		name:     "cancelled-tool",
+		language: "Go",
+		toolType: tools.LINT,
+		executeFunc: func(ctx context.Context, files []string, options tools.ExecuteOptions) (*tools.Result, error) {
+			cancel()
+			<-ctx.Done()
+			return &tools.Result{Tool: "cancelled-tool"}, nil
+		},
+		validateFunc: func() error { return nil },
+	}
+
+	plan := &tools.ExecutionPlan{
+		Tasks: []tools.Task{{Tool: tool, Files: []string{"a.go"}, Priority: 10}},
+	}
+
+	results, err := executor.ExecuteParallel(ctx, plan, 1)
+
+	require.Error(t, err)
+	assert.Contains(t, err.Error(), "cancelled")
+	require.Len(t, results, 1)
+	assert.Equal(t, "cancelled", results[0].Error)
+	assert.Equal(t, tools.StatusCancelled, results[0].Status)
+}
+
+func TestParallelExecutor_ExecuteParallel_TaskTimeoutFailsOnlyItsTask(t *testing.T) {
+	executor := NewParallelExecutor(2, time.Minute)
+
+	slow := &mockTool{
+		name:     "tsc",
+		language: "TypeScript",
+		toolType: tools.LINT,
+		executeFunc: func(ctx context.Context, files []string, options tools.ExecuteOptions) (*tools.Result, error) {
+			<-ctx.Done()
+			return &tools.Result{Tool: "tsc"}, nil
+		},
+		validateFunc: func() error { return nil },
+	}
+	fast := &mockTool{
+		name:     "gofumpt",
+		language: "Go",
+		toolType: tools.FORMAT,
+		executeFunc: func(ctx context.Context, files []string, options tools.ExecuteOptions) (*tools.Result, error) {
+			time.Sleep(100 * time.Millisecond)
+			return &tools.Result{Tool: "gofumpt", Success: true, Status: tools.StatusClean}, nil
+		},
+		validateFunc: func() error { return nil },
+	}
+
+	plan := &tools.ExecutionPlan{
+		Tasks: []tools.Task{
+			{Tool: slow, Files: []string{"a.ts"}, Priority: 5, Timeout: 50 * time.Millisecond},
+			{Tool: fast, Files: []string{"b.go"}, Priority: 10},
+		},
+	}
+
+	results, err := executor.ExecuteParallel(context.Background(), plan, 2)
+
+	require.NoError(t, err)
+	require.Len(t, results, 2)
+	for _, result := range results {
+		switch result.Tool {
+		case "tsc":
+			assert.Equal(t, tools.StatusTimeout, result.Status)
+			assert.Equal(t, "timed out after 50ms", result.Error)
+		case "gofumpt":
+			assert.True(t, result.Success)
+		}
+	}
+}
+
+func TestParallelExecutor_ExecuteParallel_TaskTimeoutOutlastsExecutorTimeout(t *testing.T) {
+	executor := NewParallelExecutor(2, 50*time.Millisecond)
+
+	slow := &mockTool{
+		name:     "tsc",
+		language: "TypeScript",
+		toolType: tools.LINT,
+		executeFunc: func(ctx context.Context, files []string, options tools.ExecuteOptions) (*tools.Result, error) {
+			select {
+			case <-time.After(200 * time.Millisecond):
+				return &tools.Result{Tool: "tsc", Success: true, Status: tools.StatusClean}, nil
+			case <-ctx.Done():
+				return &tools.Result{Tool: "tsc", Error: tools.InterruptionReason(ctx.Err())}, nil
+			}
+		},
+		validateFunc: func() error { return nil },
+	}
+	blocking := &mockTool{
+		name:     "eslint",
+		language: "JavaScript",
+		toolType: tools.LINT,
+		executeFunc: func(ctx context.Context, files []string, options tools.ExecuteOptions) (*tools.Result, error) {
+			<-ctx.Done()
+			return &tools.Result{Tool: "eslint", Error: tools.InterruptionReason(ctx.Err())}, nil
+		},
+		validateFunc: func() error { return nil },
+	}
+
+	plan := &tools.ExecutionPlan{
+		Tasks: []tools.Task{
+			{Tool: slow, Files: []string{"a.ts"}, Priority: 10, Timeout: time.Second},
+			{Tool: blocking, Files: []string{"b.js"}, Priority: 5},
+		},
+	}
+
+	results, err := executor.ExecuteParallel(context.Background(), plan, 2)
+
+	// The task without a timeout of its own is still bound by the executor's
+	require.Error(t, err)
+	assert.Contains(t, err.Error(), "timed out after 50ms")
+	require.Len(t, results, 2)
+	for _, result := range results {
+		switch result.Tool {
+		case "tsc":
+			assert.True(t, result.Success)
+			assert.Equal(t, tools.StatusClean, result.Status)
+		case "eslint":
+			assert.Equal(t, tools.StatusTimeout, result.Status)
+			assert.Equal(t, "timed out", result.Error)
+		}
+	}
+}
+
+func TestParallelExecutor_ExecuteParallel_TaskTimeoutStartsWithTask(t *testing.T) {
+	executor := NewParallelExecutor(1, 50*time.Millisecond)
+
+	newSlow := func(name string) *mockTool {
+		return &mockTool{
+			name:     name,
+			language: "TypeScript",
+			toolType: tools.LINT,
+			executeFunc: func(ctx context.Context, files []string, options tools.ExecuteOptions) (*tools.Result, error) {
+				select {
+				case <-time.After(150 * time.Millisecond):
+					return &tools.Result{Tool: name, Success: true, Status: tools.StatusClean}, nil
+				case <-ctx.Done():
+					return &tools.Result{Tool: name, Error: tools.InterruptionReason(ctx.Err())}, nil
+				}
+			},
+			validateFunc: func() error { return nil },
+		}
+	}
+
+	// With one worker the second task waits 150ms in the queue, which must not
+	// count against its own 250ms timeout
+	plan := &tools.ExecutionPlan{
+		Tasks: []tools.Task{
+			{Tool: newSlow("tsc"), Files: []string{"a.ts"}, Priority: 10, Timeout: 250 * time.Millisecond},
+			{Tool: newSlow("tsc-build"), Files: []string{"b.ts"}, Priority: 5, Timeout: 250 * time.Millisecond},
+		},
+	}
+
+	results, err := executor.ExecuteParallel(context.Background(), plan, 1)
+
+	require.NoError(t, err)
+	require.Len(t, results, 2)
+	for _, result := range results {
+		assert.True(t, result.Success, result.Tool)
+		assert.Equal(t, tools.StatusClean, result.Status, result.Tool)
+	}
+}
+
+func TestParallelExecutor_SetRunner(t *testing.T) {
+	executor := NewParallelExecutor(2, time.Minute)
+	dryRun := tools.NewDryRunRunner(nil)
+	executor.SetRunner(dryRun)
+
+	own := tools.NewDryRunRunner(nil)
+	runners := make(map[string]tools.CommandRunner)
+	var mu sync.Mutex
+	newTool := func(name string) *mockTool {
+		return &mockTool{
+			name:     name,
+			language: "Go",
+			toolType: tools.LINT,
+			executeFunc: func(ctx context.Context, files []string, options tools.ExecuteOptions) (*tools.Result, error) {
+				mu.Lock()
+				defer mu.Unlock()
+				runners[name] = options.Runner
+				return &tools.Result{Tool: name, Success: true, Status: tools.StatusClean}, nil
+			},
+			validateFunc: func() error { return nil },
+		}
+	}
+
+	plan := &tools.ExecutionPlan{
+		Tasks: []tools.Task{
+			{Tool: newTool("default"), Files: []string{"a.go"}},
+			{Tool: newTool("own"), Files: []string{"b.go"}, Options: tools.ExecuteOptions{Runner: own}},
+		},
+	}
+
+	_, err := executor.ExecuteParallel(context.Background(), plan, 2)
+	require.NoError(t, err)
+
+	assert.Same(t, dryRun, runners["default"])
+	assert.Same(t, own, runners["own"])
+}
+
+func TestMergeResults_KeepsWorstStatus(t *testing.T) {
+	tool := &mockTool{name: "golangci-lint", language: "Go", toolType: tools.LINT}
+
+	tests := []struct {
+		name     string
+		results  []*tools.Result
+		expected tools.Status
+		success  bool
+	}{
+		{
+			name: "clean and findings",
+			results: []*tools.Result{
+				{Success: true, Status: tools.StatusClean},
+				{Success: true, Status: tools.StatusIssues, ExitCode: 1, Issues: []tools.Issue{{File: "a.go"}}},
+			},
+			expected: tools.StatusIssues,
+			success:  true,
+		},
+		{
+			name: "findings and tool error",
+			results: []*tools.Result{
+				{Success: true, Status: tools.StatusIssues, ExitCode: 1},
+				{Success: false, Status: tools.StatusToolError, ExitCode: 3},
+			},
+			expected: tools.StatusToolError,
+			success:  false,
+		},
+		{
+			name: "legacy cached results without status",
+			results: []*tools.Result{
+				{Success: true, Cached: true},
+				{Success: true, Cached: true, Issues: []tools.Issue{{File: "b.go"}}},
+			},
+			expected: tools.StatusIssues,
+			success:  true,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			merged := mergeResults(tt.results, tool)
+
+			assert.Equal(t, tt.expected, merged.Status)
+			assert.Equal(t, tt.success, merged.Success)
+		})
+	}
+}
+
 // Tests for ExecutionPlanner
 
 func TestNewExecutionPlanner(t *testing.T) {
@@ -409,6 +709,14 @@ func TestExecutionPlanner_CreatePlan(t *testing.T) {
 	assert.Len(t, plan.Tasks, 1)
 	assert.Equal(t, "gofmt", plan.Tasks[0].Tool.Name())
 	assert.Equal(t, 10, plan.Tasks[0].Priority) // FORMAT priority
+	assert.Zero(t, plan.Tasks[0].Timeout)
+
+	plan, err = planner.CreatePlan(tmpDir, registry, PlanOptions{
+		Timeouts: map[string]time.Duration{"gofmt": 30 * time.Second},
+	})
+	require.NoError(t, err)
+	require.Len(t, plan.Tasks, 1)
+	assert.Equal(t, 30*time.Second, plan.Tasks[0].Timeout)
 }
 
 func TestExecutionPlanner_CreatePlan_WithFormatOnly(t *testing.T) {
@@ -521,6 +829,115 @@ func TestExecutionPlanner_CreatePlan_WithLintOnly(t *testing.T) {
 	assert.Equal(t, "golint", plan.Tasks[0].Tool.Name())
 }
 
+func TestExecutionPlanner_CreatePlan_CheckMode(t *testing.T) {
+	tmpDir := t.TempDir()
+
+	noop := func(ctx context.Context, files []string, options tools.ExecuteOptions) (*tools.Result, error) {
+		return &tools.Result{Success: true}, nil
+	}
+	checker := tools.NewGofumptTool()
+	writer := &mockTool{name: "golines", language: "Go", toolType: tools.FORMAT, executeFunc: noop, validateFunc: func() error { return nil }}
+	both := &mockTool{name: "fmtlint", language: "Go", toolType: tools.BOTH, executeFunc: noop, validateFunc: func() error { return nil }}
+	linter := &mockTool{name: "golint", language: "Go", toolType: tools.LINT, executeFunc: noop, validateFunc: func() error { return nil }}
+
+	analyzer := &mockAnalyzer{
+		analyzeFunc: func(projectRoot string, reg tools.ToolRegistry) (*AnalysisResult, error) {
+			return &AnalysisResult{
+				ProjectRoot: projectRoot,
+				Languages:   map[string][]string{"Go": {"main.go"}},
+			}, nil
+		},
+		selectionFunc: func(result *AnalysisResult, reg tools.ToolRegistry) map[string][]tools.QualityTool {
+			return map[string][]tools.QualityTool{"Go": {checker, writer, both, linter}}
+		},
+	}
+
+	plan, err := NewExecutionPlanner(analyzer).CreatePlan(tmpDir, &mockRegistry{tools: map[string]tools.QualityTool{}}, PlanOptions{
+		Check: true,
+	})
+
+	require.NoError(t, err)
+	tasks := make(map[string]tools.ExecuteOptions)
+	for _, task := range plan.Tasks {
+		tasks[task.Tool.Name()] = task.Options
+	}
+
+	// Formatters that cannot check without writing are skipped
+	assert.NotContains(t, tasks, "golines")
+	require.Contains(t, tasks, "gofumpt")
+	assert.True(t, tasks["gofumpt"].Check)
+	assert.False(t, tasks["gofumpt"].LintOnly)
+
+	// Combined tools without a check mode only lint
+	require.Contains(t, tasks, "fmtlint")
+	assert.True(t, tasks["fmtlint"].LintOnly)
+	require.Contains(t, tasks, "golint")
+}
+
+func TestExecutionPlanner_CreatePlan_Superseded(t *testing.T) {
+	tmpDir := t.TempDir()
+
+	analyzer := &mockAnalyzer{
+		analyzeFunc: func(projectRoot string, reg tools.ToolRegistry) (*AnalysisResult, error) {
+			return &AnalysisResult{
+				ProjectRoot: projectRoot,
+				Languages:   map[string][]string{"Rust": {"main.rs"}},
+			}, nil
+		},
+		selectionFunc: func(result *AnalysisResult, reg tools.ToolRegistry) map[string][]tools.QualityTool {
+			return map[string][]tools.QualityTool{"Rust": {tools.NewRustfmtTool(), tools.NewClippyTool(), tools.NewCargoCheckTool()}}
+		},
+	}
+	planner := NewExecutionPlanner(analyzer)
+	registry := &mockRegistry{tools: map[string]tools.QualityTool{}}
+
+	planned := func(options PlanOptions) []string {
+		plan, err := planner.CreatePlan(tmpDir, registry, options)
+		require.NoError(t, err)
+		var names []string
+		for _, task := range plan.Tasks {
+			names = append(names, task.Tool.Name())
+		}
+		return names
+	}
+
+	// clippy covers cargo check
+	assert.ElementsMatch(t, []string{"rustfmt", "clippy"}, planned(PlanOptions{}))
+	assert.ElementsMatch(t, []string{"clippy"}, planned(PlanOptions{LintOnly: true}))
+
+	// cargo-check runs when clippy does not
+	assert.ElementsMatch(t, []string{"cargo-check"}, planned(PlanOptions{ToolFilter: []string{"cargo-check"}}))
+}
+
+func TestExecutionPlanner_CreatePlan_BaseRef(t *testing.T) {
+	repoDir := setupTestGitRepo(t)
+	createAndCommitFile(t, repoDir, "lib.rs", "pub fn parse() {}\n")
+	createAndCommitFile(t, repoDir, "lib.rs", "pub fn parse(input: &str) {}\n")
+
+	analyzer := &mockAnalyzer{
+		analyzeFunc: func(projectRoot string, reg tools.ToolRegistry) (*AnalysisResult, error) {
+			return &AnalysisResult{
+				ProjectRoot: projectRoot,
+				Languages:   map[string][]string{"Rust": {"lib.rs"}},
+			}, nil
+		},
+		selectionFunc: func(result *AnalysisResult, reg tools.ToolRegistry) map[string][]tools.QualityTool {
+			return map[string][]tools.QualityTool{"Rust": {tools.NewCargoSemverChecksTool()}}
+		},
+	}
+	planner := NewExecutionPlanner(analyzer)
+	registry := &mockRegistry{tools: map[string]tools.QualityTool{}}
+
+	plan, err := planner.CreatePlan(repoDir, registry, PlanOptions{Since: "HEAD~1"})
+	require.NoError(t, err)
+	require.Len(t, plan.Tasks, 1)
+
+	// The ref is pinned to its commit, which also keys cached results
+	commit, err := git.NewGitUtils(repoDir).ResolveCommit("HEAD~1")
+	require.NoError(t, err)
+	assert.Equal(t, commit, plan.Tasks[0].Options.BaseRef)
+}
+
 // Tests for GitUtils
 
 func TestGitUtils_IsGitRepository(t *testing.T) {
@@ -814,10 +1231,11 @@ func TestIntersectFiles(t *testing.T) {
 
 func TestFilterIssuesByFile(t *testing.T) {
 	tests := []struct {
-		name     string
-		issues   []tools.Issue
-		filePath string
-		expected int
+		name        string
+		issues      []tools.Issue
+		filePath    string
+		projectRoot string
+		expected    int
 	}{
 		{
 			name:     "Empty issues",
@@ -853,15 +1271,35 @@ func TestFilterIssuesByFile(t *testing.T) {
 			filePath: "test.go",
 			expected: 0,
 		},
+		{
+			name: "Relative paths match an absolute file",
+			issues: []tools.Issue{
+				{File: "pkg/test.go", Line: 1, Message: "issue1"},
+				{File: "./pkg/test.go", Line: 3, Message: "issue2"},
+				{File: "/project/pkg/test.go", Line: 4, Message: "issue3"},
+				{File: "test.go", Line: 2, Message: "issue4"},
+			},
+			filePath:    "/project/pkg/test.go",
+			projectRoot: "/project",
+			expected:    3,
+		},
 	}
 
 	for _, tt := range tests {
 		t.Run(tt.name, func(t *testing.T) {
-			result := filterIssuesByFile(tt.issues, tt.filePath)
+			result := filterIssuesByFile(tt.issues, tt.filePath, tt.projectRoot)
 			assert.Len(t, result, tt.expected)
 			for _, issue := range result {
-				assert.Equal(t, tt.filePath, issue.File)
+				assert.Equal(t, issuePath(tt.filePath, tt.projectRoot), issuePath(issue.File, tt.projectRoot))
 			}
 		})
 	}
 }
+
+func TestIssuesWithinFiles(t *testing.T) {
+	files := []string{"/project/a.go", "/project/pkg/b.go"}
+
+	assert.True(t, issuesWithinFiles(nil, files, "/project"))
+	assert.True(t, issuesWithinFiles([]tools.Issue{{File: "a.go"}, {File: "/project/pkg/b.go"}}, files, "/project"))
+	assert.False(t, issuesWithinFiles([]tools.Issue{{File: "a.go"}, {File: "go.mod"}}, files, "/project"))
+}
@@ -0,0 +1,152 @@
+// Copyright (c) 2025 Archmagece
+// SPDX-License-Identifier: MIT
+
+package executor
+
+import (
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+
+	"github.com/Gizzahub/gzh-cli-quality/tools"
+)
+
+// projectMarkers lists, per language, the files that mark the root of a
+// project. Tools run from the nearest such root so they resolve modules,
+// dependencies and configuration the way they would inside that project.
+var projectMarkers = map[string][]string{
+	"Go":         {"go.mod"},
+	"JavaScript": {"package.json"},
+	"TypeScript": {"package.json"},
+	"Python":     {"pyproject.toml"},
+	"Rust":       {"Cargo.toml"},
+}
+
+// rootFinder finds the nearest project root of files below a workspace root.
+type rootFinder struct {
+	workspaceRoot string
+
+	// roots caches the nearest root of a directory per marker set
+	roots map[string]string
+}
+
+// newRootFinder creates a root finder for the workspace at workspaceRoot.
+func newRootFinder(workspaceRoot string) *rootFinder {
+	return &rootFinder{
+		workspaceRoot: filepath.Clean(workspaceRoot),
+		roots:         make(map[string]string),
+	}
+}
+
+// group splits files by the nearest directory containing one of the markers.
+// Files without a marker between them and the workspace root belong to the
+// workspace root. Roots are returned in sorted order.
+func (f *rootFinder) group(files []string, markers []string) ([]string, map[string][]string) {
+	groups := make(map[string][]string)
+	if len(markers) == 0 {
+		groups[f.workspaceRoot] = files
+		return []string{f.workspaceRoot}, groups
+	}
+
+	for _, file := range files {
+		root := f.nearest(filepath.Dir(f.abs(file)), markers)
+		groups[root] = append(groups[root], file)
+	}
+
+	roots := make([]string, 0, len(groups))
+	for root := range groups {
+		roots = append(roots, root)
+	}
+	sort.Strings(roots)
+
+	return roots, groups
+}
+
+// nearest walks up from dir to the workspace root looking for a marker.
+func (f *rootFinder) nearest(dir string, markers []string) string {
+	key := dir + "\x00" + strings.Join(markers, "\x00")
+	if root, ok := f.roots[key]; ok {
+		return root
+	}
+
+	root := f.workspaceRoot
+	if f.within(dir) {
+		for current := dir; ; current = filepath.Dir(current) {
+			if hasMarker(current, markers) {
+				root = current
+				break
+			}
+			if current == f.workspaceRoot || filepath.Dir(current) == current {
+				break
+			}
+		}
+	}
+
+	f.roots[key] = root
+	return root
+}
+
+// within reports whether dir is the workspace root or below it.
+func (f *rootFinder) within(dir string) bool {
+	rel, err := filepath.Rel(f.workspaceRoot, dir)
+	return err == nil && rel != ".." && !strings.HasPrefix(rel, ".."+string(filepath.Separator))
+}
+
+// abs resolves a file path relative to the workspace root.
+func (f *rootFinder) abs(file string) string {
+	if filepath.IsAbs(file) {
+		return filepath.Clean(file)
+	}
+	return filepath.Join(f.workspaceRoot, file)
+}
+
+// hasMarker reports whether dir directly contains one of the markers.
+func hasMarker(dir string, markers []string) bool {
+	for _, marker := range markers {
+		if _, err := os.Stat(filepath.Join(dir, marker)); err == nil {
+			return true
+		}
+	}
+	return false
+}
+
+// findConfigFile returns the tool's config file nearest to root, searching
+// up to the workspace root, or "" if there is none.
+func (f *rootFinder) findConfigFile(tool tools.QualityTool, root string) string {
+	for current := root; ; current = filepath.Dir(current) {
+		if configs := tool.FindConfigFiles(current); len(configs) > 0 {
+			return configs[0]
+		}
+		if current == f.workspaceRoot || !f.within(current) || filepath.Dir(current) == current {
+			return ""
+		}
+	}
+}
+
+// rebaseIssues rewrites issue and edit paths that a tool reported relative to
+// a project root below the workspace so they are relative to the workspace root.
+func rebaseIssues(result *tools.Result, task tools.Task) {
+	root, workspace := task.Options.ProjectRoot, task.WorkspaceRoot
+	if result == nil || root == "" || workspace == "" || filepath.Clean(root) == filepath.Clean(workspace) {
+		return
+	}
+
+	rebase := func(file string) string {
+		if file == "" || filepath.IsAbs(file) {
+			return file
+		}
+		rel, err := filepath.Rel(workspace, filepath.Join(root, file))
+		if err != nil {
+			return file
+		}
+		return rel
+	}
+
+	for i := range result.Issues {
+		result.Issues[i].File = rebase(result.Issues[i].File)
+		for j := range result.Issues[i].Edits {
+			result.Issues[i].Edits[j].File = rebase(result.Issues[i].Edits[j].File)
+		}
+	}
+}
@@ -0,0 +1,143 @@
+// Copyright (c) 2025 Archmagece
+// SPDX-License-Identifier: MIT
+
+package executor
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/Gizzahub/gzh-cli-quality/tools"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+// writeTree creates files (with empty content) below root.
+func writeTree(t *testing.T, root string, files ...string) {
+	t.Helper()
+	for _, file := range files {
+		path := filepath.Join(root, filepath.FromSlash(file))
+		require.NoError(t, os.MkdirAll(filepath.Dir(path), 0o755))
+		require.NoError(t, os.WriteFile(path, nil, 0o644))
+	}
+}
+
+func TestRootFinder_Group(t *testing.T) {
+	parent := t.TempDir()
+	workspace := filepath.Join(parent, "repo")
+	writeTree(t, parent,
+		"go.mod", // above the workspace: never used
+		"repo/tools/gen.go",
+		"repo/services/api/go.mod",
+		"repo/services/api/main.go",
+		"repo/services/api/internal/db/db.go",
+		"repo/services/billing/go.mod",
+		"repo/services/billing/billing.go",
+	)
+
+	finder := newRootFinder(workspace)
+	files := []string{
+		filepath.Join(workspace, "tools", "gen.go"),
+		filepath.Join(workspace, "services", "api", "main.go"),
+		filepath.Join(workspace, "services", "api", "internal", "db", "db.go"),
+		filepath.Join("services", "billing", "billing.go"), // relative to the workspace
+	}
+
+	roots, groups := finder.group(files, projectMarkers["Go"])
+
+	api := filepath.Join(workspace, "services", "api")
+	billing := filepath.Join(workspace, "services", "billing")
+	assert.Equal(t, []string{workspace, api, billing}, roots)
+	assert.Equal(t, []string{files[0]}, groups[workspace])
+	assert.Equal(t, []string{files[1], files[2]}, groups[api])
+	assert.Equal(t, []string{files[3]}, groups[billing])
+
+	// Languages without markers keep everything at the workspace root
+	roots, groups = finder.group(files, nil)
+	assert.Equal(t, []string{workspace}, roots)
+	assert.Equal(t, files, groups[workspace])
+}
+
+func TestExecutionPlanner_CreatePlan_NestedProjects(t *testing.T) {
+	workspace := t.TempDir()
+	writeTree(t, workspace,
+		".golangci.yml",
+		"go.mod",
+		"cmd/main.go",
+		"services/api/go.mod",
+		"services/api/.golangci.yml",
+		"services/api/main.go",
+		"services/worker/go.mod",
+		"services/worker/worker.go",
+	)
+	files := []string{
+		filepath.Join(workspace, "cmd", "main.go"),
+		filepath.Join(workspace, "services", "api", "main.go"),
+		filepath.Join(workspace, "services", "worker", "worker.go"),
+	}
+
+	linter := tools.NewGolangciLintTool()
+	analyzer := &mockAnalyzer{
+		analyzeFunc: func(projectRoot string, reg tools.ToolRegistry) (*AnalysisResult, error) {
+			return &AnalysisResult{
+				ProjectRoot: projectRoot,
+				Languages:   map[string][]string{"Go": files},
+				ConfigFiles: map[string]string{"golangci-lint": filepath.Join(workspace, ".golangci.yml")},
+			}, nil
+		},
+		selectionFunc: func(result *AnalysisResult, reg tools.ToolRegistry) map[string][]tools.QualityTool {
+			return map[string][]tools.QualityTool{"Go": {linter}}
+		},
+	}
+
+	plan, err := NewExecutionPlanner(analyzer).CreatePlan(workspace, &mockRegistry{tools: map[string]tools.QualityTool{}}, PlanOptions{})
+	require.NoError(t, err)
+	require.Len(t, plan.Tasks, 3)
+	assert.Equal(t, 3, plan.TotalFiles)
+
+	api := filepath.Join(workspace, "services", "api")
+	worker := filepath.Join(workspace, "services", "worker")
+	tests := []struct {
+		root   string
+		files  []string
+		config string
+	}{
+		{root: workspace, files: files[:1], config: filepath.Join(workspace, ".golangci.yml")},
+		{root: api, files: files[1:2], config: filepath.Join(api, ".golangci.yml")},
+		// No config of its own: the nearest one above it applies
+		{root: worker, files: files[2:], config: filepath.Join(workspace, ".golangci.yml")},
+	}
+	for i, tt := range tests {
+		task := plan.Tasks[i]
+		assert.Equal(t, tt.root, task.Options.ProjectRoot)
+		assert.Equal(t, tt.files, task.Files)
+		assert.Equal(t, tt.config, task.Options.ConfigFile)
+		assert.Equal(t, workspace, task.WorkspaceRoot)
+	}
+}
+
+func TestRebaseIssues(t *testing.T) {
+	workspace := filepath.Join(string(filepath.Separator), "repo")
+	task := tools.Task{
+		Options:       tools.ExecuteOptions{ProjectRoot: filepath.Join(workspace, "services", "api")},
+		WorkspaceRoot: workspace,
+	}
+	absolute := filepath.Join(workspace, "services", "api", "main.go")
+	result := &tools.Result{Issues: []tools.Issue{
+		{File: "internal/db.go", Edits: []tools.TextEdit{{File: "internal/db.go"}}},
+		{File: absolute},
+	}}
+
+	rebaseIssues(result, task)
+
+	expected := filepath.Join("services", "api", "internal", "db.go")
+	assert.Equal(t, expected, result.Issues[0].File)
+	assert.Equal(t, expected, result.Issues[0].Edits[0].File)
+	assert.Equal(t, absolute, result.Issues[1].File)
+
+	// Tasks at the workspace root are left alone
+	result = &tools.Result{Issues: []tools.Issue{{File: "main.go"}}}
+	rebaseIssues(result, tools.Task{Options: tools.ExecuteOptions{ProjectRoot: workspace}, WorkspaceRoot: workspace})
+	assert.Equal(t, "main.go", result.Issues[0].File)
+}
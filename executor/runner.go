@@ -7,6 +7,8 @@ package executor
 import (
 	"context"
 	"fmt"
+	"path/filepath"
+	"slices"
 	"sort"
 	"sync"
 	"time"
@@ -21,6 +23,8 @@ type ParallelExecutor struct {
 	maxWorkers int
 	timeout    time.Duration
 	cache      cache.Manager
+	prober     *tools.Prober
+	runner     tools.CommandRunner
 }
 
 // NewParallelExecutor creates a new parallel executor.
@@ -36,6 +40,7 @@ func NewParallelExecutor(maxWorkers int, timeout time.Duration) *ParallelExecuto
 		maxWorkers: maxWorkers,
 		timeout:    timeout,
 		cache:      nil, // Cache disabled by default
+		prober:     tools.NewProber(""),
 	}
 }
 
@@ -51,6 +56,18 @@ func (e *ParallelExecutor) SetCache(cacheManager cache.Manager) {
 	e.cache = cacheManager
 }
 
+// SetProber sets the prober used to get tool versions for cache keys, so
+// they are shared with the rest of the run.
+func (e *ParallelExecutor) SetProber(prober *tools.Prober) {
+	e.prober = prober
+}
+
+// SetRunner sets the runner tasks run their commands through, unless a task's
+// options already carry one. Results of tasks with a runner are not cached.
+func (e *ParallelExecutor) SetRunner(runner tools.CommandRunner) {
+	e.runner = runner
+}
+
 // CacheEnabled returns whether caching is enabled and active.
 func (e *ParallelExecutor) CacheEnabled() bool {
 	return e.cache != nil && e.cache.Enabled()
@@ -62,6 +79,8 @@ func (e *ParallelExecutor) Execute(ctx context.Context, plan *tools.ExecutionPla
 }
 
 // ExecuteParallel runs the execution plan with parallel execution.
+// Every task yields a result: tasks interrupted by the executor timeout or by
+// cancellation of ctx are reported as "timed out" or "cancelled".
 func (e *ParallelExecutor) ExecuteParallel(ctx context.Context, plan *tools.ExecutionPlan, workers int) ([]*tools.Result, error) {
 	if workers <= 0 {
 		workers = e.maxWorkers
@@ -70,120 +89,157 @@ func (e *ParallelExecutor) ExecuteParallel(ctx context.Context, plan *tools.Exec
 	// Sort tasks by priority (higher priority first)
 	sortedTasks := make([]tools.Task, len(plan.Tasks))
 	copy(sortedTasks, plan.Tasks)
-	sort.Slice(sortedTasks, func(i, j int) bool {
+	sort.SliceStable(sortedTasks, func(i, j int) bool {
 		return sortedTasks[i].Priority > sortedTasks[j].Priority
 	})
 
-	// Create worker pool
-	taskChan := make(chan tools.Task, len(sortedTasks))
-	resultChan := make(chan *tools.Result, len(sortedTasks))
-	errorChan := make(chan error, len(sortedTasks))
+	// Create worker pool; workers write each result at its task's index
+	taskChan := make(chan int, len(sortedTasks))
+	results := make([]*tools.Result, len(sortedTasks))
+	taskErrors := make([]error, len(sortedTasks))
 
-	// Create timeout context
-	timeoutCtx, cancel := context.WithTimeout(ctx, e.timeout)
-	defer cancel()
+	for i := range sortedTasks {
+		taskChan <- i
+	}
+	close(taskChan)
+
+	// The executor timeout is a run-wide deadline for tasks without a timeout
+	// of their own; the others get their timeout from when they start
+	deadline := time.Now().Add(e.timeout)
 
 	// Start workers
 	var wg sync.WaitGroup
 	for i := 0; i < workers; i++ {
 		wg.Add(1)
-		go e.worker(timeoutCtx, &wg, taskChan, resultChan, errorChan)
+		go e.worker(ctx, deadline, &wg, sortedTasks, taskChan, results, taskErrors)
 	}
 
-	// Send tasks to workers
-	go func() {
-		defer close(taskChan)
-		for _, task := range sortedTasks {
-			select {
-			case taskChan <- task:
-			case <-timeoutCtx.Done():
-				return
-			}
-		}
-	}()
-
-	// Wait for all workers to complete
-	go func() {
-		wg.Wait()
-		close(resultChan)
-		close(errorChan)
-	}()
-
-	// Collect results
-	var results []*tools.Result
-	var errors []error
-
-	for {
-		select {
-		case result, ok := <-resultChan:
-			if !ok {
-				resultChan = nil
-			} else {
-				results = append(results, result)
-			}
-		case err, ok := <-errorChan:
-			if !ok {
-				errorChan = nil
-			} else if err != nil {
-				errors = append(errors, err)
-			}
-		case <-timeoutCtx.Done():
-			return results, fmt.Errorf("execution timed out after %v", e.timeout)
-		}
+	// Wait for all workers to drain the queue; cancelled tasks still report
+	wg.Wait()
 
-		if resultChan == nil && errorChan == nil {
-			break
-		}
+	timedOut := defaultTimeoutExpired(sortedTasks, results)
+
+	// Batches of one split task become a single result again
+	results = mergeBatches(sortedTasks, results)
+
+	switch {
+	case ctx.Err() != nil:
+		return results, fmt.Errorf("execution cancelled: %w", ctx.Err())
+	case timedOut:
+		return results, fmt.Errorf("execution timed out after %v", e.timeout)
 	}
 
 	// Return first error if any occurred
-	if len(errors) > 0 {
-		return results, errors[0]
+	for _, err := range taskErrors {
+		if err != nil {
+			return results, err
+		}
 	}
 
 	return results, nil
 }
 
-// worker processes tasks from the task channel.
-func (e *ParallelExecutor) worker(ctx context.Context, wg *sync.WaitGroup, taskChan <-chan tools.Task, resultChan chan<- *tools.Result, errorChan chan<- error) {
+// worker processes task indexes from taskChan until it is drained.
+func (e *ParallelExecutor) worker(ctx context.Context, deadline time.Time, wg *sync.WaitGroup, tasks []tools.Task, taskChan <-chan int, results []*tools.Result, taskErrors []error) {
 	defer wg.Done()
 
-	for {
-		select {
-		case task, ok := <-taskChan:
-			if !ok {
-				return
-			}
+	for i := range taskChan {
+		results[i], taskErrors[i] = e.runTask(ctx, deadline, tasks[i])
+	}
+}
 
-			var result *tools.Result
-			var err error
+// runTask executes a single task, reporting interrupted tasks instead of
+// dropping them. Tasks without a timeout of their own end at deadline; the
+// others get their timeout from now, however long they were queued.
+func (e *ParallelExecutor) runTask(ctx context.Context, deadline time.Time, task tools.Task) (*tools.Result, error) {
+	// A per-task timeout only interrupts this task, not the whole run, and
+	// may outlast the executor timeout
+	var taskCtx context.Context
+	var cancel context.CancelFunc
+	if task.Timeout > 0 {
+		taskCtx, cancel = context.WithTimeout(ctx, task.Timeout)
+	} else {
+		taskCtx, cancel = context.WithDeadline(ctx, deadline)
+	}
+	defer cancel()
 
-			// Try cache lookup for each file if caching is enabled
-			if e.CacheEnabled() && len(task.Files) > 0 {
-				result, err = e.executeWithCache(ctx, task)
-			} else {
-				// Execute without cache
-				result, err = task.Tool.Execute(ctx, task.Files, task.Options)
-			}
+	// Tasks still queued when their deadline passes never start
+	if err := taskCtx.Err(); err != nil {
+		return interruptedResult(task, err), nil
+	}
 
-			// Send result
-			select {
-			case resultChan <- result:
-			case <-ctx.Done():
-				return
-			}
+	result, err := e.executeTask(taskCtx, task)
+	rebaseIssues(result, task)
 
-			// Send error if any
-			select {
-			case errorChan <- err:
-			case <-ctx.Done():
-				return
-			}
+	if result == nil {
+		result = &tools.Result{
+			Tool:     task.Tool.Name(),
+			Language: task.Tool.Language(),
+			Status:   tools.StatusToolError,
+		}
+		if err != nil {
+			result.Error = err.Error()
+		}
+	}
+
+	// A tool that ignored cancellation is still reported as interrupted
+	if ctxErr := taskCtx.Err(); ctxErr != nil && !result.Success && (result.Error == "" || tools.IsInterrupted(err)) {
+		result.Error = tools.InterruptionReason(ctxErr)
+		result.Status = tools.InterruptionStatus(ctxErr)
+	}
+
+	// Name the task's own limit when it, rather than the run, expired
+	if result.Status == tools.StatusTimeout && ctx.Err() == nil && task.Timeout > 0 {
+		result.Error = fmt.Sprintf("timed out after %v", task.Timeout)
+		err = nil
+	}
+
+	return result, err
+}
+
+// executeTask runs a task's tool, going through the cache when it is enabled.
+func (e *ParallelExecutor) executeTask(ctx context.Context, task tools.Task) (*tools.Result, error) {
+	var result *tools.Result
+	var err error
+
+	if task.Options.Runner == nil {
+		task.Options.Runner = e.runner
+	}
+
+	// Try cache lookup for each file if caching is enabled; runs through
+	// an injected runner are not real runs and bypass the cache
+	if e.CacheEnabled() && len(task.Files) > 0 && task.Options.Runner == nil {
+		result, err = e.executeWithCache(ctx, task)
+	} else {
+		// Execute without cache
+		result, err = task.Tool.Execute(ctx, task.Files, task.Options)
+	}
 
-		case <-ctx.Done():
-			return
+	return result, err
+}
+
+// interruptedResult creates the result for a task that was cancelled or timed out.
+func interruptedResult(task tools.Task, err error) *tools.Result {
+	return &tools.Result{
+		Tool:     task.Tool.Name(),
+		Language: task.Tool.Language(),
+		Success:  false,
+		Status:   tools.InterruptionStatus(err),
+		ExitCode: -1,
+		Error:    tools.InterruptionReason(err),
+		Issues:   []tools.Issue{},
+	}
+}
+
+// defaultTimeoutExpired reports whether a task bound by the executor timeout
+// rather than its own timed out.
+func defaultTimeoutExpired(tasks []tools.Task, results []*tools.Result) bool {
+	for i, task := range tasks {
+		if task.Timeout <= 0 && results[i] != nil && results[i].Status == tools.StatusTimeout {
+			return true
 		}
 	}
+	return false
 }
 
 // executeWithCache executes a task with cache support.
@@ -194,19 +250,61 @@ func (e *ParallelExecutor) executeWithCache(ctx context.Context, task tools.Task
 		return task.Tool.Execute(ctx, task.Files, task.Options)
 	}
 
+	// The version is part of every file's key; probe it once per task
+	toolVersion, err := e.prober.Version(task.Tool)
+	if err != nil {
+		// This will cause cache misses, which is safe
+		toolVersion = "unknown"
+	}
+
+	// Project-wide findings cannot be split by file; cache the whole task
+	if scoped, ok := task.Tool.(tools.ProjectScoped); ok {
+		return e.executeTaskWithCache(ctx, task, toolVersion, scoped.ProjectInputs(task.Files, task.Options.ProjectRoot))
+	}
+
+	// A command that does not take the files reports on more than them, so
+	// its findings cannot be cached per file either
+	if fileless, ok := task.Tool.(tools.FilelessCommand); ok && fileless.OmitsFiles() {
+		return task.Tool.Execute(ctx, task.Files, task.Options)
+	}
+
 	// For single file, try direct cache lookup
 	if len(task.Files) == 1 {
-		return e.executeSingleFileWithCache(ctx, task.Tool, task.Files[0], task.Options)
+		return e.executeSingleFileWithCache(ctx, task.Tool, toolVersion, task.Files[0], task.Options)
 	}
 
 	// For multiple files, process each file individually for better cache granularity
-	return e.executeMultiFileWithCache(ctx, task)
+	return e.executeMultiFileWithCache(ctx, task, toolVersion)
+}
+
+// executeTaskWithCache executes a task with a single cache entry for all its
+// files, keyed on them and on the tool's project inputs.
+func (e *ParallelExecutor) executeTaskWithCache(ctx context.Context, task tools.Task, toolVersion string, inputs []string) (*tools.Result, error) {
+	cacheKey, keyErr := cache.GenerateTaskKey(task.Files, inputs, task.Tool, toolVersion, task.Options)
+	if keyErr == nil {
+		if cached, getErr := e.cache.Get(cacheKey); getErr == nil {
+			cachedResult := cached.Result
+			cachedResult.Cached = true
+			return cachedResult, nil
+		}
+	}
+
+	result, err := task.Tool.Execute(ctx, task.Files, task.Options)
+	if err != nil {
+		return result, err
+	}
+
+	if result.Success && keyErr == nil {
+		_ = e.cache.Set(cacheKey, result) // Fire and forget
+	}
+
+	return result, nil
 }
 
 // executeSingleFileWithCache executes a tool on a single file with cache support.
-func (e *ParallelExecutor) executeSingleFileWithCache(ctx context.Context, tool tools.QualityTool, filePath string, options tools.ExecuteOptions) (*tools.Result, error) {
+func (e *ParallelExecutor) executeSingleFileWithCache(ctx context.Context, tool tools.QualityTool, toolVersion, filePath string, options tools.ExecuteOptions) (*tools.Result, error) {
 	// Generate cache key
-	cacheKey, keyErr := cache.GenerateKey(filePath, tool, options)
+	cacheKey, keyErr := cache.GenerateKeyWithVersion(filePath, tool, toolVersion, options)
 	if keyErr == nil {
 		// Try cache lookup
 		if cached, getErr := e.cache.Get(cacheKey); getErr == nil {
@@ -223,7 +321,7 @@ func (e *ParallelExecutor) executeSingleFileWithCache(ctx context.Context, tool
 		return result, err
 	}
 
-	// Store successful result in cache
+	// Store clean and findings results in cache; the cache drops failures
 	if result.Success && keyErr == nil {
 		_ = e.cache.Set(cacheKey, result) // Fire and forget
 	}
@@ -233,13 +331,13 @@ func (e *ParallelExecutor) executeSingleFileWithCache(ctx context.Context, tool
 
 // executeMultiFileWithCache processes multiple files with cache support.
 // It checks cache for each file and only executes the tool for cache misses.
-func (e *ParallelExecutor) executeMultiFileWithCache(ctx context.Context, task tools.Task) (*tools.Result, error) {
+func (e *ParallelExecutor) executeMultiFileWithCache(ctx context.Context, task tools.Task, toolVersion string) (*tools.Result, error) {
 	var cachedResults []*tools.Result
 	var uncachedFiles []string
 
 	// Check cache for each file
 	for _, filePath := range task.Files {
-		cacheKey, keyErr := cache.GenerateKey(filePath, task.Tool, task.Options)
+		cacheKey, keyErr := cache.GenerateKeyWithVersion(filePath, task.Tool, toolVersion, task.Options)
 		if keyErr != nil {
 			// Can't generate key, add to uncached
 			uncachedFiles = append(uncachedFiles, filePath)
@@ -275,19 +373,26 @@ func (e *ParallelExecutor) executeMultiFileWithCache(ctx context.Context, task t
 	}
 
 	// Store successful result in cache for each uncached file
-	// Split the result by file to store file-specific issues only
-	if result.Success {
+	// Split the result by file to store file-specific issues only; a result
+	// with findings outside these files (e.g. a manifest) cannot be split
+	projectRoot := task.Options.ProjectRoot
+	if result.Success && issuesWithinFiles(result.Issues, uncachedFiles, projectRoot) {
 		for _, filePath := range uncachedFiles {
-			cacheKey, keyErr := cache.GenerateKey(filePath, task.Tool, task.Options)
+			cacheKey, keyErr := cache.GenerateKeyWithVersion(filePath, task.Tool, toolVersion, task.Options)
 			if keyErr == nil {
 				// Create file-specific result with only issues for this file
+				fileIssues := filterIssuesByFile(result.Issues, filePath, projectRoot)
 				fileResult := &tools.Result{
 					Tool:           result.Tool,
 					Language:       result.Language,
 					Success:        result.Success,
+					Status:         tools.StatusClean,
 					FilesProcessed: 1,
 					Duration:       result.Duration / time.Duration(len(uncachedFiles)), // Approximate per-file duration
-					Issues:         filterIssuesByFile(result.Issues, filePath),
+					Issues:         fileIssues,
+				}
+				if len(fileIssues) > 0 {
+					fileResult.Status = tools.StatusIssues
 				}
 				_ = e.cache.Set(cacheKey, fileResult) // Fire and forget
 			}
@@ -300,16 +405,43 @@ func (e *ParallelExecutor) executeMultiFileWithCache(ctx context.Context, task t
 }
 
 // filterIssuesByFile returns only issues that belong to the specified file.
-func filterIssuesByFile(issues []tools.Issue, filePath string) []tools.Issue {
+// Relative paths, as most linters report them, are resolved against projectRoot.
+func filterIssuesByFile(issues []tools.Issue, filePath, projectRoot string) []tools.Issue {
+	target := issuePath(filePath, projectRoot)
+
 	var filtered []tools.Issue
 	for _, issue := range issues {
-		if issue.File == filePath {
+		if issuePath(issue.File, projectRoot) == target {
 			filtered = append(filtered, issue)
 		}
 	}
 	return filtered
 }
 
+// issuesWithinFiles reports whether every issue belongs to one of files.
+func issuesWithinFiles(issues []tools.Issue, files []string, projectRoot string) bool {
+	paths := make(map[string]bool, len(files))
+	for _, file := range files {
+		paths[issuePath(file, projectRoot)] = true
+	}
+
+	for _, issue := range issues {
+		if !paths[issuePath(issue.File, projectRoot)] {
+			return false
+		}
+	}
+	return true
+}
+
+// issuePath resolves a file path against projectRoot so that relative and
+// absolute paths of the same file compare equal.
+func issuePath(file, projectRoot string) string {
+	if file != "" && !filepath.IsAbs(file) && projectRoot != "" {
+		file = filepath.Join(projectRoot, file)
+	}
+	return filepath.Clean(file)
+}
+
 // mergeResults merges multiple results into a single result.
 func mergeResults(results []*tools.Result, tool tools.QualityTool) *tools.Result {
 	if len(results) == 0 {
@@ -348,6 +480,11 @@ func mergeResults(results []*tools.Result, tool tools.QualityTool) *tools.Result
 			}
 		}
 
+		merged.Status = tools.WorseStatus(merged.Status, r.EffectiveStatus())
+		if merged.ExitCode == 0 {
+			merged.ExitCode = r.ExitCode
+		}
+
 		merged.FilesProcessed += r.FilesProcessed
 		merged.Issues = append(merged.Issues, r.Issues...)
 		totalDuration += r.Duration
@@ -362,6 +499,8 @@ func mergeResults(results []*tools.Result, tool tools.QualityTool) *tools.Result
 			}
 			merged.Output += r.Output
 		}
+		merged.Stdout += r.Stdout
+		merged.Stderr += r.Stderr
 	}
 
 	merged.Duration = totalDuration
@@ -396,6 +535,16 @@ func (p *ExecutionPlanner) CreatePlan(projectRoot string, registry tools.ToolReg
 
 	var tasks []tools.Task
 	totalFiles := 0
+	finder := newRootFinder(projectRoot)
+
+	// Tools comparing against --since get the commit it points to, so a
+	// moving ref such as HEAD~1 never reuses stale cached results
+	baseRef := options.Since
+	if baseRef != "" {
+		if commit, err := git.NewGitUtils(projectRoot).ResolveCommit(baseRef); err == nil {
+			baseRef = commit
+		}
+	}
 
 	// Create tasks for each language
 	for language, toolList := range selection {
@@ -414,8 +563,11 @@ func (p *ExecutionPlanner) CreatePlan(projectRoot string, registry tools.ToolReg
 			continue
 		}
 
+		// Tools run from the nearest project root of each group of files
+		roots, groups := finder.group(files, projectMarkers[language])
+
 		// Create tasks for each tool
-		for _, tool := range toolList {
+		for _, tool := range dropSuperseded(toolList, options) {
 			// Skip if tool type doesn't match options
 			if !matchesToolType(tool, options) {
 				continue
@@ -431,19 +583,14 @@ func (p *ExecutionPlanner) CreatePlan(projectRoot string, registry tools.ToolReg
 				continue
 			}
 
-			// Create execution options
-			execOptions := tools.ExecuteOptions{
-				ProjectRoot: projectRoot,
-				Fix:         options.Fix,
-				FormatOnly:  options.FormatOnly,
-				LintOnly:    options.LintOnly,
-				ExtraArgs:   options.ExtraArgs,
-				Env:         options.Env,
-			}
-
-			// Set config file if found
-			if configFile, exists := analysis.ConfigFiles[tool.Name()]; exists {
-				execOptions.ConfigFile = configFile
+			// Check mode must never write: formatting is only verified by
+			// tools that can do so without modifying files
+			lintOnly := options.LintOnly
+			if options.Check && tool.Type() != tools.LINT && !supportsCheck(tool) {
+				if tool.Type() == tools.FORMAT || options.FormatOnly {
+					continue
+				}
+				lintOnly = true
 			}
 
 			// Determine priority
@@ -457,15 +604,44 @@ func (p *ExecutionPlanner) CreatePlan(projectRoot string, registry tools.ToolReg
 				priority = 7 // BOTH tools run in between
 			}
 
-			task := tools.Task{
-				Tool:     tool,
-				Files:    files,
-				Options:  execOptions,
-				Priority: priority,
-			}
+			// One task per (tool, project root)
+			for _, root := range roots {
+				rootFiles := groups[root]
+
+				// Create execution options
+				execOptions := tools.ExecuteOptions{
+					ProjectRoot: root,
+					Fix:         options.Fix,
+					FormatOnly:  options.FormatOnly,
+					LintOnly:    lintOnly,
+					Check:       options.Check,
+					ExtraArgs:   options.ExtraArgs,
+					Env:         options.Env,
+					BaseRef:     baseRef,
+				}
 
-			tasks = append(tasks, task)
-			totalFiles += len(files)
+				// Set config file if found; nested projects use their nearest config
+				if root == finder.workspaceRoot {
+					if configFile, exists := analysis.ConfigFiles[tool.Name()]; exists {
+						execOptions.ConfigFile = configFile
+					}
+				} else {
+					execOptions.ConfigFile = finder.findConfigFile(tool, root)
+				}
+
+				task := tools.Task{
+					Tool:          tool,
+					Files:         rootFiles,
+					Options:       execOptions,
+					Priority:      priority,
+					Timeout:       options.Timeouts[tool.Name()],
+					WorkspaceRoot: finder.workspaceRoot,
+				}
+
+				// Split huge file lists so argv stays below the OS limit
+				tasks = append(tasks, splitTask(task, len(tasks), options.MaxArgBytes)...)
+				totalFiles += len(rootFiles)
+			}
 		}
 	}
 
@@ -482,14 +658,17 @@ func (p *ExecutionPlanner) CreatePlan(projectRoot string, registry tools.ToolReg
 
 // PlanOptions contains options for creating execution plans.
 type PlanOptions struct {
-	Files      []string          // Specific files to process
-	Fix        bool              // Auto-fix issues if supported
-	FormatOnly bool              // Run only formatters
-	LintOnly   bool              // Run only linters
-	ExtraArgs  []string          // Extra arguments to pass to tools
-	Env        map[string]string // Environment variables
-	Language   string            // Filter by specific language
-	ToolFilter []string          // Filter by specific tool names
+	Files       []string                 // Specific files to process
+	Fix         bool                     // Auto-fix issues if supported
+	FormatOnly  bool                     // Run only formatters
+	LintOnly    bool                     // Run only linters
+	Check       bool                     // Verify formatting without modifying files
+	ExtraArgs   []string                 // Extra arguments to pass to tools
+	Env         map[string]string        // Environment variables
+	Timeouts    map[string]time.Duration // Per-tool task timeouts keyed by tool name
+	MaxArgBytes int                      // Argv budget per command (0 = DefaultMaxArgBytes)
+	Language    string                   // Filter by specific language
+	ToolFilter  []string                 // Filter by specific tool names
 	// Git-based options
 	Since   string // Process files changed since this commit
 	Staged  bool   // Process only staged files
@@ -512,6 +691,33 @@ func matchesToolType(tool tools.QualityTool, options PlanOptions) bool {
 	return true
 }
 
+// dropSuperseded removes the tools whose findings another tool passing the
+// filters reports as well, e.g. cargo-check when clippy runs.
+func dropSuperseded(toolList []tools.QualityTool, options PlanOptions) []tools.QualityTool {
+	running := make(map[string]bool)
+	for _, tool := range toolList {
+		if matchesToolType(tool, options) && matchesLanguageFilter(tool, options) && matchesToolFilter(tool, options) {
+			running[tool.Name()] = true
+		}
+	}
+
+	kept := make([]tools.QualityTool, 0, len(toolList))
+	for _, tool := range toolList {
+		superseded, ok := tool.(tools.Superseded)
+		if ok && slices.ContainsFunc(superseded.SupersededBy(), func(name string) bool { return running[name] }) {
+			continue
+		}
+		kept = append(kept, tool)
+	}
+	return kept
+}
+
+// supportsCheck reports whether a tool can verify formatting without writing.
+func supportsCheck(tool tools.QualityTool) bool {
+	checker, ok := tool.(tools.FormatChecker)
+	return ok && checker.SupportsCheck()
+}
+
 // matchesLanguageFilter checks if a tool matches the language filter.
 func matchesLanguageFilter(tool tools.QualityTool, options PlanOptions) bool {
 	if options.Language == "" {
@@ -0,0 +1,271 @@
+// Copyright (c) 2025 Archmagece
+// SPDX-License-Identifier: MIT
+
+// Package fixer applies the machine-applicable edits attached to issues.
+package fixer
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"slices"
+	"sort"
+	"strings"
+
+	"github.com/Gizzahub/gzh-cli-quality/tools"
+)
+
+// Options selects which edits are applied.
+type Options struct {
+	// ProjectRoot resolves relative file paths
+	ProjectRoot string
+
+	// Rules limits fixes to these rules (empty means all)
+	Rules []string
+
+	// Files limits fixes to files matching these paths or glob patterns (empty means all)
+	Files []string
+
+	// Unsafe also applies edits that may change behaviour
+	Unsafe bool
+}
+
+// FileChange is the fixed content of one file.
+type FileChange struct {
+	// Path is the file path relative to the project root when possible
+	Path string
+
+	// Original and Fixed are the file content before and after the edits
+	Original string
+	Fixed    string
+
+	// Fixes is the number of issues fixed in the file
+	Fixes int
+
+	absPath string
+}
+
+// Skipped records an issue whose edits were not applied.
+type Skipped struct {
+	Issue  tools.Issue
+	Reason string
+}
+
+// Plan is the set of changes selected from a list of issues.
+type Plan struct {
+	Changes []FileChange
+	Skipped []Skipped
+}
+
+// byteRange is a resolved edit.
+type byteRange struct {
+	start, end  int
+	replacement string
+}
+
+// Prepare resolves the edits of the selected issues against the files on
+// disk. An issue's edits are applied together or not at all, and an issue
+// whose edits overlap an earlier accepted fix is skipped.
+func Prepare(issues []tools.Issue, options Options) *Plan {
+	plan := &Plan{}
+	contents := make(map[string][]byte)
+	accepted := make(map[string][]byteRange)
+	fixes := make(map[string]int)
+	var order []string
+
+	for _, issue := range issues {
+		if len(issue.Edits) == 0 || !options.selects(issue) {
+			continue
+		}
+
+		if reason := options.rejects(issue.Edits); reason != "" {
+			plan.Skipped = append(plan.Skipped, Skipped{Issue: issue, Reason: reason})
+			continue
+		}
+
+		ranges := make(map[string][]byteRange)
+		reason := ""
+		for _, edit := range issue.Edits {
+			path := options.resolve(edit.File)
+			content, ok := contents[path]
+			if !ok {
+				data, err := os.ReadFile(path)
+				if err != nil {
+					reason = fmt.Sprintf("failed to read %s: %v", edit.File, err)
+					break
+				}
+				contents[path] = data
+				order = append(order, path)
+				content = data
+			}
+
+			start, end, err := edit.Resolve(content)
+			if err != nil {
+				reason = err.Error()
+				break
+			}
+
+			r := byteRange{start: start, end: end, replacement: edit.Replacement}
+			if overlapsAny(r, ranges[path]) || overlapsAny(r, accepted[path]) {
+				reason = "overlaps another fix"
+				break
+			}
+			ranges[path] = append(ranges[path], r)
+		}
+
+		if reason != "" {
+			plan.Skipped = append(plan.Skipped, Skipped{Issue: issue, Reason: reason})
+			continue
+		}
+		for path, rs := range ranges {
+			accepted[path] = append(accepted[path], rs...)
+			fixes[path]++
+		}
+	}
+
+	for _, path := range order {
+		if len(accepted[path]) == 0 {
+			continue
+		}
+		original := contents[path]
+		plan.Changes = append(plan.Changes, FileChange{
+			Path:     options.display(path),
+			Original: string(original),
+			Fixed:    apply(original, accepted[path]),
+			Fixes:    fixes[path],
+			absPath:  path,
+		})
+	}
+
+	return plan
+}
+
+// Fixes returns the number of issues the plan fixes.
+func (p *Plan) Fixes() int {
+	total := 0
+	for _, change := range p.Changes {
+		total += change.Fixes
+	}
+	return total
+}
+
+// Diff returns a unified diff of all changes.
+func (p *Plan) Diff() string {
+	var sb strings.Builder
+	for _, change := range p.Changes {
+		sb.WriteString(tools.UnifiedDiff(change.Path, change.Original, change.Fixed))
+	}
+	return sb.String()
+}
+
+// Write writes the fixed content of every changed file, refusing files that
+// changed on disk since Prepare read them.
+func (p *Plan) Write() error {
+	for _, change := range p.Changes {
+		info, err := os.Stat(change.absPath)
+		if err != nil {
+			return fmt.Errorf("failed to stat %s: %w", change.Path, err)
+		}
+		current, err := os.ReadFile(change.absPath)
+		if err != nil {
+			return fmt.Errorf("failed to read %s: %w", change.Path, err)
+		}
+		if string(current) != change.Original {
+			return fmt.Errorf("%s changed since it was checked; re-run to fix it", change.Path)
+		}
+		if err := os.WriteFile(change.absPath, []byte(change.Fixed), info.Mode().Perm()); err != nil {
+			return fmt.Errorf("failed to write %s: %w", change.Path, err)
+		}
+	}
+	return nil
+}
+
+// selects reports whether the issue matches the rule and file filters.
+func (o Options) selects(issue tools.Issue) bool {
+	if len(o.Rules) > 0 && !slices.Contains(o.Rules, issue.Rule) {
+		return false
+	}
+	if len(o.Files) == 0 {
+		return true
+	}
+
+	path := o.display(o.resolve(issue.File))
+	for _, pattern := range o.Files {
+		if pattern == issue.File || pattern == path {
+			return true
+		}
+		if matched, _ := filepath.Match(pattern, path); matched {
+			return true
+		}
+		if matched, _ := filepath.Match(pattern, filepath.Base(path)); matched {
+			return true
+		}
+	}
+	return false
+}
+
+// rejects returns why edits may not be applied under these options, or "".
+func (o Options) rejects(edits []tools.TextEdit) string {
+	for _, edit := range edits {
+		switch edit.Applicability {
+		case tools.ApplicabilitySafe:
+		case tools.ApplicabilityUnsafe:
+			if !o.Unsafe {
+				return "unsafe fix (use --unsafe to apply)"
+			}
+		default:
+			return "fix needs manual review"
+		}
+	}
+	return ""
+}
+
+// resolve returns the absolute path of a tool-reported file.
+func (o Options) resolve(file string) string {
+	if !filepath.IsAbs(file) && o.ProjectRoot != "" {
+		file = filepath.Join(o.ProjectRoot, file)
+	}
+	return filepath.Clean(file)
+}
+
+// display returns path relative to the project root when it is inside it.
+func (o Options) display(path string) string {
+	if o.ProjectRoot == "" {
+		return path
+	}
+	rel, err := filepath.Rel(o.ProjectRoot, path)
+	if err != nil || strings.HasPrefix(rel, "..") {
+		return path
+	}
+	return filepath.ToSlash(rel)
+}
+
+// overlapsAny reports whether r overlaps any of ranges. Two insertions at
+// the same point overlap because their order would be ambiguous.
+func overlapsAny(r byteRange, ranges []byteRange) bool {
+	for _, other := range ranges {
+		if r.start < other.end && other.start < r.end {
+			return true
+		}
+		if r.start == other.start && (r.start == r.end || other.start == other.end) {
+			return true
+		}
+	}
+	return false
+}
+
+// apply replaces the given non-overlapping ranges in content.
+func apply(content []byte, ranges []byteRange) string {
+	sorted := append([]byteRange(nil), ranges...)
+	sort.Slice(sorted, func(i, j int) bool { return sorted[i].start < sorted[j].start })
+
+	var sb strings.Builder
+	last := 0
+	for _, r := range sorted {
+		sb.Write(content[last:r.start])
+		sb.WriteString(r.replacement)
+		last = r.end
+	}
+	sb.Write(content[last:])
+	return sb.String()
+}
@@ -0,0 +1,150 @@
+// Copyright (c) 2025 Archmagece
+// SPDX-License-Identifier: MIT
+
+package fixer
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/Gizzahub/gzh-cli-quality/tools"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func writeFile(t *testing.T, dir, name, content string) string {
+	t.Helper()
+	path := filepath.Join(dir, name)
+	require.NoError(t, os.WriteFile(path, []byte(content), 0o644))
+	return path
+}
+
+func lineEdit(file string, line int, replacement string, applicability tools.Applicability) tools.TextEdit {
+	return tools.TextEdit{
+		File:          file,
+		StartLine:     line,
+		StartColumn:   1,
+		EndLine:       line + 1,
+		EndColumn:     1,
+		Replacement:   replacement,
+		Applicability: applicability,
+	}
+}
+
+func TestPrepare_AppliesSafeEdits(t *testing.T) {
+	dir := t.TempDir()
+	writeFile(t, dir, "main.py", "import os\nimport sys\nx=1\n")
+
+	issues := []tools.Issue{
+		{File: "main.py", Rule: "F401", Edits: []tools.TextEdit{lineEdit("main.py", 1, "", tools.ApplicabilitySafe)}},
+		{File: "main.py", Rule: "E225", Edits: []tools.TextEdit{{
+			File: "main.py", StartLine: 3, StartColumn: 2, EndLine: 3, EndColumn: 3,
+			Replacement: " = ", Applicability: tools.ApplicabilitySafe,
+		}}},
+		{File: "main.py", Rule: "E501"}, // no edits
+	}
+
+	plan := Prepare(issues, Options{ProjectRoot: dir})
+
+	require.Len(t, plan.Changes, 1)
+	assert.Equal(t, "main.py", plan.Changes[0].Path)
+	assert.Equal(t, "import sys\nx = 1\n", plan.Changes[0].Fixed)
+	assert.Equal(t, 2, plan.Fixes())
+	assert.Empty(t, plan.Skipped)
+	assert.Contains(t, plan.Diff(), "-import os\n")
+}
+
+func TestPrepare_SkipsOverlappingAndUnsafeEdits(t *testing.T) {
+	dir := t.TempDir()
+	writeFile(t, dir, "lib.rs", "let a = b.clone();\n")
+
+	clone := tools.TextEdit{File: "lib.rs", StartOffset: 9, EndOffset: 17, Replacement: "", Applicability: tools.ApplicabilitySafe}
+	issues := []tools.Issue{
+		{File: "lib.rs", Rule: "clippy::redundant_clone", Edits: []tools.TextEdit{clone}},
+		{File: "lib.rs", Rule: "clippy::other", Edits: []tools.TextEdit{clone}},
+		{File: "lib.rs", Rule: "clippy::maybe", Edits: []tools.TextEdit{{
+			File: "lib.rs", StartOffset: 0, EndOffset: 3, Replacement: "const", Applicability: tools.ApplicabilityUnsafe,
+		}}},
+		{File: "lib.rs", Rule: "clippy::manual", Edits: []tools.TextEdit{{
+			File: "lib.rs", StartOffset: 0, EndOffset: 0, Replacement: "/* ... */", Applicability: tools.ApplicabilityManual,
+		}}},
+	}
+
+	plan := Prepare(issues, Options{ProjectRoot: dir})
+
+	require.Len(t, plan.Changes, 1)
+	assert.Equal(t, "let a = b;\n", plan.Changes[0].Fixed)
+	require.Len(t, plan.Skipped, 3)
+	assert.Equal(t, "overlaps another fix", plan.Skipped[0].Reason)
+	assert.Contains(t, plan.Skipped[1].Reason, "unsafe")
+	assert.Contains(t, plan.Skipped[2].Reason, "manual")
+
+	// --unsafe applies the behaviour-changing edit as well
+	plan = Prepare(issues, Options{ProjectRoot: dir, Unsafe: true})
+	assert.Equal(t, "const a = b;\n", plan.Changes[0].Fixed)
+}
+
+func TestPrepare_IssueEditsAreAtomic(t *testing.T) {
+	dir := t.TempDir()
+	writeFile(t, dir, "a.js", "foo(1)\n")
+
+	issues := []tools.Issue{
+		{File: "a.js", Rule: "one", Edits: []tools.TextEdit{
+			{File: "a.js", StartOffset: 0, EndOffset: 3, Replacement: "bar", Applicability: tools.ApplicabilitySafe},
+		}},
+		// The second edit overlaps the first issue, so neither edit of this issue applies
+		{File: "a.js", Rule: "two", Edits: []tools.TextEdit{
+			{File: "a.js", StartOffset: 6, EndOffset: 6, Replacement: ";", Applicability: tools.ApplicabilitySafe},
+			{File: "a.js", StartOffset: 1, EndOffset: 2, Replacement: "O", Applicability: tools.ApplicabilitySafe},
+		}},
+	}
+
+	plan := Prepare(issues, Options{ProjectRoot: dir})
+
+	require.Len(t, plan.Changes, 1)
+	assert.Equal(t, "bar(1)\n", plan.Changes[0].Fixed)
+	require.Len(t, plan.Skipped, 1)
+	assert.Equal(t, "two", plan.Skipped[0].Issue.Rule)
+}
+
+func TestPrepare_FiltersByRuleAndFile(t *testing.T) {
+	dir := t.TempDir()
+	require.NoError(t, os.MkdirAll(filepath.Join(dir, "src"), 0o755))
+	writeFile(t, dir, "src/a.py", "a\n")
+	writeFile(t, dir, "src/b.py", "b\n")
+
+	issues := []tools.Issue{
+		{File: "src/a.py", Rule: "F401", Edits: []tools.TextEdit{lineEdit("src/a.py", 1, "A\n", tools.ApplicabilitySafe)}},
+		{File: "src/b.py", Rule: "F401", Edits: []tools.TextEdit{lineEdit("src/b.py", 1, "B\n", tools.ApplicabilitySafe)}},
+		{File: "src/b.py", Rule: "I001", Edits: []tools.TextEdit{lineEdit("src/b.py", 1, "b2\n", tools.ApplicabilitySafe)}},
+	}
+
+	plan := Prepare(issues, Options{ProjectRoot: dir, Rules: []string{"F401"}})
+	require.Len(t, plan.Changes, 2)
+	assert.Equal(t, "B\n", plan.Changes[1].Fixed)
+
+	plan = Prepare(issues, Options{ProjectRoot: dir, Files: []string{"src/b.*"}, Rules: []string{"I001"}})
+	require.Len(t, plan.Changes, 1)
+	assert.Equal(t, "src/b.py", plan.Changes[0].Path)
+	assert.Equal(t, "b2\n", plan.Changes[0].Fixed)
+}
+
+func TestPlan_Write(t *testing.T) {
+	dir := t.TempDir()
+	path := writeFile(t, dir, "main.go", "x:=1\n")
+
+	issues := []tools.Issue{{File: path, Edits: []tools.TextEdit{lineEdit(path, 1, "x := 1\n", tools.ApplicabilitySafe)}}}
+
+	plan := Prepare(issues, Options{ProjectRoot: dir})
+	require.NoError(t, plan.Write())
+
+	content, err := os.ReadFile(path)
+	require.NoError(t, err)
+	assert.Equal(t, "x := 1\n", string(content))
+
+	// A file edited after Prepare is left alone
+	plan = Prepare([]tools.Issue{{File: path, Edits: []tools.TextEdit{lineEdit(path, 1, "y := 2\n", tools.ApplicabilitySafe)}}}, Options{})
+	require.NoError(t, os.WriteFile(path, []byte("z := 3\n"), 0o644))
+	assert.Error(t, plan.Write())
+}
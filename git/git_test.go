@@ -503,3 +503,66 @@ func TestGetAllChangedFiles_Deduplication(t *testing.T) {
 	}
 	assert.Equal(t, 1, count, "File should appear only once after deduplication")
 }
+
+func TestGetRepositoryRoot_FromSubdirectory(t *testing.T) {
+	repoDir := setupGitRepo(t)
+	createAndCommitFile(t, repoDir, "file.txt", "content")
+	subDir := filepath.Join(repoDir, "crates", "core")
+	require.NoError(t, os.MkdirAll(subDir, 0o755))
+
+	root, err := NewGitUtils(subDir).GetRepositoryRoot()
+	require.NoError(t, err)
+
+	expected, err := filepath.EvalSymlinks(repoDir)
+	require.NoError(t, err)
+	assert.Equal(t, expected, root)
+}
+
+func TestResolveCommit(t *testing.T) {
+	repoDir := setupGitRepo(t)
+	createAndCommitFile(t, repoDir, "file1.txt", "content1")
+	createAndCommitFile(t, repoDir, "file2.txt", "content2")
+
+	gitUtils := NewGitUtils(repoDir)
+
+	head, err := gitUtils.ResolveCommit("HEAD")
+	require.NoError(t, err)
+	assert.Len(t, head, 40)
+
+	parent, err := gitUtils.ResolveCommit("HEAD~1")
+	require.NoError(t, err)
+	assert.NotEqual(t, head, parent)
+
+	_, err = gitUtils.ResolveCommit("nonexistent-ref")
+	assert.Error(t, err)
+}
+
+func TestAddWorktree(t *testing.T) {
+	repoDir := setupGitRepo(t)
+	createAndCommitFile(t, repoDir, "file.txt", "original")
+	createAndCommitFile(t, repoDir, "file.txt", "changed")
+
+	gitUtils := NewGitUtils(repoDir)
+	worktree := filepath.Join(t.TempDir(), "baseline")
+
+	require.NoError(t, gitUtils.AddWorktree("HEAD~1", worktree))
+
+	// The worktree holds the old revision, the working tree the new one
+	content, err := os.ReadFile(filepath.Join(worktree, "file.txt"))
+	require.NoError(t, err)
+	assert.Equal(t, "original", string(content))
+	content, err = os.ReadFile(filepath.Join(repoDir, "file.txt"))
+	require.NoError(t, err)
+	assert.Equal(t, "changed", string(content))
+
+	require.NoError(t, gitUtils.RemoveWorktree(worktree))
+	assert.NoDirExists(t, worktree)
+}
+
+func TestAddWorktree_InvalidRef(t *testing.T) {
+	repoDir := setupGitRepo(t)
+	createAndCommitFile(t, repoDir, "file.txt", "content")
+
+	err := NewGitUtils(repoDir).AddWorktree("nonexistent-ref", filepath.Join(t.TempDir(), "baseline"))
+	assert.Error(t, err)
+}
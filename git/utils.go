@@ -158,6 +158,71 @@ func (g *GitUtils) GetCurrentBranch() (string, error) {
 	return strings.TrimSpace(string(output)), nil
 }
 
+// GetRepositoryRoot returns the top-level directory of the working tree
+func (g *GitUtils) GetRepositoryRoot() (string, error) {
+	if !g.IsGitRepository() {
+		return "", fmt.Errorf("not a git repository")
+	}
+
+	cmd := exec.Command("git", "rev-parse", "--show-toplevel")
+	cmd.Dir = g.projectRoot
+
+	output, err := cmd.Output()
+	if err != nil {
+		return "", fmt.Errorf("failed to get repository root: %w", err)
+	}
+
+	return strings.TrimSpace(string(output)), nil
+}
+
+// ResolveCommit returns the full hash of the commit a commit-ish reference
+// points to
+func (g *GitUtils) ResolveCommit(commitish string) (string, error) {
+	if !g.IsGitRepository() {
+		return "", fmt.Errorf("not a git repository")
+	}
+
+	cmd := exec.Command("git", "rev-parse", "--verify", commitish+"^{commit}")
+	cmd.Dir = g.projectRoot
+
+	output, err := cmd.Output()
+	if err != nil {
+		return "", fmt.Errorf("invalid commit reference '%s': %w", commitish, err)
+	}
+
+	return strings.TrimSpace(string(output)), nil
+}
+
+// AddWorktree checks out a commit-ish reference into a new detached worktree
+// at dir, leaving the current working tree untouched
+func (g *GitUtils) AddWorktree(commitish, dir string) error {
+	if !g.IsGitRepository() {
+		return fmt.Errorf("not a git repository")
+	}
+
+	cmd := exec.Command("git", "worktree", "add", "--detach", dir, commitish)
+	cmd.Dir = g.projectRoot
+
+	if output, err := cmd.CombinedOutput(); err != nil {
+		return fmt.Errorf("failed to add worktree for '%s': %w: %s", commitish, err, strings.TrimSpace(string(output)))
+	}
+
+	return nil
+}
+
+// RemoveWorktree removes a worktree created by AddWorktree along with its
+// administrative files
+func (g *GitUtils) RemoveWorktree(dir string) error {
+	cmd := exec.Command("git", "worktree", "remove", "--force", dir)
+	cmd.Dir = g.projectRoot
+
+	if output, err := cmd.CombinedOutput(); err != nil {
+		return fmt.Errorf("failed to remove worktree %s: %w: %s", dir, err, strings.TrimSpace(string(output)))
+	}
+
+	return nil
+}
+
 // parseFileList parses git command output into file list
 func (g *GitUtils) parseFileList(output string) []string {
 	var files []string
@@ -0,0 +1,336 @@
+// Copyright (c) 2025 Archmagece
+// SPDX-License-Identifier: MIT
+
+// Package semver parses tool versions and version constraints.
+package semver
+
+import (
+	"fmt"
+	"strconv"
+	"strings"
+)
+
+// Version is a semantic version. Build metadata is dropped when parsing.
+type Version struct {
+	Major      int
+	Minor      int
+	Patch      int
+	Prerelease string
+}
+
+// Parse parses a version such as "1.2.3", "v0.6" or "1.0.0-rc.1".
+// Missing minor and patch components are zero.
+func Parse(s string) (Version, error) {
+	v, parts, err := parsePartial(s)
+	if err != nil {
+		return Version{}, err
+	}
+	if parts == 0 {
+		return Version{}, fmt.Errorf("invalid version %q", s)
+	}
+	return v, nil
+}
+
+// parsePartial parses a possibly partial version and returns how many
+// numeric components were given before the first wildcard (x, X or *).
+func parsePartial(s string) (Version, int, error) {
+	raw := s
+	s = strings.TrimPrefix(strings.TrimSpace(s), "v")
+	if i := strings.IndexByte(s, '+'); i >= 0 {
+		s = s[:i]
+	}
+
+	var v Version
+	if i := strings.IndexByte(s, '-'); i >= 0 {
+		v.Prerelease = s[i+1:]
+		s = s[:i]
+	}
+
+	fields := strings.Split(s, ".")
+	if s == "" || len(fields) > 3 {
+		return Version{}, 0, fmt.Errorf("invalid version %q", raw)
+	}
+
+	numbers := []*int{&v.Major, &v.Minor, &v.Patch}
+	parts := 0
+	for i, field := range fields {
+		if field == "x" || field == "X" || field == "*" {
+			break
+		}
+		n, err := strconv.Atoi(field)
+		if err != nil || n < 0 {
+			return Version{}, 0, fmt.Errorf("invalid version %q", raw)
+		}
+		*numbers[i] = n
+		parts++
+	}
+	if parts < len(fields) && v.Prerelease != "" {
+		return Version{}, 0, fmt.Errorf("invalid version %q", raw)
+	}
+
+	return v, parts, nil
+}
+
+// String formats the version as MAJOR.MINOR.PATCH[-PRERELEASE].
+func (v Version) String() string {
+	s := fmt.Sprintf("%d.%d.%d", v.Major, v.Minor, v.Patch)
+	if v.Prerelease != "" {
+		s += "-" + v.Prerelease
+	}
+	return s
+}
+
+// Compare returns -1, 0 or 1 as v is older than, equal to or newer than other.
+// A prerelease is older than the release it precedes.
+func (v Version) Compare(other Version) int {
+	for _, d := range []int{v.Major - other.Major, v.Minor - other.Minor, v.Patch - other.Patch} {
+		if d < 0 {
+			return -1
+		}
+		if d > 0 {
+			return 1
+		}
+	}
+
+	switch {
+	case v.Prerelease == other.Prerelease:
+		return 0
+	case v.Prerelease == "":
+		return 1
+	case other.Prerelease == "":
+		return -1
+	}
+	return comparePrerelease(v.Prerelease, other.Prerelease)
+}
+
+// comparePrerelease compares dot-separated prerelease identifiers.
+func comparePrerelease(a, b string) int {
+	as, bs := strings.Split(a, "."), strings.Split(b, ".")
+	for i := 0; i < len(as) && i < len(bs); i++ {
+		an, aErr := strconv.Atoi(as[i])
+		bn, bErr := strconv.Atoi(bs[i])
+		switch {
+		case aErr == nil && bErr == nil:
+			if an != bn {
+				return compareInts(an, bn)
+			}
+		case aErr == nil:
+			return -1
+		case bErr == nil:
+			return 1
+		default:
+			if c := strings.Compare(as[i], bs[i]); c != 0 {
+				return c
+			}
+		}
+	}
+	return compareInts(len(as), len(bs))
+}
+
+func compareInts(a, b int) int {
+	switch {
+	case a < b:
+		return -1
+	case a > b:
+		return 1
+	default:
+		return 0
+	}
+}
+
+// comparator is a single bound such as ">=1.2.0".
+type comparator struct {
+	op      string
+	version Version
+}
+
+// matches reports whether v satisfies the bound.
+func (c comparator) matches(v Version) bool {
+	cmp := v.Compare(c.version)
+	switch c.op {
+	case "=":
+		return cmp == 0
+	case "!=":
+		return cmp != 0
+	case ">":
+		return cmp > 0
+	case ">=":
+		return cmp >= 0
+	case "<":
+		return cmp < 0
+	default: // "<="
+		return cmp <= 0
+	}
+}
+
+// Constraint is a set of version requirements such as ">=0.6, <0.7" or
+// "^1.55 || ~2.1". Comma or space separated terms must all hold; "||"
+// separates alternatives.
+//
+// Supported terms are =, !=, >, >=, <, <=, ^ (compatible: same leftmost
+// non-zero component), ~ (same minor, or same major if only the major is
+// given) and wildcards such as "1.2", "1.2.x" or "*".
+type Constraint struct {
+	raw  string
+	sets [][]comparator
+}
+
+// ParseConstraint parses a version constraint.
+func ParseConstraint(s string) (*Constraint, error) {
+	c := &Constraint{raw: strings.TrimSpace(s)}
+	if c.raw == "" {
+		return nil, fmt.Errorf("empty version constraint")
+	}
+
+	for _, alternative := range strings.Split(c.raw, "||") {
+		terms := strings.Fields(strings.ReplaceAll(alternative, ",", " "))
+		if len(terms) == 0 {
+			return nil, fmt.Errorf("invalid version constraint %q", s)
+		}
+
+		// Allow a space between an operator and its version (">= 1.2")
+		var joined []string
+		for i := 0; i < len(terms); i++ {
+			if strings.Trim(terms[i], "=<>!^~") == "" && i+1 < len(terms) {
+				joined = append(joined, terms[i]+terms[i+1])
+				i++
+				continue
+			}
+			joined = append(joined, terms[i])
+		}
+
+		var set []comparator
+		for _, term := range joined {
+			comparators, err := parseTerm(term)
+			if err != nil {
+				return nil, fmt.Errorf("invalid version constraint %q: %w", s, err)
+			}
+			set = append(set, comparators...)
+		}
+		c.sets = append(c.sets, set)
+	}
+
+	return c, nil
+}
+
+// parseTerm expands one term into the bounds it implies.
+func parseTerm(term string) ([]comparator, error) {
+	op := ""
+	for _, prefix := range []string{">=", "<=", "!=", "==", ">", "<", "=", "^", "~"} {
+		if strings.HasPrefix(term, prefix) {
+			op = prefix
+			break
+		}
+	}
+
+	v, parts, err := parsePartial(term[len(op):])
+	if err != nil {
+		return nil, err
+	}
+
+	// upper returns the version after the range given by the first n components
+	upper := func(n int) Version {
+		switch n {
+		case 1:
+			return Version{Major: v.Major + 1}
+		case 2:
+			return Version{Major: v.Major, Minor: v.Minor + 1}
+		default:
+			return Version{Major: v.Major, Minor: v.Minor, Patch: v.Patch + 1}
+		}
+	}
+	lower := comparator{op: ">=", version: v}
+
+	switch op {
+	case "", "=", "==":
+		if parts == 0 {
+			return nil, nil
+		}
+		if parts == 3 {
+			return []comparator{{op: "=", version: v}}, nil
+		}
+		return []comparator{lower, {op: "<", version: upper(parts)}}, nil
+	case "!=":
+		if parts < 3 {
+			return nil, fmt.Errorf("%q needs a full version", term)
+		}
+		return []comparator{{op: "!=", version: v}}, nil
+	case ">":
+		if parts == 0 {
+			return nil, fmt.Errorf("%q needs a version", term)
+		}
+		if parts == 3 {
+			return []comparator{{op: ">", version: v}}, nil
+		}
+		return []comparator{{op: ">=", version: upper(parts)}}, nil
+	case ">=":
+		if parts == 0 {
+			return nil, nil
+		}
+		return []comparator{lower}, nil
+	case "<":
+		if parts == 0 {
+			return nil, fmt.Errorf("%q needs a version", term)
+		}
+		return []comparator{{op: "<", version: v}}, nil
+	case "<=":
+		if parts == 0 {
+			return nil, nil
+		}
+		if parts == 3 {
+			return []comparator{{op: "<=", version: v}}, nil
+		}
+		return []comparator{{op: "<", version: upper(parts)}}, nil
+	case "^":
+		switch {
+		case parts == 0:
+			return nil, nil
+		case v.Major > 0 || parts == 1:
+			return []comparator{lower, {op: "<", version: upper(1)}}, nil
+		case v.Minor > 0 || parts == 2:
+			return []comparator{lower, {op: "<", version: upper(2)}}, nil
+		default:
+			return []comparator{lower, {op: "<", version: upper(3)}}, nil
+		}
+	default: // "~"
+		switch parts {
+		case 0:
+			return nil, nil
+		case 1:
+			return []comparator{lower, {op: "<", version: upper(1)}}, nil
+		default:
+			return []comparator{lower, {op: "<", version: upper(2)}}, nil
+		}
+	}
+}
+
+// Check reports whether v satisfies the constraint.
+func (c *Constraint) Check(v Version) bool {
+	for _, set := range c.sets {
+		ok := true
+		for _, bound := range set {
+			if !bound.matches(v) {
+				ok = false
+				break
+			}
+		}
+		if ok {
+			return true
+		}
+	}
+	return false
+}
+
+// Exact returns the pinned version if the constraint allows exactly one
+// version ("1.2.3" or "=1.2.3").
+func (c *Constraint) Exact() (Version, bool) {
+	if len(c.sets) == 1 && len(c.sets[0]) == 1 && c.sets[0][0].op == "=" {
+		return c.sets[0][0].version, true
+	}
+	return Version{}, false
+}
+
+// String returns the constraint as written.
+func (c *Constraint) String() string {
+	return c.raw
+}
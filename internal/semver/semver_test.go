@@ -0,0 +1,124 @@
+// Copyright (c) 2025 Archmagece
+// SPDX-License-Identifier: MIT
+
+package semver
+
+import (
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestParse(t *testing.T) {
+	tests := []struct {
+		input    string
+		expected Version
+	}{
+		{input: "1.2.3", expected: Version{Major: 1, Minor: 2, Patch: 3}},
+		{input: "v0.6", expected: Version{Minor: 6}},
+		{input: "24", expected: Version{Major: 24}},
+		{input: "1.0.0-rc.1+build.5", expected: Version{Major: 1, Prerelease: "rc.1"}},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.input, func(t *testing.T) {
+			v, err := Parse(tt.input)
+			require.NoError(t, err)
+			assert.Equal(t, tt.expected, v)
+		})
+	}
+
+	for _, invalid := range []string{"", "abc", "1.2.3.4", "1.-2", "*"} {
+		_, err := Parse(invalid)
+		assert.Error(t, err, invalid)
+	}
+}
+
+func TestVersion_Compare(t *testing.T) {
+	tests := []struct {
+		a, b     string
+		expected int
+	}{
+		{a: "1.2.3", b: "1.2.3", expected: 0},
+		{a: "1.2.3", b: "1.10.0", expected: -1},
+		{a: "2.0.0", b: "1.99.99", expected: 1},
+		{a: "1.0.0-rc.1", b: "1.0.0", expected: -1},
+		{a: "1.0.0-rc.2", b: "1.0.0-rc.10", expected: -1},
+		{a: "1.0.0-beta", b: "1.0.0-alpha", expected: 1},
+		{a: "1.0.0-1", b: "1.0.0-alpha", expected: -1},
+	}
+
+	for _, tt := range tests {
+		a, err := Parse(tt.a)
+		require.NoError(t, err)
+		b, err := Parse(tt.b)
+		require.NoError(t, err)
+		assert.Equal(t, tt.expected, a.Compare(b), "%s vs %s", tt.a, tt.b)
+	}
+}
+
+func TestConstraint_Check(t *testing.T) {
+	tests := []struct {
+		constraint string
+		matches    []string
+		rejects    []string
+	}{
+		{constraint: ">=0.6, <0.7", matches: []string{"0.6.0", "0.6.9"}, rejects: []string{"0.5.7", "0.7.0"}},
+		{constraint: ">= 0.6 < 0.7", matches: []string{"0.6.3"}, rejects: []string{"0.4.1"}},
+		{constraint: "^1.55", matches: []string{"1.55.0", "1.99.1"}, rejects: []string{"1.54.9", "2.0.0"}},
+		{constraint: "^0.6.1", matches: []string{"0.6.1", "0.6.5"}, rejects: []string{"0.6.0", "0.7.0"}},
+		{constraint: "^0.0.3", matches: []string{"0.0.3"}, rejects: []string{"0.0.4"}},
+		{constraint: "~1.55.2", matches: []string{"1.55.9"}, rejects: []string{"1.55.1", "1.56.0"}},
+		{constraint: "~1", matches: []string{"1.9.0"}, rejects: []string{"2.0.0"}},
+		{constraint: "0.6.x", matches: []string{"0.6.4"}, rejects: []string{"0.7.0"}},
+		{constraint: "24", matches: []string{"24.1.0"}, rejects: []string{"25.0.0"}},
+		{constraint: "=1.2.3", matches: []string{"1.2.3"}, rejects: []string{"1.2.4"}},
+		{constraint: ">1.2", matches: []string{"1.3.0"}, rejects: []string{"1.2.9"}},
+		{constraint: "<=1.2", matches: []string{"1.2.9"}, rejects: []string{"1.3.0"}},
+		{constraint: "!=1.2.3", matches: []string{"1.2.4"}, rejects: []string{"1.2.3"}},
+		{constraint: "^1.2 || ~2.1", matches: []string{"1.4.0", "2.1.5"}, rejects: []string{"2.2.0"}},
+		{constraint: "*", matches: []string{"0.0.1", "99.0.0"}},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.constraint, func(t *testing.T) {
+			c, err := ParseConstraint(tt.constraint)
+			require.NoError(t, err)
+			assert.Equal(t, tt.constraint, c.String())
+
+			for _, s := range tt.matches {
+				v, err := Parse(s)
+				require.NoError(t, err)
+				assert.True(t, c.Check(v), "%s should satisfy %s", s, tt.constraint)
+			}
+			for _, s := range tt.rejects {
+				v, err := Parse(s)
+				require.NoError(t, err)
+				assert.False(t, c.Check(v), "%s should not satisfy %s", s, tt.constraint)
+			}
+		})
+	}
+}
+
+func TestParseConstraint_Invalid(t *testing.T) {
+	for _, invalid := range []string{"", "latest", ">=", "!=1.2", "<*", "1.2 ||"} {
+		_, err := ParseConstraint(invalid)
+		assert.Error(t, err, invalid)
+	}
+}
+
+func TestConstraint_Exact(t *testing.T) {
+	c, err := ParseConstraint("=0.6.2")
+	require.NoError(t, err)
+	v, ok := c.Exact()
+	assert.True(t, ok)
+	assert.Equal(t, "0.6.2", v.String())
+
+	for _, s := range []string{"0.6", "^0.6.2", ">=0.6.2", "0.6.2 || 0.7.0"} {
+		c, err := ParseConstraint(s)
+		require.NoError(t, err)
+		_, ok := c.Exact()
+		assert.False(t, ok, s)
+	}
+}
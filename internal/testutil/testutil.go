@@ -67,6 +67,23 @@ func WriteFile(t *testing.T, dir, filename, content string) string {
 	return path
 }
 
+// WriteExecutable writes an executable shell script into the given directory.
+// Tests put the directory first on PATH to stand in for external tools.
+func WriteExecutable(t *testing.T, dir, name, script string) string {
+	t.Helper()
+
+	if err := os.MkdirAll(dir, 0o755); err != nil {
+		t.Fatalf("failed to create directory: %v", err)
+	}
+
+	path := filepath.Join(dir, name)
+	if err := os.WriteFile(path, []byte("#!/bin/sh\n"+script), 0o755); err != nil { //nolint:gosec // test executable
+		t.Fatalf("failed to write executable: %v", err)
+	}
+
+	return path
+}
+
 // AssertEqual asserts that two values are equal.
 func AssertEqual(t *testing.T, got, want interface{}) {
 	t.Helper()
@@ -9,6 +9,7 @@ import (
 	"os"
 	"path/filepath"
 	"runtime"
+	"sort"
 	"strings"
 	"time"
 
@@ -18,15 +19,24 @@ import (
 	"github.com/Gizzahub/gzh-cli-quality/config"
 	"github.com/Gizzahub/gzh-cli-quality/detector"
 	"github.com/Gizzahub/gzh-cli-quality/executor"
+	"github.com/Gizzahub/gzh-cli-quality/fixer"
+	"github.com/Gizzahub/gzh-cli-quality/internal/semver"
 	"github.com/Gizzahub/gzh-cli-quality/report"
+	"github.com/Gizzahub/gzh-cli-quality/toolchain"
 	"github.com/Gizzahub/gzh-cli-quality/tools"
 )
 
 const (
 	statusSuccess = "✅"
 	statusFailure = "❌"
+	statusIssues  = "⚠️"
+	statusTimeout = "⏱️"
+	statusSkipped = "⏭️"
 )
 
+// defaultTimeout bounds a whole run when the config sets no timeout.
+const defaultTimeout = 10 * time.Minute
+
 // QualityManager manages the quality command functionality.
 type QualityManager struct {
 	registry     tools.ToolRegistry
@@ -35,6 +45,7 @@ type QualityManager struct {
 	planner      *executor.ExecutionPlanner
 	config       *config.Config
 	cacheManager *cache.CacheManager
+	prober       *tools.Prober
 }
 
 // NewQualityManager creates a new quality manager.
@@ -51,7 +62,17 @@ func NewQualityManager() *QualityManager {
 		cfg = config.DefaultConfig()
 	}
 
+	// Register plugins and tools declared in the config file; they may replace built-in ones
+	registerPlugins(registry, cfg.GetPluginDirs())
+	registerCustomTools(registry, cfg.CustomTools)
+	configureRustTools(registry, cfg.Rust)
+
+	// Tool availability and versions are probed once per run and remembered
+	// across runs while the installed binary is unchanged
+	prober := tools.NewProber(filepath.Join(cfg.GetCacheDirectory(), "tool-versions.json"))
+
 	analyzer := detector.NewProjectAnalyzer()
+	analyzer.SetProber(prober)
 	adapter := &ProjectAnalyzerAdapter{analyzer}
 	planner := executor.NewExecutionPlanner(adapter)
 
@@ -67,13 +88,15 @@ func NewQualityManager() *QualityManager {
 		}
 	}
 
-	// Create executor with or without cache
+	// Create executor with or without cache; the global timeout bounds the whole run
+	timeout := cfg.GetTimeout(defaultTimeout)
 	var parallelExecutor *executor.ParallelExecutor
 	if cacheManager != nil {
-		parallelExecutor = executor.NewParallelExecutorWithCache(runtime.NumCPU(), 10*time.Minute, cacheManager)
+		parallelExecutor = executor.NewParallelExecutorWithCache(runtime.NumCPU(), timeout, cacheManager)
 	} else {
-		parallelExecutor = executor.NewParallelExecutor(runtime.NumCPU(), 10*time.Minute)
+		parallelExecutor = executor.NewParallelExecutor(runtime.NumCPU(), timeout)
 	}
+	parallelExecutor.SetProber(prober)
 
 	return &QualityManager{
 		registry:     registry,
@@ -82,6 +105,7 @@ func NewQualityManager() *QualityManager {
 		planner:      planner,
 		config:       cfg,
 		cacheManager: cacheManager,
+		prober:       prober,
 	}
 }
 
@@ -91,17 +115,7 @@ func parseDuration(s string, defaultVal time.Duration) time.Duration {
 		return defaultVal
 	}
 
-	// Handle day suffix "d"
-	if s != "" && s[len(s)-1] == 'd' {
-		days := 0
-		_, err := fmt.Sscanf(s, "%dd", &days)
-		if err == nil && days > 0 {
-			return time.Duration(days) * 24 * time.Hour
-		}
-	}
-
-	// Try standard duration parsing
-	d, err := time.ParseDuration(s)
+	d, err := config.ParseDuration(s)
 	if err != nil {
 		return defaultVal
 	}
@@ -119,7 +133,8 @@ func NewQualityCmd() *cobra.Command {
 
 주요 명령어:
   run     모든 포매팅 및 린팅 도구 실행 (기본)
-  check   린팅만 실행 (변경 없이 검사)
+  check   변경 없이 검사 (포매팅 확인 + 린팅)
+  fix     린터가 제안한 자동 수정 적용 (diff 미리보기)
   init    프로젝트 설정 파일 자동 생성
 
 도구 실행:
@@ -131,17 +146,19 @@ func NewQualityCmd() *cobra.Command {
     ... (모든 설치된 도구)
 
 관리 명령어:
-  analyze  프로젝트 분석 및 권장 도구 표시
-  install  품질 도구 설치
-  upgrade  품질 도구 업그레이드
-  version  품질 도구 버전 확인
-  list     사용 가능한 품질 도구 목록 표시
+  analyze   프로젝트 분석 및 권장 도구 표시
+  install   품질 도구 설치
+  upgrade   품질 도구 업그레이드
+  version   품질 도구 버전 확인
+  outdated  버전 인덱스보다 오래된 도구 표시
+  list      사용 가능한 품질 도구 목록 표시
 
 사용 예시:
   gz quality run                      # 모든 도구 실행
   gz quality tool ruff --changed     # ruff로 변경된 파일만 처리
   gz quality tool gofumpt --staged   # gofumpt로 staged 파일만 처리
   gz quality run --format-only       # 포매팅 도구만 실행
+  gz quality check                    # CI용 검사 (미포매팅 파일은 diff로 표시)
   gz quality check --lint-only       # 린팅 도구만 실행`,
 		RunE: func(cmd *cobra.Command, args []string) error {
 			return cmd.Help()
@@ -151,11 +168,13 @@ func NewQualityCmd() *cobra.Command {
 	// Add subcommands
 	cmd.AddCommand(manager.newRunCmd())
 	cmd.AddCommand(manager.newCheckCmd())
+	cmd.AddCommand(manager.newFixCmd())
 	cmd.AddCommand(manager.newInitCmd())
 	cmd.AddCommand(manager.newAnalyzeCmd())
 	cmd.AddCommand(manager.newInstallCmd())
 	cmd.AddCommand(manager.newUpgradeCmd())
 	cmd.AddCommand(manager.newVersionCmd())
+	cmd.AddCommand(manager.newOutdatedCmd())
 	cmd.AddCommand(manager.newListCmd())
 	cmd.AddCommand(manager.newToolCmd())
 
@@ -173,7 +192,7 @@ func addCommonExecutionFlags(cmd *cobra.Command) {
 	cmd.Flags().StringSliceP("files", "f", nil, "특정 파일들만 처리")
 	cmd.Flags().IntP("workers", "w", runtime.NumCPU(), "병렬 실행 워커 수")
 	cmd.Flags().StringSlice("extra-args", nil, "도구에 전달할 추가 인수")
-	cmd.Flags().Bool("dry-run", false, "실제 실행하지 않고 계획만 표시")
+	cmd.Flags().Bool("dry-run", false, "실제 실행하지 않고 계획과 실행할 명령만 표시")
 	cmd.Flags().BoolP("verbose", "v", false, "상세 출력")
 	cmd.Flags().String("report", "", "리포트 생성 (json, html, markdown)")
 	cmd.Flags().String("output", "", "리포트 출력 파일 경로")
@@ -223,6 +242,7 @@ type executionOptions struct {
 	fix          bool
 	formatOnly   bool
 	lintOnly     bool
+	check        bool
 	workers      int
 	extraArgs    []string
 	dryRun       bool
@@ -236,6 +256,8 @@ type executionOptions struct {
 	// Display customization
 	emptyMessage  string
 	executePrefix string
+	// afterRun handles the displayed results (check failure, applying fixes)
+	afterRun func(results []*tools.Result, projectRoot string) error
 }
 
 // parseExecutionOptions parses common flags from a cobra command.
@@ -279,12 +301,16 @@ func (m *QualityManager) executeQuality(ctx context.Context, opts *executionOpti
 		return err
 	}
 
+	m.activateToolchain(projectRoot)
+
 	planOptions := executor.PlanOptions{
 		Files:      opts.files,
 		Fix:        opts.fix,
 		FormatOnly: opts.formatOnly,
 		LintOnly:   opts.lintOnly,
+		Check:      opts.check,
 		ExtraArgs:  opts.extraArgs,
+		Timeouts:   m.config.ToolTimeouts(),
 		Since:      opts.since,
 		Staged:     opts.staged,
 		Changed:    opts.changed,
@@ -303,8 +329,11 @@ func (m *QualityManager) executeQuality(ctx context.Context, opts *executionOpti
 	m.displayPlan(plan, opts.verbose)
 
 	if opts.dryRun {
-		fmt.Println("✨ 드라이런 모드: 실제 실행하지 않습니다.")
-		return nil
+		return m.dryRun(ctx, plan)
+	}
+
+	if err := m.checkToolVersions(plan); err != nil {
+		return err
 	}
 
 	cacheStatus := ""
@@ -331,9 +360,41 @@ func (m *QualityManager) executeQuality(ctx context.Context, opts *executionOpti
 		}
 	}
 
+	if opts.afterRun != nil {
+		return opts.afterRun(results, projectRoot)
+	}
+
 	return nil
 }
 
+// dryRun prints the exact command line of every task instead of running it.
+func (m *QualityManager) dryRun(ctx context.Context, plan *tools.ExecutionPlan) error {
+	fmt.Println("✨ 드라이런 모드: 실제 실행하지 않고 실행할 명령만 표시합니다.")
+
+	m.executor.SetRunner(tools.NewDryRunRunner(os.Stdout))
+	defer m.executor.SetRunner(nil)
+
+	_, err := m.executor.ExecuteParallel(ctx, plan, 1)
+	return err
+}
+
+// checkFailure returns an error when a check run found issues or a tool
+// failed, so CI fails without any file being modified.
+func checkFailure(results []*tools.Result, _ string) error {
+	issues, failed := 0, 0
+	for _, result := range results {
+		issues += len(result.Issues)
+		if result.EffectiveStatus().IsFailure() {
+			failed++
+		}
+	}
+
+	if issues == 0 && failed == 0 {
+		return nil
+	}
+	return fmt.Errorf("check failed: %d issue(s) found, %d tool(s) failed", issues, failed)
+}
+
 // runQuality executes the main quality command logic.
 func (m *QualityManager) runQuality(cmd *cobra.Command, _ []string) error {
 	opts, err := parseExecutionOptions(cmd)
@@ -374,36 +435,33 @@ func (m *QualityManager) displayPlan(plan *tools.ExecutionPlan, verbose bool) {
 func (m *QualityManager) displayResults(results []*tools.Result, duration time.Duration, verbose bool) {
 	fmt.Printf("\n✅ 완료! 총 소요시간: %v\n", duration.Round(time.Millisecond))
 
-	successful := 0
+	counts := make(map[tools.Status]int)
 	totalIssues := 0
 	cachedCount := 0
 
 	for _, result := range results {
-		if result.Success {
-			successful++
-		}
+		status := result.EffectiveStatus()
+		counts[status]++
 		if result.Cached {
 			cachedCount++
 		}
 		totalIssues += len(result.Issues)
 
-		if verbose || !result.Success {
-			status := statusSuccess
-			if !result.Success {
-				status = statusFailure
-			}
-
+		if verbose || status != tools.StatusClean {
 			cachedLabel := ""
 			if result.Cached {
 				cachedLabel = " (캐시됨)"
 			}
 
 			fmt.Printf("%s %s (%s): %d개 파일, %v%s\n",
-				status, result.Tool, result.Language, result.FilesProcessed, result.Duration, cachedLabel)
+				statusIcon(status), result.Tool, result.Language, result.FilesProcessed, result.Duration, cachedLabel)
 
 			if result.Error != "" {
 				fmt.Printf("   오류: %s\n", result.Error)
 			}
+			if status == tools.StatusToolError && result.ExitCode > 0 {
+				fmt.Printf("   종료 코드: %d\n", result.ExitCode)
+			}
 
 			if len(result.Issues) > 0 {
 				fmt.Printf("   이슈: %d개\n", len(result.Issues))
@@ -422,8 +480,32 @@ func (m *QualityManager) displayResults(results []*tools.Result, duration time.D
 	if cachedCount > 0 {
 		cacheInfo = fmt.Sprintf(", %d개 캐시 히트", cachedCount)
 	}
+	completed := counts[tools.StatusClean] + counts[tools.StatusIssues]
 	fmt.Printf("\n📊 요약: %d/%d 도구 성공, %d개 이슈 발견%s\n",
-		successful, len(results), totalIssues, cacheInfo)
+		completed, len(results), totalIssues, cacheInfo)
+
+	failed := counts[tools.StatusToolError] + counts[tools.StatusTimeout] + counts[tools.StatusCancelled]
+	if counts[tools.StatusIssues] > 0 || failed > 0 || counts[tools.StatusSkipped] > 0 {
+		fmt.Printf("   이슈 발견 %d개 도구, 도구 실패 %d개 (시간 초과 %d, 취소 %d), 건너뜀 %d개\n",
+			counts[tools.StatusIssues], failed, counts[tools.StatusTimeout], counts[tools.StatusCancelled],
+			counts[tools.StatusSkipped])
+	}
+}
+
+// statusIcon returns the display icon for a result status.
+func statusIcon(status tools.Status) string {
+	switch status {
+	case tools.StatusClean:
+		return statusSuccess
+	case tools.StatusIssues:
+		return statusIssues
+	case tools.StatusTimeout, tools.StatusCancelled:
+		return statusTimeout
+	case tools.StatusSkipped:
+		return statusSkipped
+	default:
+		return statusFailure
+	}
 }
 
 // newAnalyzeCmd creates the analyze subcommand.
@@ -437,6 +519,8 @@ func (m *QualityManager) newAnalyzeCmd() *cobra.Command {
 				return fmt.Errorf("failed to get current directory: %w", err)
 			}
 
+			m.activateToolchain(projectRoot)
+
 			analysis, err := m.analyzer.AnalyzeProject(projectRoot, m.registry)
 			if err != nil {
 				return fmt.Errorf("failed to analyze project: %w", err)
@@ -470,6 +554,14 @@ func (m *QualityManager) newAnalyzeCmd() *cobra.Command {
 				}
 			}
 
+			// Show Rust crates
+			if len(analysis.RustCrates) > 0 {
+				fmt.Println("\nRust 크레이트:")
+				for _, crate := range analysis.RustCrates {
+					fmt.Printf("  %s\n", formatRustCrate(crate, analysis.ProjectRoot))
+				}
+			}
+
 			// Show issues
 			if len(analysis.Issues) > 0 {
 				fmt.Println("\n이슈:")
@@ -483,6 +575,21 @@ func (m *QualityManager) newAnalyzeCmd() *cobra.Command {
 	}
 }
 
+// formatRustCrate describes a crate's edition and toolchain for the analyze output.
+func formatRustCrate(crate tools.RustCrate, projectRoot string) string {
+	dir := crate.Dir
+	if rel, err := filepath.Rel(projectRoot, dir); err == nil {
+		dir = rel
+	}
+
+	toolchain := crate.Toolchain
+	if toolchain == "" {
+		toolchain = "기본값"
+	}
+
+	return fmt.Sprintf("%s (%s): edition %s, toolchain %s", crate.Name, dir, crate.Edition, toolchain)
+}
+
 // forEachTool executes an action on specified tools or all tools if none specified.
 func (m *QualityManager) forEachTool(args []string, action func(tools.QualityTool) error, successMsg, failMsg string) {
 	if len(args) == 0 {
@@ -522,11 +629,26 @@ func groupToolsByLanguage(toolList []tools.QualityTool) map[string][]tools.Quali
 
 // newInstallCmd creates the install subcommand.
 func (m *QualityManager) newInstallCmd() *cobra.Command {
-	return &cobra.Command{
+	cmd := &cobra.Command{
 		Use:   "install [tool-name...]",
 		Short: "품질 도구 설치",
-		Long:  "지정된 도구를 설치합니다. 도구명을 지정하지 않으면 모든 도구를 설치합니다.",
+		Long: `지정된 도구를 설치합니다. 도구명을 지정하지 않으면 모든 도구를 설치합니다.
+
+--local을 지정하면 각 도구를 프로젝트 로컬 툴체인(.gzquality/tools)의 개별
+디렉터리에 고정 버전으로 설치하고 버전과 체크섬을 gzquality.lock에 기록합니다.
+로컬 툴체인의 도구는 전역 설치된 도구보다 먼저 사용됩니다.
+
+사용 예시:
+  gz quality install --local                       # lock 파일(없으면 모든 도구) 기준 설치
+  gz quality install --local ruff eslint
+  gz quality install --local --mirror ./artifacts  # 네트워크 없이 로컬 미러에서 설치`,
 		RunE: func(cmd *cobra.Command, args []string) error {
+			local, _ := cmd.Flags().GetBool("local")
+			mirror, _ := cmd.Flags().GetString("mirror")
+			if local || mirror != "" {
+				return m.installLocal(cmd.Context(), args, mirror)
+			}
+
 			if len(args) == 0 {
 				fmt.Println("🔧 모든 품질 도구를 설치합니다...")
 			}
@@ -534,6 +656,119 @@ func (m *QualityManager) newInstallCmd() *cobra.Command {
 			return nil
 		},
 	}
+
+	cmd.Flags().Bool("local", false, "프로젝트 로컬 툴체인(.gzquality/tools)에 설치하고 gzquality.lock 기록")
+	cmd.Flags().String("mirror", "", "네트워크 대신 사용할 로컬 아티팩트 미러 디렉터리 (--local 포함)")
+
+	return cmd
+}
+
+// installLocal installs tools into the project toolchain at their pinned
+// versions and records them in the lockfile. Without names it installs the
+// locked tools, or every tool published to a package registry.
+func (m *QualityManager) installLocal(ctx context.Context, names []string, mirror string) error {
+	projectRoot, err := os.Getwd()
+	if err != nil {
+		return fmt.Errorf("failed to get working directory: %w", err)
+	}
+
+	lock, err := toolchain.LoadLock(projectRoot)
+	if err != nil {
+		return err
+	}
+
+	tc := toolchain.New(projectRoot)
+	tc.Mirror = mirror
+
+	if len(names) == 0 {
+		names = lock.Names()
+	}
+	if len(names) == 0 {
+		for _, tool := range m.registry.GetTools() {
+			if installable, ok := tool.(toolchain.Installable); ok {
+				if _, ok := installable.Package(); ok {
+					names = append(names, tool.Name())
+				}
+			}
+		}
+	}
+
+	fmt.Printf("🔧 프로젝트 로컬 툴체인에 설치합니다: %s\n", tc.Root)
+
+	failed := 0
+	for _, name := range names {
+		tool := m.registry.FindTool(name)
+		if tool == nil {
+			fmt.Printf("❌ 도구를 찾을 수 없습니다: %s\n", name)
+			failed++
+			continue
+		}
+		installable, ok := tool.(toolchain.Installable)
+		if ok {
+			_, ok = installable.Package()
+		}
+		if !ok {
+			fmt.Printf("⚠️ %s: 로컬 설치를 지원하지 않습니다\n", name)
+			continue
+		}
+
+		version := m.pinnedVersion(name, lock.Tools[name])
+		if locked, exists := lock.Tools[name]; exists && locked.Version == version && tc.Verify(locked) == nil {
+			fmt.Printf("✅ %s %s (최신 상태)\n", name, version)
+			continue
+		}
+
+		entry, err := tc.Install(ctx, installable, version)
+		if err != nil {
+			fmt.Printf("❌ %s 설치 실패: %v\n", name, err)
+			failed++
+			continue
+		}
+		lock.Tools[name] = entry
+		m.prober.Forget(name)
+		fmt.Printf("✅ %s %s 설치 완료\n", name, entry.Version)
+	}
+
+	if err := lock.Save(projectRoot); err != nil {
+		return err
+	}
+	if failed > 0 {
+		return fmt.Errorf("%d tool(s) failed to install", failed)
+	}
+	return nil
+}
+
+// activateToolchain points the tools recorded in gzquality.lock at their
+// project-local executables. Tools failing verification keep using the
+// globally installed executable.
+func (m *QualityManager) activateToolchain(projectRoot string) {
+	if _, err := toolchain.Activate(projectRoot, m.registry); err != nil {
+		fmt.Printf("⚠️ 로컬 툴체인 검증 실패, 전역 도구를 사용합니다: %v\n", err)
+	}
+}
+
+// pinnedVersion picks the version to install: the locked version while it
+// satisfies the configured constraint, otherwise the exact configured version,
+// otherwise the latest ("").
+func (m *QualityManager) pinnedVersion(name string, locked toolchain.LockedTool) string {
+	required := m.config.GetToolConfig(name).Version
+	if required == "" {
+		return locked.Version
+	}
+
+	constraint, err := semver.ParseConstraint(required)
+	if err != nil {
+		return locked.Version
+	}
+	if locked.Version != "" {
+		if v, err := semver.Parse(locked.Version); err == nil && constraint.Check(v) {
+			return locked.Version
+		}
+	}
+	if exact, ok := constraint.Exact(); ok {
+		return exact.String()
+	}
+	return ""
 }
 
 // newUpgradeCmd creates the upgrade subcommand.
@@ -559,6 +794,10 @@ func (m *QualityManager) newVersionCmd() *cobra.Command {
 		Short: "품질 도구 버전 확인",
 		Long:  "설치된 품질 도구들의 버전을 표시합니다. 도구명을 지정하지 않으면 모든 도구의 버전을 표시합니다.",
 		RunE: func(cmd *cobra.Command, args []string) error {
+			if projectRoot, err := os.Getwd(); err == nil {
+				m.activateToolchain(projectRoot)
+			}
+
 			if len(args) == 0 {
 				fmt.Println("📋 설치된 품질 도구 버전:")
 				for lang, toolList := range groupToolsByLanguage(m.registry.GetTools()) {
@@ -582,6 +821,108 @@ func (m *QualityManager) newVersionCmd() *cobra.Command {
 	}
 }
 
+// newOutdatedCmd creates the outdated subcommand.
+func (m *QualityManager) newOutdatedCmd() *cobra.Command {
+	cmd := &cobra.Command{
+		Use:   "outdated [tool-name...]",
+		Short: "버전 인덱스보다 오래된 품질 도구 표시",
+		Long: `설치된 도구의 버전을 로컬 버전 인덱스(기본: .gzquality/versions.yml)와 비교합니다.
+도구명을 지정하지 않으면 인덱스에 있는 모든 도구를 확인합니다.
+
+버전 인덱스 형식:
+  tools:
+    gofumpt: 0.7.0
+    ruff: 0.6.9`,
+		RunE: func(cmd *cobra.Command, args []string) error {
+			indexPath, _ := cmd.Flags().GetString("index")
+			if indexPath == "" {
+				indexPath = m.config.GetVersionIndexPath()
+			}
+
+			index, err := config.LoadVersionIndex(indexPath)
+			if err != nil {
+				return err
+			}
+
+			if projectRoot, err := os.Getwd(); err == nil {
+				m.activateToolchain(projectRoot)
+			}
+
+			outdated := m.findOutdated(args, index)
+			if len(outdated) == 0 {
+				fmt.Println("✅ 모든 도구가 최신 상태입니다")
+				return nil
+			}
+
+			fmt.Println("⬆️ 업데이트 가능한 도구:")
+			names := make([]string, 0, len(outdated))
+			for _, tool := range outdated {
+				fmt.Printf("  %s %s → %s\n", tool.name, tool.installed, tool.latest)
+				names = append(names, tool.name)
+			}
+			fmt.Printf("\n💡 gz quality upgrade %s\n", strings.Join(names, " "))
+			return nil
+		},
+	}
+
+	cmd.Flags().String("index", "", "버전 인덱스 파일 (기본: .gzquality/versions.yml 또는 설정의 version_index)")
+
+	return cmd
+}
+
+// outdatedTool is an installed tool older than the version index.
+type outdatedTool struct {
+	name      string
+	installed string
+	latest    string
+}
+
+// findOutdated compares installed tool versions with the version index.
+// Without names every indexed tool is checked; tools that are not installed
+// are skipped.
+func (m *QualityManager) findOutdated(names []string, index map[string]string) []outdatedTool {
+	if len(names) == 0 {
+		for name := range index {
+			names = append(names, name)
+		}
+		sort.Strings(names)
+	}
+
+	var outdated []outdatedTool
+	for _, name := range names {
+		latest, ok := index[name]
+		if !ok {
+			fmt.Printf("⚠️ %s: 버전 인덱스에 없습니다\n", name)
+			continue
+		}
+		tool := m.registry.FindTool(name)
+		if tool == nil {
+			fmt.Printf("❌ 도구를 찾을 수 없습니다: %s\n", name)
+			continue
+		}
+		if !m.prober.IsAvailable(tool) {
+			continue
+		}
+
+		version, err := m.prober.Version(tool)
+		if err != nil {
+			fmt.Printf("⚠️ %s: 버전 확인 실패 (%v)\n", name, err)
+			continue
+		}
+		installed, err := semver.Parse(version)
+		if err != nil {
+			fmt.Printf("⚠️ %s: 설치된 버전을 해석할 수 없습니다 (%s)\n", name, version)
+			continue
+		}
+		want, _ := semver.Parse(latest) // validated by LoadVersionIndex
+		if installed.Compare(want) < 0 {
+			outdated = append(outdated, outdatedTool{name: name, installed: version, latest: strings.TrimPrefix(latest, "v")})
+		}
+	}
+
+	return outdated
+}
+
 // newListCmd creates the list subcommand.
 func (m *QualityManager) newListCmd() *cobra.Command {
 	return &cobra.Command{
@@ -593,7 +934,7 @@ func (m *QualityManager) newListCmd() *cobra.Command {
 				fmt.Printf("\n%s:\n", lang)
 				for _, tool := range toolList {
 					status := "❌"
-					if tool.IsAvailable() {
+					if m.prober.IsAvailable(tool) {
 						status = "✅"
 					}
 					fmt.Printf("  %s %s (%s)\n", status, tool.Name(), tool.Type().String())
@@ -606,43 +947,109 @@ func (m *QualityManager) newListCmd() *cobra.Command {
 
 // installTool installs a specific tool.
 func (m *QualityManager) installTool(tool tools.QualityTool) error {
-	if tool.IsAvailable() {
+	if m.prober.IsAvailable(tool) {
 		return nil // Already installed
 	}
 
+	defer m.prober.Forget(tool.Name())
 	return tool.Install()
 }
 
 // upgradeTool upgrades a specific tool.
 func (m *QualityManager) upgradeTool(tool tools.QualityTool) error {
-	if !tool.IsAvailable() {
+	defer m.prober.Forget(tool.Name())
+
+	if !m.prober.IsAvailable(tool) {
 		fmt.Printf("📦 %s is not installed, installing...\n", tool.Name())
 		return tool.Install()
 	}
 
 	// Show current version before upgrade
-	if version, err := tool.GetVersion(); err == nil {
+	if version, err := m.prober.Version(tool); err == nil {
 		fmt.Printf("📦 Current %s version: %s\n", tool.Name(), version)
 	}
 
 	return tool.Upgrade()
 }
 
-// showToolVersion displays the version of a tool.
+// showToolVersion displays the version of a tool and the version required by the config.
 func (m *QualityManager) showToolVersion(tool tools.QualityTool) {
-	if !tool.IsAvailable() {
-		fmt.Printf("  ❌ %s: not installed\n", tool.Name())
+	required := ""
+	if constraint := m.config.GetToolConfig(tool.Name()).Version; constraint != "" {
+		required = fmt.Sprintf(" (required: %s)", constraint)
+	}
+
+	if !m.prober.IsAvailable(tool) {
+		fmt.Printf("  ❌ %s: not installed%s\n", tool.Name(), required)
 		return
 	}
 
-	version, err := tool.GetVersion()
+	version, err := m.prober.Version(tool)
 	if err != nil {
-		fmt.Printf("  ⚠️  %s: error getting version (%v)\n", tool.Name(), err)
+		fmt.Printf("  ⚠️  %s: error getting version (%v)%s\n", tool.Name(), err, required)
 		return
 	}
 
 	status := "✅"
-	fmt.Printf("  %s %s: %s\n", status, tool.Name(), version)
+	if required != "" && m.versionProblem(tool.Name(), version) != "" {
+		status = "❌"
+	}
+	fmt.Printf("  %s %s: %s%s\n", status, tool.Name(), version, required)
+}
+
+// checkToolVersions fails when an installed tool in the plan does not satisfy
+// its configured version constraint. Tools that are not installed are left
+// to be skipped by the executor.
+func (m *QualityManager) checkToolVersions(plan *tools.ExecutionPlan) error {
+	checked := make(map[string]bool)
+	var problems []string
+
+	for _, task := range plan.Tasks {
+		name := task.Tool.Name()
+		if checked[name] || m.config.GetToolConfig(name).Version == "" {
+			continue
+		}
+		checked[name] = true
+
+		if !m.prober.IsAvailable(task.Tool) {
+			continue
+		}
+		version, err := m.prober.Version(task.Tool)
+		if err != nil {
+			problems = append(problems, fmt.Sprintf("%s: failed to get version: %v", name, err))
+			continue
+		}
+		if problem := m.versionProblem(name, version); problem != "" {
+			problems = append(problems, problem)
+		}
+	}
+
+	if len(problems) == 0 {
+		return nil
+	}
+	for _, problem := range problems {
+		fmt.Printf("❌ %s\n", problem)
+	}
+	return fmt.Errorf("tool version requirements not met:\n  %s", strings.Join(problems, "\n  "))
+}
+
+// versionProblem describes why version does not satisfy the tool's
+// configured constraint, or returns "" if it does.
+func (m *QualityManager) versionProblem(name, version string) string {
+	required := m.config.GetToolConfig(name).Version
+	constraint, err := semver.ParseConstraint(required)
+	if err != nil {
+		return fmt.Sprintf("%s: invalid version constraint: %v", name, err)
+	}
+
+	installed, err := semver.Parse(version)
+	if err != nil {
+		return fmt.Sprintf("%s: cannot read installed version %q (required %s)", name, version, required)
+	}
+	if !constraint.Check(installed) {
+		return fmt.Sprintf("%s %s does not satisfy required version %s", name, version, required)
+	}
+	return ""
 }
 
 // generateReport creates and saves a quality report.
@@ -685,9 +1092,12 @@ func (m *QualityManager) generateReport(results []*tools.Result, duration time.D
 func (m *QualityManager) newCheckCmd() *cobra.Command {
 	cmd := &cobra.Command{
 		Use:   "check",
-		Short: "린팅만 실행 (변경 없이 검사)",
-		Long: `코드를 변경하지 않고 린팅만 수행합니다.
-포맷팅 도구는 실행하지 않고 린터만 실행하여 코드 품질을 검사합니다.`,
+		Short: "변경 없이 검사 (포매팅 확인 + 린팅)",
+		Long: `코드를 변경하지 않고 포매팅과 린팅을 검사합니다.
+포매터는 확인 모드로 실행되어 (gofumpt -l -d, black --check --diff, rustfmt --check,
+prettier --check, cargo fmt -- --check) 포매팅이 필요한 파일을 diff와 함께 이슈로 보고합니다.
+확인 모드를 지원하지 않는 포매터는 건너뜁니다.
+이슈가 있거나 도구가 실패하면 0이 아닌 종료 코드로 끝나므로 CI에 적합합니다.`,
 		RunE: func(cmd *cobra.Command, args []string) error {
 			return m.runCheck(cmd, args)
 		},
@@ -698,10 +1108,14 @@ func (m *QualityManager) newCheckCmd() *cobra.Command {
 	addGitFilterFlags(cmd)
 	addCacheFlags(cmd)
 
+	// Check-specific flags
+	cmd.Flags().Bool("format-only", false, "포매팅 확인만 실행")
+	cmd.Flags().Bool("lint-only", false, "린팅만 실행")
+
 	return cmd
 }
 
-// runCheck executes the check command (lint-only).
+// runCheck executes the check command (verify formatting and lint, never write).
 func (m *QualityManager) runCheck(cmd *cobra.Command, _ []string) error {
 	opts, err := parseExecutionOptions(cmd)
 	if err != nil {
@@ -709,15 +1123,112 @@ func (m *QualityManager) runCheck(cmd *cobra.Command, _ []string) error {
 	}
 
 	// Override for check mode
-	opts.fix = false       // Never fix in check mode
-	opts.formatOnly = false
-	opts.lintOnly = true   // Only run linters
+	opts.fix = false  // Never fix in check mode
+	opts.check = true // Formatters only report diffs
 	opts.emptyMessage = "🎯 검사할 작업이 없습니다."
 	opts.executePrefix = "🔍"
+	opts.afterRun = checkFailure
+
+	return m.executeQuality(cmd.Context(), opts)
+}
+
+// newFixCmd creates the fix subcommand.
+func (m *QualityManager) newFixCmd() *cobra.Command {
+	cmd := &cobra.Command{
+		Use:   "fix",
+		Short: "린터가 제안한 자동 수정 적용",
+		Long: `린터를 변경 없이 실행한 뒤 도구가 제공한 기계 적용 가능한 수정
+(clippy suggested_replacement, ruff fix, eslint fix, golangci-lint Replacement)을 적용합니다.
+서로 겹치지 않는 안전한 수정만 적용하며, 적용 전 diff를 표시합니다.
+
+사용 예시:
+  gz quality fix --preview              # 적용하지 않고 diff만 표시
+  gz quality fix --rule F401 --rule I001
+  gz quality fix --file 'src/*.py'
+  gz quality fix --unsafe               # 동작이 바뀔 수 있는 수정도 적용`,
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return m.runFix(cmd, args)
+		},
+	}
+
+	// Common flags
+	addCommonExecutionFlags(cmd)
+	addGitFilterFlags(cmd)
+	addCacheFlags(cmd)
+
+	// Fix-specific flags
+	cmd.Flags().StringSlice("rule", nil, "이 규칙의 수정만 적용")
+	cmd.Flags().StringSlice("file", nil, "이 파일(glob 패턴)의 수정만 적용")
+	cmd.Flags().Bool("unsafe", false, "동작이 바뀔 수 있는 수정도 적용")
+	cmd.Flags().Bool("preview", false, "수정을 적용하지 않고 diff만 표시")
+
+	return cmd
+}
+
+// runFix runs the linters without writing and applies their structured edits.
+func (m *QualityManager) runFix(cmd *cobra.Command, _ []string) error {
+	opts, err := parseExecutionOptions(cmd)
+	if err != nil {
+		return err
+	}
+
+	rules, _ := cmd.Flags().GetStringSlice("rule")
+	files, _ := cmd.Flags().GetStringSlice("file")
+	unsafe, _ := cmd.Flags().GetBool("unsafe")
+	preview, _ := cmd.Flags().GetBool("preview")
+
+	// Tools only report edits; they are applied here
+	opts.fix = false
+	opts.check = true
+	opts.lintOnly = true
+	opts.emptyMessage = "🎯 수정할 작업이 없습니다."
+	opts.executePrefix = "🔧"
+	opts.afterRun = func(results []*tools.Result, projectRoot string) error {
+		return applyFixes(results, fixer.Options{
+			ProjectRoot: projectRoot,
+			Rules:       rules,
+			Files:       files,
+			Unsafe:      unsafe,
+		}, preview)
+	}
 
 	return m.executeQuality(cmd.Context(), opts)
 }
 
+// applyFixes shows the selected edits as a diff and writes them unless previewing.
+func applyFixes(results []*tools.Result, options fixer.Options, preview bool) error {
+	var issues []tools.Issue
+	for _, result := range results {
+		issues = append(issues, result.Issues...)
+	}
+
+	plan := fixer.Prepare(issues, options)
+
+	fmt.Println()
+	if diff := plan.Diff(); diff != "" {
+		fmt.Print(diff)
+	}
+	for _, skipped := range plan.Skipped {
+		fmt.Printf("⏭️ %s:%d %s: %s\n", skipped.Issue.File, skipped.Issue.Line, skipped.Issue.Rule, skipped.Reason)
+	}
+
+	if len(plan.Changes) == 0 {
+		fmt.Println("🎯 적용할 수 있는 자동 수정이 없습니다.")
+		return nil
+	}
+
+	if preview {
+		fmt.Printf("👀 미리보기: %d개 파일, %d개 수정 (적용하지 않음)\n", len(plan.Changes), plan.Fixes())
+		return nil
+	}
+
+	if err := plan.Write(); err != nil {
+		return fmt.Errorf("failed to apply fixes: %w", err)
+	}
+	fmt.Printf("🔧 %d개 파일에 %d개 수정 적용 완료\n", len(plan.Changes), plan.Fixes())
+	return nil
+}
+
 // newInitCmd creates the init subcommand.
 func (m *QualityManager) newInitCmd() *cobra.Command {
 	return &cobra.Command{
@@ -855,6 +1366,104 @@ func (c *Config) ToYAML() (string, error) {
 	return sb.String(), nil
 }
 
+// registerPlugins registers the plugin executables found in dirs.
+func registerPlugins(registry tools.ToolRegistry, dirs []string) {
+	plugins, errs := tools.DiscoverPlugins(dirs)
+	for _, err := range errs {
+		fmt.Printf("⚠️ 플러그인 로드 실패: %v\n", err)
+	}
+	for _, plugin := range plugins {
+		registry.Register(plugin)
+	}
+}
+
+// configureRustTools applies the rust section of the config to the cargo-based tools.
+func configureRustTools(registry tools.ToolRegistry, rust config.RustConfig) {
+	settings := tools.RustSettings{
+		Deny:       rust.Deny,
+		Warn:       rust.Warn,
+		Allow:      rust.Allow,
+		AllTargets: rust.AllTargets,
+		Features: tools.FeatureSet{
+			AllFeatures:       rust.AllFeatures,
+			NoDefaultFeatures: rust.NoDefaultFeatures,
+			Features:          rust.Features,
+		},
+	}
+	for _, set := range rust.FeatureMatrix {
+		settings.FeatureMatrix = append(settings.FeatureMatrix, tools.FeatureSet{
+			AllFeatures:       set.AllFeatures,
+			NoDefaultFeatures: set.NoDefaultFeatures,
+			Features:          set.Features,
+		})
+	}
+
+	if clippy, ok := registry.FindTool("clippy").(*tools.ClippyTool); ok {
+		clippy.SetSettings(settings)
+	}
+	if rustdoc, ok := registry.FindTool("rustdoc").(*tools.RustdocTool); ok {
+		rustdoc.SetMissingDocs(rust.MissingDocs)
+	}
+
+	advisoryDB := rust.AdvisoryDB
+	if advisoryDB != "" {
+		if abs, err := filepath.Abs(advisoryDB); err == nil {
+			advisoryDB = abs
+		}
+	}
+	if audit, ok := registry.FindTool("cargo-audit").(*tools.CargoAuditTool); ok {
+		audit.SetAdvisoryDB(advisoryDB)
+	}
+	if deny, ok := registry.FindTool("cargo-deny").(*tools.CargoDenyTool); ok {
+		deny.SetAdvisoryDB(advisoryDB)
+	}
+}
+
+// registerCustomTools registers the tools declared in the custom_tools section.
+func registerCustomTools(registry tools.ToolRegistry, customTools []config.CustomToolConfig) {
+	for _, custom := range customTools {
+		tool, err := newCustomTool(custom)
+		if err != nil {
+			fmt.Printf("⚠️ 사용자 정의 도구 %s 등록 실패: %v\n", custom.Name, err)
+			continue
+		}
+		registry.Register(tool)
+	}
+}
+
+// newCustomTool converts a custom tool config into a tool.
+func newCustomTool(custom config.CustomToolConfig) (*tools.CustomTool, error) {
+	toolType := tools.LINT
+	if custom.Type != "" {
+		parsed, err := tools.ParseToolType(custom.Type)
+		if err != nil {
+			return nil, err
+		}
+		toolType = parsed
+	}
+
+	return tools.NewCustomTool(tools.CustomToolSpec{
+		Name:             custom.Name,
+		Language:         custom.Language,
+		Type:             toolType,
+		Executable:       custom.Executable,
+		Args:             custom.Args,
+		FixArgs:          custom.FixArgs,
+		ConfigArgs:       custom.ConfigArgs,
+		Extensions:       custom.Extensions,
+		ConfigPatterns:   custom.ConfigPatterns,
+		FindingExitCodes: custom.FindingExitCodes,
+		InstallCommand:   custom.Install,
+		Output: tools.OutputSpec{
+			Format:          custom.Output.Format,
+			Pattern:         custom.Output.Pattern,
+			DefaultSeverity: custom.Output.Severity,
+			Root:            custom.Output.Root,
+			Fields:          custom.Output.Fields,
+		},
+	})
+}
+
 // registerAllTools registers all available quality tools.
 func registerAllTools(registry tools.ToolRegistry) {
 	// Go tools
@@ -881,7 +1490,14 @@ func registerAllTools(registry tools.ToolRegistry) {
 	// Rust tools
 	registry.Register(tools.NewRustfmtTool())
 	registry.Register(tools.NewClippyTool())
+	registry.Register(tools.NewCargoCheckTool())
+	registry.Register(tools.NewRustdocTool())
 	registry.Register(tools.NewCargoFmtTool())
+	registry.Register(tools.NewCargoAuditTool())
+	registry.Register(tools.NewCargoDenyTool())
+	registry.Register(tools.NewCargoMacheteTool())
+	registry.Register(tools.NewCargoUdepsTool())
+	registry.Register(tools.NewCargoSemverChecksTool())
 
 	// Markdown tools
 	registry.Register(tools.NewMarkdownlintTool())
@@ -976,7 +1592,14 @@ func (m *QualityManager) newToolCmd() *cobra.Command {
   tsc           TypeScript 린터
   rustfmt       Rust 포매터
   clippy        Rust 린터
+  cargo-check   Rust 타입 검사 (clippy와 함께 선택되면 생략)
+  rustdoc       Rust 문서 린터 (cargo doc)
   cargo-fmt     Rust 포매터
+  cargo-audit   Rust 의존성 취약점 검사
+  cargo-deny    Rust 의존성 정책 검사 (취약점, 라이선스, 금지 크레이트, 출처)
+  cargo-machete Rust 미사용 의존성 검사
+  cargo-udeps   Rust 미사용 의존성 검사 (nightly 툴체인 필요)
+  cargo-semver-checks Rust 공개 API 호환성 검사 (--since 기준)
 
 사용 예시:
   gz quality tool gofumpt --staged    # gofumpt로 staged 파일만 처리
@@ -1036,7 +1659,7 @@ func (m *QualityManager) addDirectToolFlags(cmd *cobra.Command) {
 	cmd.Flags().BoolP("fix", "x", false, "자동 수정 적용 (지원하는 도구만)")
 	cmd.Flags().IntP("workers", "w", 1, "병렬 실행 워커 수 (기본값: 1, 단일 도구)")
 	cmd.Flags().StringSlice("extra-args", nil, "도구에 전달할 추가 인수")
-	cmd.Flags().Bool("dry-run", false, "실제 실행하지 않고 계획만 표시")
+	cmd.Flags().Bool("dry-run", false, "실제 실행하지 않고 계획과 실행할 명령만 표시")
 	cmd.Flags().BoolP("verbose", "v", false, "상세 출력")
 
 	addGitFilterFlags(cmd)
@@ -1070,6 +1693,8 @@ func (m *QualityManager) runDirectTool(cmd *cobra.Command, _ []string, tool tool
 		return err
 	}
 
+	m.activateToolchain(projectRoot)
+
 	// Create execution plan with specific tool filter
 	planOptions := executor.PlanOptions{
 		Files:      files,
@@ -1077,6 +1702,7 @@ func (m *QualityManager) runDirectTool(cmd *cobra.Command, _ []string, tool tool
 		ExtraArgs:  extraArgs,
 		Language:   tool.Language(),
 		ToolFilter: []string{tool.Name()}, // Only this specific tool
+		Timeouts:   m.config.ToolTimeouts(),
 		Since:      since,
 		Staged:     staged,
 		Changed:    changed,
@@ -1096,8 +1722,11 @@ func (m *QualityManager) runDirectTool(cmd *cobra.Command, _ []string, tool tool
 	m.displayPlan(plan, verbose)
 
 	if dryRun {
-		fmt.Println("✨ 드라이런 모드: 실제 실행하지 않습니다.")
-		return nil
+		return m.dryRun(ctx, plan)
+	}
+
+	if err := m.checkToolVersions(plan); err != nil {
+		return err
 	}
 
 	// Execute plan
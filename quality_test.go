@@ -5,6 +5,7 @@ import (
 	"context"
 	"os"
 	"path/filepath"
+	"strings"
 	"testing"
 	"time"
 
@@ -12,7 +13,10 @@ import (
 	"github.com/stretchr/testify/assert"
 	"github.com/stretchr/testify/require"
 
+	"github.com/Gizzahub/gzh-cli-quality/config"
 	"github.com/Gizzahub/gzh-cli-quality/detector"
+	"github.com/Gizzahub/gzh-cli-quality/internal/testutil"
+	"github.com/Gizzahub/gzh-cli-quality/toolchain"
 	"github.com/Gizzahub/gzh-cli-quality/tools"
 )
 
@@ -46,7 +50,7 @@ func TestNewQualityCmd(t *testing.T) {
 		subcommandNames[cmdName] = true
 	}
 
-	expectedSubcommands := []string{"run", "check", "init", "analyze", "install", "upgrade", "version", "list", "tool"}
+	expectedSubcommands := []string{"run", "check", "fix", "init", "analyze", "install", "upgrade", "version", "outdated", "list", "tool"}
 	for _, expected := range expectedSubcommands {
 		assert.True(t, subcommandNames[expected], "Subcommand %s should exist", expected)
 	}
@@ -74,12 +78,29 @@ func TestQualityManagerCheckCmd(t *testing.T) {
 	cmd := manager.newCheckCmd()
 
 	assert.Equal(t, "check", cmd.Use)
-	assert.Contains(t, cmd.Short, "린팅만 실행")
+	assert.Contains(t, cmd.Short, "변경 없이 검사")
 	assert.NotEmpty(t, cmd.Long)
 	assert.NotNil(t, cmd.RunE)
 
 	// Check flags exist
-	flags := []string{"files", "workers", "extra-args", "dry-run", "verbose", "report", "output", "since", "staged", "changed"}
+	flags := []string{"files", "format-only", "lint-only", "workers", "extra-args", "dry-run", "verbose", "report", "output", "since", "staged", "changed"}
+	for _, flagName := range flags {
+		flag := cmd.Flags().Lookup(flagName)
+		assert.NotNil(t, flag, "Flag %s should exist", flagName)
+	}
+}
+
+func TestQualityManagerFixCmd(t *testing.T) {
+	manager := NewQualityManager()
+	cmd := manager.newFixCmd()
+
+	assert.Equal(t, "fix", cmd.Use)
+	assert.Contains(t, cmd.Short, "자동 수정")
+	assert.NotEmpty(t, cmd.Long)
+	assert.NotNil(t, cmd.RunE)
+
+	// Check flags exist
+	flags := []string{"files", "rule", "file", "unsafe", "preview", "workers", "dry-run", "since", "staged", "changed"}
 	for _, flagName := range flags {
 		flag := cmd.Flags().Lookup(flagName)
 		assert.NotNil(t, flag, "Flag %s should exist", flagName)
@@ -103,6 +124,8 @@ func TestQualityManagerInstallCmd(t *testing.T) {
 	assert.Contains(t, cmd.Short, "품질 도구 설치")
 	assert.NotEmpty(t, cmd.Long)
 	assert.NotNil(t, cmd.RunE)
+	assert.NotNil(t, cmd.Flags().Lookup("local"))
+	assert.NotNil(t, cmd.Flags().Lookup("mirror"))
 }
 
 func TestQualityManagerUpgradeCmd(t *testing.T) {
@@ -307,6 +330,77 @@ func TestConfigToYAML(t *testing.T) {
 	assert.Contains(t, yaml, "gofumpt:")
 }
 
+func TestRegisterCustomTools(t *testing.T) {
+	registry := tools.NewRegistry()
+	registerAllTools(registry)
+	builtins := len(registry.GetTools())
+
+	registerCustomTools(registry, []config.CustomToolConfig{
+		{
+			Name:       "hadolint",
+			Language:   "Docker",
+			Executable: "hadolint",
+			Args:       []string{"{files}"},
+			Output:     config.CustomOutputConfig{Format: "sarif"},
+		},
+		{
+			// Replaces the built-in shellcheck
+			Name:       "shellcheck",
+			Language:   "Shell",
+			Type:       "lint",
+			Executable: "shellcheck",
+			Args:       []string{"--format=gcc", "{files}"},
+			Output: config.CustomOutputConfig{
+				Format:  "regex",
+				Pattern: `^(?P<file>[^:]+):(?P<line>\d+):(?P<column>\d+): (?P<severity>\w+): (?P<message>.+)$`,
+			},
+		},
+		{Name: "broken", Executable: "broken", Type: "check"},
+	})
+
+	assert.Len(t, registry.GetTools(), builtins+1)
+
+	hadolint := registry.FindTool("hadolint")
+	require.NotNil(t, hadolint)
+	assert.Equal(t, "Docker", hadolint.Language())
+	assert.Equal(t, tools.LINT, hadolint.Type())
+
+	assert.IsType(t, &tools.CustomTool{}, registry.FindTool("shellcheck"))
+	assert.Nil(t, registry.FindTool("broken"))
+}
+
+func TestConfigureRustTools(t *testing.T) {
+	registry := tools.NewRegistry()
+	registerAllTools(registry)
+
+	configureRustTools(registry, config.RustConfig{
+		Deny:       []string{"clippy::pedantic"},
+		Allow:      []string{"clippy::module_name_repetitions"},
+		AllTargets: true,
+		Features:   []string{"serde", "tokio"},
+	})
+
+	clippy := registry.FindTool("clippy").(*tools.ClippyTool)
+	cmd := clippy.BuildCommand(nil, tools.ExecuteOptions{ProjectRoot: t.TempDir()})
+	assert.Equal(t, []string{
+		"clippy", "--all-targets", "--features", "serde,tokio", "--message-format", "json",
+		"--", "-D", "clippy::pedantic", "-A", "clippy::module_name_repetitions",
+	}, cmd.Args[1:])
+
+	// A relative advisory database is resolved against the working directory
+	configureRustTools(registry, config.RustConfig{AdvisoryDB: "advisory-db"})
+	advisoryDB, err := filepath.Abs("advisory-db")
+	require.NoError(t, err)
+
+	audit := registry.FindTool("cargo-audit").(*tools.CargoAuditTool)
+	cmd = audit.BuildCommand(nil, tools.ExecuteOptions{ProjectRoot: t.TempDir()})
+	assert.Equal(t, []string{"audit", "--json", "--db", advisoryDB, "--no-fetch"}, cmd.Args[1:])
+
+	deny := registry.FindTool("cargo-deny").(*tools.CargoDenyTool)
+	cmd = deny.BuildCommand(nil, tools.ExecuteOptions{})
+	assert.Equal(t, []string{"--format", "json", "check", "--disable-fetch"}, cmd.Args[1:])
+}
+
 func TestGetLanguageList(t *testing.T) {
 	languages := map[string][]string{
 		"Go":     {"main.go"},
@@ -687,6 +781,73 @@ func TestShowToolVersion_Installed(t *testing.T) {
 	})
 }
 
+func TestCheckToolVersions(t *testing.T) {
+	manager := NewQualityManager()
+	manager.config = config.DefaultConfig()
+
+	tool := &mockTool{name: "test-tool", language: "Go", toolType: tools.LINT}
+	missing := &mockToolNotAvailable{name: "missing-tool", language: "Go", toolType: tools.LINT}
+	plan := &tools.ExecutionPlan{Tasks: []tools.Task{{Tool: tool}, {Tool: tool}, {Tool: missing}}}
+
+	// No constraints configured
+	assert.NoError(t, manager.checkToolVersions(plan))
+
+	manager.config.Tools["test-tool"] = config.ToolConfig{Enabled: true, Version: "^1.0"}
+	manager.config.Tools["missing-tool"] = config.ToolConfig{Enabled: true, Version: ">=3"}
+	assert.NoError(t, manager.checkToolVersions(plan))
+
+	manager.config.Tools["test-tool"] = config.ToolConfig{Enabled: true, Version: ">=2, <3"}
+	err := manager.checkToolVersions(plan)
+	require.Error(t, err)
+	assert.Contains(t, err.Error(), "test-tool 1.0.0 does not satisfy required version >=2, <3")
+	assert.NotContains(t, err.Error(), "missing-tool")
+
+	assert.Contains(t, manager.versionProblem("test-tool", "unknown"), `cannot read installed version "unknown"`)
+
+	assert.NotPanics(t, func() {
+		manager.showToolVersion(tool)
+		manager.showToolVersion(missing)
+	})
+}
+
+func TestPinnedVersion(t *testing.T) {
+	manager := NewQualityManager()
+	manager.config = config.DefaultConfig()
+	locked := toolchain.LockedTool{Version: "0.6.1"}
+
+	// Without a constraint the lockfile decides; without either, the latest
+	assert.Equal(t, "0.6.1", manager.pinnedVersion("gofumpt", locked))
+	assert.Equal(t, "", manager.pinnedVersion("gofumpt", toolchain.LockedTool{}))
+
+	manager.config.Tools["gofumpt"] = config.ToolConfig{Enabled: true, Version: "^0.6"}
+	assert.Equal(t, "0.6.1", manager.pinnedVersion("gofumpt", locked))
+
+	manager.config.Tools["gofumpt"] = config.ToolConfig{Enabled: true, Version: "0.7.0"}
+	assert.Equal(t, "0.7.0", manager.pinnedVersion("gofumpt", locked))
+
+	manager.config.Tools["gofumpt"] = config.ToolConfig{Enabled: true, Version: ">=0.7"}
+	assert.Equal(t, "", manager.pinnedVersion("gofumpt", locked))
+}
+
+func TestFindOutdated(t *testing.T) {
+	manager := NewQualityManager()
+	manager.registry.Register(&mockTool{name: "old-tool", language: "Go", toolType: tools.LINT})
+	manager.registry.Register(&mockTool{name: "new-tool", language: "Go", toolType: tools.LINT})
+	manager.registry.Register(&mockToolNotAvailable{name: "missing-tool", language: "Go", toolType: tools.LINT})
+
+	index := map[string]string{
+		"old-tool":     "v1.2.0",
+		"new-tool":     "1.0.0",
+		"missing-tool": "9.0.0",
+	}
+
+	outdated := manager.findOutdated(nil, index)
+	assert.Equal(t, []outdatedTool{{name: "old-tool", installed: "1.0.0", latest: "1.2.0"}}, outdated)
+
+	// Named tools outside the index or registry are reported, not compared
+	assert.Empty(t, manager.findOutdated([]string{"new-tool", "unindexed", "missing-tool"}, index))
+}
+
 func TestRunDirectTool_DryRun(t *testing.T) {
 	manager := NewQualityManager()
 
@@ -1025,3 +1186,48 @@ func TestValidateGitFlags_ConflictingFlags(t *testing.T) {
 	err = manager.validateGitFlags("", false, true)
 	assert.NoError(t, err)
 }
+
+func TestRegisteredTools_ExecuteUsesOwnCommand(t *testing.T) {
+	registry := tools.NewRegistry()
+	registerAllTools(registry)
+
+	binDir := t.TempDir()
+	argsFile := filepath.Join(t.TempDir(), "args")
+	t.Setenv("GZQ_ARGS_FILE", argsFile)
+	t.Setenv("PATH", binDir+string(os.PathListSeparator)+os.Getenv("PATH"))
+	// cargo-udeps is skipped unless a nightly toolchain is selected or installed
+	t.Setenv("RUSTUP_TOOLCHAIN", "nightly")
+
+	files := []string{
+		"main.go", "app.py", "index.ts", "lib.rs", "README.md", "Main.java", "App.kt",
+		"run.sh", "main.cpp", "config.yaml", "query.sql", "Dockerfile", "Cargo.toml",
+		"api.proto", "style.css",
+	}
+	options := tools.ExecuteOptions{}
+
+	for _, tool := range registry.GetTools() {
+		t.Run(tool.Name(), func(t *testing.T) {
+			builder, ok := tool.(tools.CommandBuilder)
+			require.True(t, ok, "%s must implement tools.CommandBuilder", tool.Name())
+			_, ok = tool.(tools.OutputParser)
+			require.True(t, ok, "%s must implement tools.OutputParser", tool.Name())
+
+			// cargo-semver-checks needs a git worktree of a base commit; its
+			// Execute is covered by the tools package tests
+			if tool.Name() == "cargo-semver-checks" {
+				t.Skip("runs against a checked out base commit")
+			}
+
+			executable := filepath.Base(builder.BuildCommand(files, options).Path)
+			testutil.WriteExecutable(t, binDir, executable, `printf '%s\n' "$@" > "$GZQ_ARGS_FILE"`+"\n")
+
+			_, err := tool.Execute(context.Background(), files, options)
+			require.NoError(t, err)
+
+			recorded, err := os.ReadFile(argsFile)
+			require.NoError(t, err)
+			args := strings.Split(strings.TrimSuffix(string(recorded), "\n"), "\n")
+			assert.Equal(t, builder.BuildCommand(files, options).Args[1:], args)
+		})
+	}
+}
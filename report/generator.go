@@ -31,6 +31,10 @@ type Summary struct {
 	TotalTools      int `json:"total_tools"`
 	SuccessfulTools int `json:"successful_tools"`
 	FailedTools     int `json:"failed_tools"`
+	CleanTools      int `json:"clean_tools"`
+	ToolsWithIssues int `json:"tools_with_issues"`
+	TimedOutTools   int `json:"timed_out_tools"`
+	SkippedTools    int `json:"skipped_tools"`
 	TotalIssues     int `json:"total_issues"`
 	ErrorIssues     int `json:"error_issues"`
 	WarningIssues   int `json:"warning_issues"`
@@ -43,22 +47,43 @@ type ToolResult struct {
 	Tool           string        `json:"tool"`
 	Language       string        `json:"language"`
 	Success        bool          `json:"success"`
+	Status         tools.Status  `json:"status"`
+	ExitCode       int           `json:"exit_code"`
 	Duration       time.Duration `json:"duration"`
 	FilesProcessed int           `json:"files_processed"`
 	IssuesFound    int           `json:"issues_found"`
 	Error          string        `json:"error,omitempty"`
 }
 
+// EffectiveStatus returns Status, deriving it from Success and IssuesFound
+// when it is unset.
+func (r ToolResult) EffectiveStatus() tools.Status {
+	switch {
+	case r.Status != "":
+		return r.Status
+	case !r.Success:
+		return tools.StatusToolError
+	case r.IssuesFound > 0:
+		return tools.StatusIssues
+	default:
+		return tools.StatusClean
+	}
+}
+
 // Issue represents a quality issue.
 type Issue struct {
-	File       string `json:"file"`
-	Line       int    `json:"line"`
-	Column     int    `json:"column"`
-	Severity   string `json:"severity"`
-	Rule       string `json:"rule"`
-	Message    string `json:"message"`
-	Tool       string `json:"tool"`
-	Suggestion string `json:"suggestion,omitempty"`
+	File       string   `json:"file"`
+	Line       int      `json:"line"`
+	Column     int      `json:"column"`
+	EndLine    int      `json:"end_line,omitempty"`
+	EndColumn  int      `json:"end_column,omitempty"`
+	Severity   string   `json:"severity"`
+	Rule       string   `json:"rule"`
+	Message    string   `json:"message"`
+	Tool       string   `json:"tool"`
+	Suggestion string   `json:"suggestion,omitempty"`
+	Notes      []string `json:"notes,omitempty"`
+	Rendered   string   `json:"rendered,omitempty"`
 }
 
 // ReportGenerator generates quality reports.
@@ -90,6 +115,8 @@ func (g *ReportGenerator) GenerateReport(results []*tools.Result, duration time.
 			Tool:           result.Tool,
 			Language:       result.Language,
 			Success:        result.Success,
+			Status:         result.EffectiveStatus(),
+			ExitCode:       result.ExitCode,
 			Duration:       result.Duration,
 			FilesProcessed: result.FilesProcessed,
 			IssuesFound:    len(result.Issues),
@@ -107,11 +134,15 @@ func (g *ReportGenerator) GenerateReport(results []*tools.Result, duration time.
 				File:       issue.File,
 				Line:       issue.Line,
 				Column:     issue.Column,
+				EndLine:    issue.EndLine,
+				EndColumn:  issue.EndColumn,
 				Severity:   issue.Severity,
 				Rule:       issue.Rule,
 				Message:    issue.Message,
 				Tool:       result.Tool,
 				Suggestion: issue.Suggestion,
+				Notes:      issue.Notes,
+				Rendered:   issue.Rendered,
 			}
 
 			report.IssuesByFile[issue.File] = append(report.IssuesByFile[issue.File], reportIssue)
@@ -131,10 +162,22 @@ func (g *ReportGenerator) calculateSummary(report *Report) Summary {
 	}
 
 	for _, result := range report.ToolResults {
-		if result.Success {
-			summary.SuccessfulTools++
-		} else {
+		// Findings are a successful run; only tool failures count as failed
+		switch status := result.EffectiveStatus(); {
+		case status == tools.StatusSkipped:
+			summary.SkippedTools++
+		case status.IsFailure():
 			summary.FailedTools++
+			if status == tools.StatusTimeout {
+				summary.TimedOutTools++
+			}
+		default:
+			summary.SuccessfulTools++
+			if status == tools.StatusIssues {
+				summary.ToolsWithIssues++
+			} else {
+				summary.CleanTools++
+			}
 		}
 		summary.TotalIssues += result.IssuesFound
 	}
@@ -211,6 +254,8 @@ func (g *ReportGenerator) generateHTML(report *Report) string {
         .tool-results { display: grid; gap: 15px; }
         .tool-result { background: #f8f9fa; padding: 15px; border-radius: 6px; border-left: 4px solid #007bff; }
         .tool-result.failed { border-left-color: #dc3545; }
+        .tool-result.issues { border-left-color: #ffc107; }
+        .tool-result.skipped { border-left-color: #6c757d; }
         .issues-table { width: 100%; border-collapse: collapse; margin-top: 15px; }
         .issues-table th, .issues-table td { padding: 12px; text-align: left; border-bottom: 1px solid #e0e0e0; }
         .issues-table th { background: #f8f9fa; font-weight: 600; }
@@ -246,6 +291,10 @@ func (g *ReportGenerator) generateHTML(report *Report) string {
                 <div class="stat-value error">` + fmt.Sprintf("%d", report.Summary.FailedTools) + `</div>
                 <div class="stat-label">실패한 도구</div>
             </div>
+            <div class="stat-card">
+                <div class="stat-value warning">` + fmt.Sprintf("%d", report.Summary.ToolsWithIssues) + `</div>
+                <div class="stat-label">이슈 발견 도구</div>
+            </div>
             <div class="stat-card">
                 <div class="stat-value">` + fmt.Sprintf("%d", report.Summary.TotalIssues) + `</div>
                 <div class="stat-label">총 이슈</div>
@@ -258,19 +307,37 @@ func (g *ReportGenerator) generateHTML(report *Report) string {
             <div class="tool-results">`)
 
 	for _, result := range report.ToolResults {
-		status := "success"
-		if !result.Success {
-			status = "failed"
+		status := result.EffectiveStatus()
+		class := "success"
+		switch {
+		case status.IsFailure():
+			class = "failed"
+		case status == tools.StatusIssues:
+			class = "issues"
+		case status == tools.StatusSkipped:
+			class = "skipped"
 		}
 
-		sb.WriteString(`<div class="tool-result ` + status + `">
+		sb.WriteString(`<div class="tool-result ` + class + `">
                 <h3>` + result.Tool + ` (` + result.Language + `)</h3>
                 <p><strong>상태:</strong> `)
 
-		if result.Success {
+		switch status {
+		case tools.StatusClean:
 			sb.WriteString(`<span class="success">✅ 성공</span>`)
-		} else {
+		case tools.StatusIssues:
+			sb.WriteString(`<span class="warning">⚠️ 이슈 발견</span>`)
+		case tools.StatusSkipped:
+			sb.WriteString(`<span>⏭️ 건너뜀</span>`)
+		case tools.StatusTimeout:
+			sb.WriteString(`<span class="error">⏱️ 시간 초과</span>`)
+		case tools.StatusCancelled:
+			sb.WriteString(`<span class="error">⏱️ 취소됨</span>`)
+		default:
 			sb.WriteString(`<span class="error">❌ 실패</span>`)
+			if result.ExitCode != 0 {
+				sb.WriteString(fmt.Sprintf(" (종료 코드 %d)", result.ExitCode))
+			}
 		}
 
 		sb.WriteString(`</p>
@@ -365,7 +432,9 @@ func (g *ReportGenerator) generateMarkdown(report *Report) string {
 	sb.WriteString(fmt.Sprintf("- **생성 시간**: %s\n", report.Timestamp.Format("2006-01-02 15:04:05")))
 	sb.WriteString(fmt.Sprintf("- **분석 시간**: %s\n", report.Duration.String()))
 	sb.WriteString(fmt.Sprintf("- **총 파일 수**: %d\n", report.TotalFiles))
-	sb.WriteString(fmt.Sprintf("- **성공한 도구**: %d/%d\n", report.Summary.SuccessfulTools, report.Summary.TotalTools))
+	sb.WriteString(fmt.Sprintf("- **성공한 도구**: %d/%d (이슈 발견: %d, 실패: %d, 건너뜀: %d)\n",
+		report.Summary.SuccessfulTools, report.Summary.TotalTools,
+		report.Summary.ToolsWithIssues, report.Summary.FailedTools, report.Summary.SkippedTools))
 	sb.WriteString(fmt.Sprintf("- **총 이슈**: %d (오류: %d, 경고: %d, 정보: %d)\n\n",
 		report.Summary.TotalIssues, report.Summary.ErrorIssues, report.Summary.WarningIssues, report.Summary.InfoIssues))
 
@@ -374,13 +443,8 @@ func (g *ReportGenerator) generateMarkdown(report *Report) string {
 	sb.WriteString("|------|------|------|---------|---------|----------|\n")
 
 	for _, result := range report.ToolResults {
-		status := "✅"
-		if !result.Success {
-			status = "❌"
-		}
-
 		sb.WriteString(fmt.Sprintf("| %s | %s | %s | %d | %d | %s |\n",
-			result.Tool, result.Language, status, result.FilesProcessed, result.IssuesFound, result.Duration.String()))
+			result.Tool, result.Language, markdownStatus(result), result.FilesProcessed, result.IssuesFound, result.Duration.String()))
 	}
 
 	if len(report.IssuesByFile) > 0 {
@@ -403,6 +467,25 @@ func (g *ReportGenerator) generateMarkdown(report *Report) string {
 	return sb.String()
 }
 
+// markdownStatus renders a tool result status for the Markdown report.
+func markdownStatus(result ToolResult) string {
+	switch status := result.EffectiveStatus(); status {
+	case tools.StatusClean:
+		return "✅"
+	case tools.StatusIssues:
+		return "⚠️ issues"
+	case tools.StatusSkipped:
+		return "⏭️ skipped"
+	case tools.StatusTimeout, tools.StatusCancelled:
+		return "⏱️ " + string(status)
+	default:
+		if result.ExitCode != 0 {
+			return fmt.Sprintf("❌ exit %d", result.ExitCode)
+		}
+		return "❌"
+	}
+}
+
 // GetReportPath generates a report file path.
 func (g *ReportGenerator) GetReportPath(format string) string {
 	timestamp := time.Now().Format("20060102-150405")
@@ -86,10 +86,14 @@ func TestGenerateReport_WithIssues(t *testing.T) {
 					File:       "main.go",
 					Line:       10,
 					Column:     5,
+					EndLine:    10,
+					EndColumn:  6,
 					Severity:   "error",
 					Rule:       "unused-var",
 					Message:    "Variable 'x' is unused",
 					Suggestion: "Remove unused variable",
+					Notes:      []string{"note: declared here"},
+					Rendered:   "error: Variable 'x' is unused",
 				},
 				{
 					File:       "main.go",
@@ -136,6 +140,10 @@ func TestGenerateReport_WithIssues(t *testing.T) {
 	assert.Equal(t, "Variable 'x' is unused", mainIssue.Message)
 	assert.Equal(t, "golint", mainIssue.Tool)
 	assert.Equal(t, "Remove unused variable", mainIssue.Suggestion)
+	assert.Equal(t, 10, mainIssue.EndLine)
+	assert.Equal(t, 6, mainIssue.EndColumn)
+	assert.Equal(t, []string{"note: declared here"}, mainIssue.Notes)
+	assert.Equal(t, "error: Variable 'x' is unused", mainIssue.Rendered)
 
 	// Check summary
 	assert.Equal(t, 1, report.Summary.TotalTools)
@@ -227,6 +235,43 @@ func TestGenerateReport_MultipleTools(t *testing.T) {
 	assert.Equal(t, 1, report.Summary.WarningIssues)
 }
 
+func TestGenerateReport_StatusBreakdown(t *testing.T) {
+	generator := NewReportGenerator("/test/project")
+
+	results := []*tools.Result{
+		{Tool: "gofumpt", Success: true, Status: tools.StatusClean},
+		{
+			Tool:     "golangci-lint",
+			Success:  true,
+			Status:   tools.StatusIssues,
+			ExitCode: 1,
+			Issues:   []tools.Issue{{File: "main.go", Line: 3, Severity: "error"}},
+		},
+		{Tool: "clippy", Success: false, Status: tools.StatusToolError, ExitCode: 101, Error: "exit status 101"},
+		{Tool: "mypy", Success: false, Status: tools.StatusTimeout, ExitCode: -1, Error: "timed out"},
+		{Tool: "ruff", Success: false, Status: tools.StatusSkipped, ExitCode: -1, Error: "tool ruff is not available"},
+	}
+
+	report := generator.GenerateReport(results, time.Second, 3)
+
+	assert.Equal(t, 5, report.Summary.TotalTools)
+	assert.Equal(t, 2, report.Summary.SuccessfulTools)
+	assert.Equal(t, 1, report.Summary.CleanTools)
+	assert.Equal(t, 1, report.Summary.ToolsWithIssues)
+	assert.Equal(t, 2, report.Summary.FailedTools)
+	assert.Equal(t, 1, report.Summary.TimedOutTools)
+	assert.Equal(t, 1, report.Summary.SkippedTools)
+
+	assert.Equal(t, tools.StatusIssues, report.ToolResults[1].Status)
+	assert.Equal(t, 101, report.ToolResults[2].ExitCode)
+
+	md := generator.generateMarkdown(report)
+	assert.Contains(t, md, "⚠️ issues")
+	assert.Contains(t, md, "❌ exit 101")
+	assert.Contains(t, md, "⏱️ timeout")
+	assert.Contains(t, md, "⏭️ skipped")
+}
+
 func TestGenerateReport_InvalidDuration(t *testing.T) {
 	generator := NewReportGenerator("/test/project")
 
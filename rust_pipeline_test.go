@@ -0,0 +1,259 @@
+//nolint:testpackage // White-box testing needed for internal function access
+package quality
+
+import (
+	"bytes"
+	"encoding/json"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/spf13/cobra"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/Gizzahub/gzh-cli-quality/internal/testutil"
+	"github.com/Gizzahub/gzh-cli-quality/report"
+)
+
+// rustPipeline is a temporary Cargo project built from tests/fixtures/sample.rs
+// with stub cargo and rustfmt executables on PATH that replay recorded output
+// from tests/fixtures/rust.
+type rustPipeline struct {
+	root string
+}
+
+// newRustPipeline creates the project, installs the stubs and changes into the
+// project directory. The fixture's `Self { name, value }` is deformatted so the
+// rustfmt recording matches the source.
+func newRustPipeline(t *testing.T) *rustPipeline {
+	t.Helper()
+
+	source, err := os.ReadFile(filepath.Join("tests", "fixtures", "sample.rs"))
+	require.NoError(t, err)
+	clippy, err := os.ReadFile(filepath.Join("tests", "fixtures", "rust", "clippy.json"))
+	require.NoError(t, err)
+	rustfmt, err := os.ReadFile(filepath.Join("tests", "fixtures", "rust", "rustfmt-check.diff"))
+	require.NoError(t, err)
+
+	root, err := filepath.EvalSymlinks(t.TempDir())
+	require.NoError(t, err)
+
+	libRs := strings.Replace(string(source), "Self { name, value }", "Self {name, value}", 1)
+	require.NotEqual(t, string(source), libRs)
+	require.NoError(t, os.WriteFile(filepath.Join(root, "Cargo.toml"),
+		[]byte("[package]\nname = \"sample\"\nversion = \"0.1.0\"\nedition = \"2021\"\n"), 0o644))
+	require.NoError(t, os.MkdirAll(filepath.Join(root, "src"), 0o755))
+	require.NoError(t, os.WriteFile(filepath.Join(root, "src", "lib.rs"), []byte(libRs), 0o644))
+
+	// Recordings use @ROOT@ for the absolute project path
+	binDir := t.TempDir()
+	clippyReplay := filepath.Join(binDir, "clippy.json")
+	rustfmtReplay := filepath.Join(binDir, "rustfmt-check.diff")
+	require.NoError(t, os.WriteFile(clippyReplay, []byte(strings.ReplaceAll(string(clippy), "@ROOT@", root)), 0o644))
+	require.NoError(t, os.WriteFile(rustfmtReplay, []byte(strings.ReplaceAll(string(rustfmt), "@ROOT@", root)), 0o644))
+
+	// Only shell builtins are available: PATH holds nothing but the stubs
+	replay := `replay() { while IFS= read -r line; do printf '%s\n' "$line"; done < "$1"; }
+`
+	testutil.WriteExecutable(t, binDir, "cargo", replay+`
+case "$1" in
+  +*) shift ;;
+esac
+case "$1" in
+  clippy) replay "`+clippyReplay+`"; exit 101 ;;
+  fmt)
+    for arg in "$@"; do
+      if [ "$arg" = "--check" ]; then replay "`+rustfmtReplay+`"; exit 1; fi
+    done
+    exit 0 ;;
+  metadata) exit 1 ;;
+  *) exit 0 ;;
+esac
+`)
+	testutil.WriteExecutable(t, binDir, "rustfmt", replay+`
+for arg in "$@"; do
+  if [ "$arg" = "--check" ]; then replay "`+rustfmtReplay+`"; exit 1; fi
+done
+exit 0
+`)
+
+	t.Setenv("PATH", binDir)
+	t.Setenv("HOME", t.TempDir())
+	t.Setenv("RUSTUP_TOOLCHAIN", "")
+	t.Setenv("RUSTDOCFLAGS", "")
+	t.Chdir(root)
+
+	return &rustPipeline{root: root}
+}
+
+// run executes the subcommand created by newCmd with a JSON report and returns
+// the decoded report along with the command's error.
+func (p *rustPipeline) run(t *testing.T, newCmd func(*QualityManager) *cobra.Command, args ...string) (*report.Report, error) {
+	t.Helper()
+
+	reportPath := filepath.Join(t.TempDir(), "report.json")
+	cmd := newCmd(NewQualityManager())
+	cmd.SetArgs(append([]string{"--workers", "1", "--no-cache", "--report", "json", "--output", reportPath}, args...))
+	runErr := cmd.Execute()
+
+	data, err := os.ReadFile(reportPath)
+	require.NoError(t, err)
+	var qualityReport report.Report
+	require.NoError(t, json.Unmarshal(data, &qualityReport))
+
+	return &qualityReport, runErr
+}
+
+// libRs returns the current content of the project's src/lib.rs.
+func (p *rustPipeline) libRs(t *testing.T) string {
+	t.Helper()
+
+	data, err := os.ReadFile(filepath.Join(p.root, "src", "lib.rs"))
+	require.NoError(t, err)
+	return string(data)
+}
+
+// toolResults indexes the report's tool results by tool name.
+func toolResults(qualityReport *report.Report) map[string]report.ToolResult {
+	results := make(map[string]report.ToolResult, len(qualityReport.ToolResults))
+	for _, result := range qualityReport.ToolResults {
+		results[result.Tool] = result
+	}
+	return results
+}
+
+// issuesByTool collects the report's issues by the tool that found them.
+func issuesByTool(qualityReport *report.Report) map[string][]report.Issue {
+	issues := make(map[string][]report.Issue)
+	for _, fileIssues := range qualityReport.IssuesByFile {
+		for _, issue := range fileIssues {
+			issues[issue.Tool] = append(issues[issue.Tool], issue)
+		}
+	}
+	return issues
+}
+
+func TestRustPipeline_Check(t *testing.T) {
+	pipeline := newRustPipeline(t)
+	before := pipeline.libRs(t)
+
+	qualityReport, err := pipeline.run(t, (*QualityManager).newCheckCmd)
+	require.Error(t, err)
+	assert.Contains(t, err.Error(), "check failed: 3 issue(s) found, 0 tool(s) failed")
+
+	assert.Equal(t, pipeline.root, qualityReport.ProjectRoot)
+	assert.Equal(t, 1, qualityReport.TotalFiles)
+
+	results := toolResults(qualityReport)
+	names := make([]string, 0, len(results))
+	for name := range results {
+		names = append(names, name)
+	}
+	assert.ElementsMatch(t, []string{"rustfmt", "cargo-fmt", "clippy", "rustdoc"}, names)
+
+	for name, issues := range map[string]int{"rustfmt": 1, "cargo-fmt": 1, "clippy": 1, "rustdoc": 0} {
+		result := results[name]
+		assert.Equal(t, "Rust", result.Language, name)
+		assert.True(t, result.Success, name)
+		assert.Empty(t, result.Error, name)
+		assert.Equal(t, issues, result.IssuesFound, name)
+	}
+	assert.Equal(t, 1, results["rustfmt"].ExitCode)
+	assert.Equal(t, 101, results["clippy"].ExitCode)
+	assert.Equal(t, 0, results["rustdoc"].ExitCode)
+
+	summary := qualityReport.Summary
+	assert.Equal(t, 4, summary.TotalTools)
+	assert.Equal(t, 4, summary.SuccessfulTools)
+	assert.Equal(t, 0, summary.FailedTools)
+	assert.Equal(t, 3, summary.ToolsWithIssues)
+	assert.Equal(t, 1, summary.CleanTools)
+	assert.Equal(t, 3, summary.TotalIssues)
+	assert.Equal(t, 1, summary.ErrorIssues)
+	assert.Equal(t, 2, summary.WarningIssues)
+
+	issues := issuesByTool(qualityReport)
+	libPath := filepath.Join(pipeline.root, "src", "lib.rs")
+	for _, tool := range []string{"rustfmt", "cargo-fmt"} {
+		require.Len(t, issues[tool], 1, tool)
+		issue := issues[tool][0]
+		assert.Equal(t, libPath, issue.File, tool)
+		assert.Equal(t, 20, issue.Line, tool)
+		assert.Equal(t, "format", issue.Rule, tool)
+		assert.Equal(t, "warning", issue.Severity, tool)
+		assert.Equal(t, "file is not rustfmt-formatted", issue.Message, tool)
+		assert.Contains(t, issue.Suggestion, "@@ -20,7 +20,7 @@", tool)
+		assert.Contains(t, issue.Suggestion, "-        Self {name, value}\n+        Self { name, value }\n", tool)
+	}
+
+	require.Len(t, issues["clippy"], 1)
+	clippy := issues["clippy"][0]
+	assert.Equal(t, "src/lib.rs", clippy.File)
+	assert.Equal(t, 11, clippy.Line)
+	assert.Equal(t, 5, clippy.Column)
+	assert.Equal(t, 11, clippy.EndLine)
+	assert.Equal(t, 32, clippy.EndColumn)
+	assert.Equal(t, "error", clippy.Severity)
+	assert.Equal(t, "clippy::uninlined_format_args", clippy.Rule)
+	assert.Equal(t, "variables can be used directly in the `format!` string", clippy.Message)
+	assert.Contains(t, clippy.Suggestion, "change this to: `{input}`")
+	assert.Contains(t, clippy.Notes, "note: `-D clippy::uninlined-format-args` implied by `-D warnings`")
+	assert.Contains(t, clippy.Rendered, "--> src/lib.rs:11:5")
+	assert.Contains(t, qualityReport.IssuesByFile, "src/lib.rs")
+	assert.Contains(t, qualityReport.IssuesByFile, libPath)
+
+	// check never writes
+	assert.Equal(t, before, pipeline.libRs(t))
+}
+
+func TestRustPipeline_Fix(t *testing.T) {
+	pipeline := newRustPipeline(t)
+
+	qualityReport, err := pipeline.run(t, (*QualityManager).newFixCmd)
+	require.NoError(t, err)
+
+	// fix runs linters only; formatters are left to `gz quality run`
+	results := toolResults(qualityReport)
+	names := make([]string, 0, len(results))
+	for name := range results {
+		names = append(names, name)
+	}
+	assert.ElementsMatch(t, []string{"clippy", "rustdoc"}, names)
+	assert.Equal(t, 1, results["clippy"].IssuesFound)
+	assert.Equal(t, 1, qualityReport.Summary.TotalIssues)
+	assert.Equal(t, 1, qualityReport.Summary.ErrorIssues)
+
+	// The MachineApplicable suggestion is applied; the formatting issue remains
+	libRs := pipeline.libRs(t)
+	assert.Contains(t, libRs, `    format!("value: {input}")`)
+	assert.NotContains(t, libRs, `format!("value: {}", input)`)
+	assert.Contains(t, libRs, "Self {name, value}")
+}
+
+func TestRustPipeline_DryRun(t *testing.T) {
+	pipeline := newRustPipeline(t)
+	before := pipeline.libRs(t)
+
+	stdout := os.Stdout
+	r, w, err := os.Pipe()
+	require.NoError(t, err)
+	os.Stdout = w
+
+	cmd := NewQualityManager().newRunCmd()
+	cmd.SetArgs([]string{"--workers", "1", "--no-cache", "--dry-run"})
+	runErr := cmd.Execute()
+
+	w.Close()
+	os.Stdout = stdout
+	var output bytes.Buffer
+	_, _ = output.ReadFrom(r)
+	require.NoError(t, runErr)
+
+	// Each tool's exact command line is printed instead of being run
+	assert.Contains(t, output.String(), "cd "+pipeline.root+" && cargo clippy ")
+	assert.Contains(t, output.String(), "cd "+pipeline.root+" && cargo doc --no-deps ")
+	assert.Contains(t, output.String(), "rustfmt ")
+	assert.Equal(t, before, pipeline.libRs(t))
+}
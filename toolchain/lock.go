@@ -0,0 +1,98 @@
+// Copyright (c) 2025 Archmagece
+// SPDX-License-Identifier: MIT
+
+package toolchain
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+
+	yaml "gopkg.in/yaml.v3"
+)
+
+// LockFileName is the lockfile written next to the project's config.
+const LockFileName = "gzquality.lock"
+
+// lockVersion is the format version of the lockfile.
+const lockVersion = 1
+
+// Lock records the exact tools installed into the project toolchain.
+type Lock struct {
+	// Version is the lockfile format version
+	Version int `yaml:"version"`
+
+	// Tools maps tool names to their installed package
+	Tools map[string]LockedTool `yaml:"tools"`
+}
+
+// LockedTool is one installed tool.
+type LockedTool struct {
+	// Manager is the package manager used (go, pip, npm, cargo)
+	Manager string `yaml:"manager"`
+
+	// Package is the module, distribution, package or crate name
+	Package string `yaml:"package"`
+
+	// Version is the installed version
+	Version string `yaml:"version"`
+
+	// Executable is the installed executable, relative to the project root
+	Executable string `yaml:"executable"`
+
+	// Checksum is the sha256 of the executable ("sha256:<hex>")
+	Checksum string `yaml:"checksum"`
+}
+
+// LoadLock reads the project's lockfile. A missing lockfile yields an empty lock.
+func LoadLock(projectRoot string) (*Lock, error) {
+	lock := &Lock{Version: lockVersion, Tools: make(map[string]LockedTool)}
+
+	path := filepath.Join(projectRoot, LockFileName)
+	data, err := os.ReadFile(path)
+	if os.IsNotExist(err) {
+		return lock, nil
+	}
+	if err != nil {
+		return nil, fmt.Errorf("failed to read %s: %w", path, err)
+	}
+
+	if err := yaml.Unmarshal(data, lock); err != nil {
+		return nil, fmt.Errorf("failed to parse %s: %w", path, err)
+	}
+	if lock.Version != lockVersion {
+		return nil, fmt.Errorf("%s has unsupported version %d", path, lock.Version)
+	}
+	if lock.Tools == nil {
+		lock.Tools = make(map[string]LockedTool)
+	}
+
+	return lock, nil
+}
+
+// Save writes the lockfile into the project root.
+func (l *Lock) Save(projectRoot string) error {
+	data, err := yaml.Marshal(l)
+	if err != nil {
+		return fmt.Errorf("failed to marshal lockfile: %w", err)
+	}
+
+	header := []byte("# Generated by gz-quality install --local. Do not edit.\n")
+	path := filepath.Join(projectRoot, LockFileName)
+	if err := os.WriteFile(path, append(header, data...), 0o644); err != nil {
+		return fmt.Errorf("failed to write %s: %w", path, err)
+	}
+
+	return nil
+}
+
+// Names returns the locked tool names in sorted order.
+func (l *Lock) Names() []string {
+	names := make([]string, 0, len(l.Tools))
+	for name := range l.Tools {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+	return names
+}
@@ -0,0 +1,262 @@
+// Copyright (c) 2025 Archmagece
+// SPDX-License-Identifier: MIT
+
+// Package toolchain installs quality tools into a project-local directory at
+// pinned versions and records them in gzquality.lock.
+package toolchain
+
+import (
+	"context"
+	"crypto/sha256"
+	"encoding/hex"
+	"errors"
+	"fmt"
+	"io"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"strings"
+
+	"github.com/Gizzahub/gzh-cli-quality/tools"
+)
+
+// DirName is the project-local toolchain directory, relative to the project root.
+const DirName = ".gzquality/tools"
+
+// Installable is a tool the toolchain can provision.
+type Installable interface {
+	tools.QualityTool
+	tools.Packaged
+
+	// Executable returns the name of the tool's executable
+	Executable() string
+
+	// SetExecutable points the tool at an installed executable
+	SetExecutable(executable string)
+}
+
+// Toolchain installs each tool into its own prefix under Root.
+type Toolchain struct {
+	// Root holds one prefix per tool (<project>/.gzquality/tools)
+	Root string
+
+	// Mirror is a local artifact directory to install from instead of the
+	// network, with go/, pip/, npm/ and cargo/ subdirectories (empty means online)
+	Mirror string
+
+	projectRoot string
+
+	// run executes one installer command
+	run func(cmd *exec.Cmd) error
+}
+
+// New returns the toolchain of the project at projectRoot.
+func New(projectRoot string) *Toolchain {
+	return &Toolchain{
+		Root:        filepath.Join(projectRoot, filepath.FromSlash(DirName)),
+		projectRoot: projectRoot,
+		run:         runInstaller,
+	}
+}
+
+// Prefix returns the installation prefix of a tool.
+func (tc *Toolchain) Prefix(name string) string {
+	return filepath.Join(tc.Root, name)
+}
+
+// Install installs tool at version (empty means the latest) into its own
+// prefix, points the tool at the installed executable and returns the lock entry.
+func (tc *Toolchain) Install(ctx context.Context, tool Installable, version string) (LockedTool, error) {
+	pkg, ok := tool.Package()
+	if !ok {
+		return LockedTool{}, fmt.Errorf("%s is not published to a package registry", tool.Name())
+	}
+
+	prefix := tc.Prefix(tool.Name())
+	if err := os.RemoveAll(prefix); err != nil {
+		return LockedTool{}, fmt.Errorf("failed to clean %s: %w", prefix, err)
+	}
+	if err := os.MkdirAll(prefix, 0o755); err != nil {
+		return LockedTool{}, fmt.Errorf("failed to create %s: %w", prefix, err)
+	}
+
+	cmds, err := tc.commands(ctx, pkg, prefix, version)
+	if err != nil {
+		return LockedTool{}, err
+	}
+	for _, cmd := range cmds {
+		if err := tc.run(cmd); err != nil {
+			return LockedTool{}, fmt.Errorf("failed to install %s: %w", tool.Name(), err)
+		}
+	}
+
+	bin := binDir(prefix, pkg.Manager)
+	executable := filepath.Join(bin, filepath.Base(tool.Executable()))
+	checksum, err := Checksum(executable)
+	if err != nil {
+		return LockedTool{}, fmt.Errorf("installed %s but its executable is missing: %w", tool.Name(), err)
+	}
+
+	tool.SetExecutable(executable)
+
+	installed, err := tool.GetVersion()
+	if err != nil || installed == "unknown" {
+		installed = version
+	}
+
+	rel, err := filepath.Rel(tc.projectRoot, executable)
+	if err != nil {
+		rel = executable
+	}
+
+	return LockedTool{
+		Manager:    string(pkg.Manager),
+		Package:    pkg.Name,
+		Version:    installed,
+		Executable: filepath.ToSlash(rel),
+		Checksum:   checksum,
+	}, nil
+}
+
+// Verify checks that a locked tool is installed with its recorded checksum.
+func (tc *Toolchain) Verify(locked LockedTool) error {
+	path := filepath.Join(tc.projectRoot, filepath.FromSlash(locked.Executable))
+	checksum, err := Checksum(path)
+	if err != nil {
+		return fmt.Errorf("%s is not installed: %w", locked.Executable, err)
+	}
+	if checksum != locked.Checksum {
+		return fmt.Errorf("%s does not match the lockfile checksum", locked.Executable)
+	}
+	return nil
+}
+
+// Activate points the registered tools recorded in the project's lockfile at
+// their installed executables, so they take precedence over globally installed
+// ones. Tools whose executable is missing or does not match its checksum are
+// left alone and reported in the error. It returns the activated tool names.
+func Activate(projectRoot string, registry tools.ToolRegistry) ([]string, error) {
+	lock, err := LoadLock(projectRoot)
+	if err != nil {
+		return nil, err
+	}
+
+	tc := New(projectRoot)
+	var activated []string
+	var problems []error
+	for _, name := range lock.Names() {
+		installable, ok := registry.FindTool(name).(Installable)
+		if !ok {
+			continue
+		}
+		locked := lock.Tools[name]
+		if err := tc.Verify(locked); err != nil {
+			problems = append(problems, fmt.Errorf("%s: %w", name, err))
+			continue
+		}
+		installable.SetExecutable(filepath.Join(projectRoot, filepath.FromSlash(locked.Executable)))
+		activated = append(activated, name)
+	}
+
+	return activated, errors.Join(problems...)
+}
+
+// Checksum returns the sha256 of a file as "sha256:<hex>".
+func Checksum(path string) (string, error) {
+	file, err := os.Open(path)
+	if err != nil {
+		return "", err
+	}
+	defer file.Close()
+
+	hash := sha256.New()
+	if _, err := io.Copy(hash, file); err != nil {
+		return "", err
+	}
+	return "sha256:" + hex.EncodeToString(hash.Sum(nil)), nil
+}
+
+// commands builds the installer commands for pkg at version into prefix.
+func (tc *Toolchain) commands(ctx context.Context, pkg tools.Package, prefix, version string) ([]*exec.Cmd, error) {
+	mirror := ""
+	if tc.Mirror != "" {
+		abs, err := filepath.Abs(tc.Mirror)
+		if err != nil {
+			return nil, fmt.Errorf("invalid mirror directory %s: %w", tc.Mirror, err)
+		}
+		mirror = abs
+	}
+
+	switch pkg.Manager {
+	case tools.PackageGo:
+		ref := "latest"
+		if version != "" {
+			ref = "v" + strings.TrimPrefix(version, "v")
+		}
+		cmd := exec.CommandContext(ctx, "go", "install", pkg.Name+"@"+ref)
+		cmd.Env = append(os.Environ(), "GOBIN="+filepath.Join(prefix, "bin"))
+		if mirror != "" {
+			cmd.Env = append(cmd.Env, "GOPROXY=file://"+filepath.ToSlash(filepath.Join(mirror, "go")), "GOSUMDB=off")
+		}
+		return []*exec.Cmd{cmd}, nil
+
+	case tools.PackagePip:
+		spec := pkg.Name
+		if version != "" {
+			spec += "==" + version
+		}
+		args := []string{"-m", "pip", "install", "--disable-pip-version-check"}
+		if mirror != "" {
+			args = append(args, "--no-index", "--find-links", filepath.Join(mirror, "pip"))
+		}
+		return []*exec.Cmd{
+			exec.CommandContext(ctx, "python3", "-m", "venv", prefix),
+			exec.CommandContext(ctx, filepath.Join(prefix, "bin", "python"), append(args, spec)...),
+		}, nil
+
+	case tools.PackageNpm:
+		spec := pkg.Name + "@latest"
+		if version != "" {
+			spec = pkg.Name + "@" + version
+		}
+		args := []string{"install", "--prefix", prefix, "--no-audit", "--no-fund"}
+		if mirror != "" {
+			args = append(args, "--offline", "--cache", filepath.Join(mirror, "npm"))
+		}
+		return []*exec.Cmd{exec.CommandContext(ctx, "npm", append(args, spec)...)}, nil
+
+	case tools.PackageCargo:
+		args := []string{"install", "--root", prefix, "--locked"}
+		if version != "" {
+			args = append(args, "--version", version)
+		}
+		if mirror != "" {
+			args = append(args, "--offline")
+		}
+		cmd := exec.CommandContext(ctx, "cargo", append(args, pkg.Name)...)
+		if mirror != "" {
+			cmd.Env = append(os.Environ(), "CARGO_HOME="+filepath.Join(mirror, "cargo"))
+		}
+		return []*exec.Cmd{cmd}, nil
+
+	default:
+		return nil, fmt.Errorf("unsupported package manager %q", pkg.Manager)
+	}
+}
+
+// binDir returns where a package manager puts executables inside prefix.
+func binDir(prefix string, manager tools.PackageManager) string {
+	if manager == tools.PackageNpm {
+		return filepath.Join(prefix, "node_modules", ".bin")
+	}
+	return filepath.Join(prefix, "bin")
+}
+
+// runInstaller runs an installer command, including its output in errors.
+func runInstaller(cmd *exec.Cmd) error {
+	output, err := cmd.CombinedOutput()
+	if err != nil {
+		return fmt.Errorf("%s: %w\n%s", strings.Join(cmd.Args, " "), err, strings.TrimSpace(string(output)))
+	}
+	return nil
+}
@@ -0,0 +1,207 @@
+// Copyright (c) 2025 Archmagece
+// SPDX-License-Identifier: MIT
+
+package toolchain
+
+import (
+	"context"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/Gizzahub/gzh-cli-quality/internal/testutil"
+	"github.com/Gizzahub/gzh-cli-quality/tools"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+// envValue returns the last value of key in a command environment.
+func envValue(env []string, key string) string {
+	value := ""
+	for _, entry := range env {
+		if strings.HasPrefix(entry, key+"=") {
+			value = strings.TrimPrefix(entry, key+"=")
+		}
+	}
+	return value
+}
+
+func TestToolchain_Install(t *testing.T) {
+	t.Setenv("PATH", "/usr/bin")
+	projectRoot := t.TempDir()
+
+	tc := New(projectRoot)
+	var ran [][]string
+	tc.run = func(cmd *exec.Cmd) error {
+		ran = append(ran, cmd.Args)
+		// Fake "go install": drop a gofumpt that reports the requested version
+		testutil.WriteExecutable(t, envValue(cmd.Env, "GOBIN"), "gofumpt", "echo 'v0.6.0 (go1.22.0)'\n")
+		return nil
+	}
+
+	tool := tools.NewGofumptTool()
+	entry, err := tc.Install(context.Background(), tool, "0.6.0")
+	require.NoError(t, err)
+
+	assert.Equal(t, [][]string{{"go", "install", "mvdan.cc/gofumpt@v0.6.0"}}, ran)
+	assert.Equal(t, "go", entry.Manager)
+	assert.Equal(t, "mvdan.cc/gofumpt", entry.Package)
+	assert.Equal(t, "0.6.0", entry.Version)
+	assert.Equal(t, ".gzquality/tools/gofumpt/bin/gofumpt", entry.Executable)
+	assert.True(t, strings.HasPrefix(entry.Checksum, "sha256:"))
+
+	// The tool now runs the installed executable; PATH is left alone
+	path := filepath.Join(tc.Prefix("gofumpt"), "bin", "gofumpt")
+	assert.Equal(t, path, tool.Executable())
+	assert.Equal(t, "/usr/bin", os.Getenv("PATH"))
+
+	require.NoError(t, tc.Verify(entry))
+	require.NoError(t, os.WriteFile(path, []byte("#!/bin/sh\necho tampered\n"), 0o755))
+	assert.ErrorContains(t, tc.Verify(entry), "checksum")
+}
+
+func TestToolchain_Install_Errors(t *testing.T) {
+	tc := New(t.TempDir())
+	tc.run = func(cmd *exec.Cmd) error { return nil }
+
+	// Not published to a registry
+	_, err := tc.Install(context.Background(), tools.NewClippyTool(), "")
+	assert.ErrorContains(t, err, "not published")
+
+	// The installer succeeded but produced no executable
+	_, err = tc.Install(context.Background(), tools.NewRuffTool(), "0.6.2")
+	assert.ErrorContains(t, err, "executable is missing")
+}
+
+func TestToolchain_Commands(t *testing.T) {
+	mirror := t.TempDir()
+	tc := New(t.TempDir())
+	tc.Mirror = mirror
+	prefix := tc.Prefix("tool")
+
+	tests := []struct {
+		name     string
+		pkg      tools.Package
+		version  string
+		expected [][]string
+		env      map[string]string
+	}{
+		{
+			name:     "go latest",
+			pkg:      tools.Package{Manager: tools.PackageGo, Name: "mvdan.cc/gofumpt"},
+			expected: [][]string{{"go", "install", "mvdan.cc/gofumpt@latest"}},
+			env:      map[string]string{"GOBIN": filepath.Join(prefix, "bin"), "GOPROXY": "file://" + filepath.ToSlash(filepath.Join(mirror, "go")), "GOSUMDB": "off"},
+		},
+		{
+			name:    "pip",
+			pkg:     tools.Package{Manager: tools.PackagePip, Name: "ruff"},
+			version: "0.6.2",
+			expected: [][]string{
+				{"python3", "-m", "venv", prefix},
+				{filepath.Join(prefix, "bin", "python"), "-m", "pip", "install", "--disable-pip-version-check", "--no-index", "--find-links", filepath.Join(mirror, "pip"), "ruff==0.6.2"},
+			},
+		},
+		{
+			name:     "npm",
+			pkg:      tools.Package{Manager: tools.PackageNpm, Name: "eslint"},
+			version:  "8.57.0",
+			expected: [][]string{{"npm", "install", "--prefix", prefix, "--no-audit", "--no-fund", "--offline", "--cache", filepath.Join(mirror, "npm"), "eslint@8.57.0"}},
+		},
+		{
+			name:     "cargo",
+			pkg:      tools.Package{Manager: tools.PackageCargo, Name: "taplo-cli"},
+			version:  "0.9.0",
+			expected: [][]string{{"cargo", "install", "--root", prefix, "--locked", "--version", "0.9.0", "--offline", "taplo-cli"}},
+			env:      map[string]string{"CARGO_HOME": filepath.Join(mirror, "cargo")},
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			cmds, err := tc.commands(context.Background(), tt.pkg, prefix, tt.version)
+			require.NoError(t, err)
+
+			args := make([][]string, len(cmds))
+			for i, cmd := range cmds {
+				args[i] = cmd.Args
+			}
+			assert.Equal(t, tt.expected, args)
+
+			for key, value := range tt.env {
+				assert.Equal(t, value, envValue(cmds[0].Env, key), key)
+			}
+		})
+	}
+
+	_, err := tc.commands(context.Background(), tools.Package{Manager: "brew", Name: "x"}, prefix, "")
+	assert.ErrorContains(t, err, "unsupported package manager")
+}
+
+func TestActivate(t *testing.T) {
+	t.Setenv("PATH", "/usr/bin")
+	projectRoot := t.TempDir()
+	tc := New(projectRoot)
+
+	lock, err := LoadLock(projectRoot)
+	require.NoError(t, err)
+	for _, name := range []string{"gofumpt", "ruff"} {
+		bin := filepath.Join(tc.Prefix(name), "bin")
+		testutil.WriteExecutable(t, bin, name, "echo "+name+"\n")
+		checksum, err := Checksum(filepath.Join(bin, name))
+		require.NoError(t, err)
+		lock.Tools[name] = LockedTool{Executable: ".gzquality/tools/" + name + "/bin/" + name, Checksum: checksum}
+	}
+	require.NoError(t, lock.Save(projectRoot))
+
+	// Tampered after locking, and installed without being locked
+	testutil.WriteExecutable(t, filepath.Join(tc.Prefix("ruff"), "bin"), "ruff", "echo tampered\n")
+	testutil.WriteExecutable(t, filepath.Join(tc.Prefix("black"), "bin"), "black", "echo black\n")
+
+	registry := tools.NewRegistry()
+	gofumpt, ruff, black := tools.NewGofumptTool(), tools.NewRuffTool(), tools.NewBlackTool()
+	registry.Register(gofumpt)
+	registry.Register(ruff)
+	registry.Register(black)
+
+	activated, err := Activate(projectRoot, registry)
+
+	assert.Equal(t, []string{"gofumpt"}, activated)
+	assert.ErrorContains(t, err, "ruff: .gzquality/tools/ruff/bin/ruff does not match the lockfile checksum")
+	assert.Equal(t, filepath.Join(tc.Prefix("gofumpt"), "bin", "gofumpt"), gofumpt.Executable())
+	assert.Equal(t, "ruff", ruff.Executable())
+	assert.Equal(t, "black", black.Executable())
+	assert.Equal(t, "/usr/bin", os.Getenv("PATH"))
+}
+
+func TestActivate_WithoutLock(t *testing.T) {
+	registry := tools.NewRegistry()
+	registry.Register(tools.NewGofumptTool())
+
+	activated, err := Activate(t.TempDir(), registry)
+
+	require.NoError(t, err)
+	assert.Empty(t, activated)
+}
+
+func TestLock_SaveAndLoad(t *testing.T) {
+	projectRoot := t.TempDir()
+
+	lock, err := LoadLock(projectRoot)
+	require.NoError(t, err)
+	assert.Empty(t, lock.Tools)
+
+	lock.Tools["ruff"] = LockedTool{Manager: "pip", Package: "ruff", Version: "0.6.2", Executable: ".gzquality/tools/ruff/bin/ruff", Checksum: "sha256:abc"}
+	lock.Tools["eslint"] = LockedTool{Manager: "npm", Package: "eslint", Version: "8.57.0"}
+	require.NoError(t, lock.Save(projectRoot))
+
+	loaded, err := LoadLock(projectRoot)
+	require.NoError(t, err)
+	assert.Equal(t, lock.Tools, loaded.Tools)
+	assert.Equal(t, []string{"eslint", "ruff"}, loaded.Names())
+
+	require.NoError(t, os.WriteFile(filepath.Join(projectRoot, LockFileName), []byte("version: 9\n"), 0o644))
+	_, err = LoadLock(projectRoot)
+	assert.ErrorContains(t, err, "unsupported version")
+}
@@ -4,15 +4,26 @@
 package tools
 
 import (
+	"bytes"
 	"context"
+	"errors"
 	"fmt"
 	"os"
 	"os/exec"
 	"path/filepath"
+	"regexp"
+	"sort"
 	"strings"
 	"time"
 )
 
+// killGracePeriod is how long a cancelled tool gets to exit after SIGTERM
+// before its whole process group is killed.
+var killGracePeriod = 5 * time.Second
+
+// defaultVersionPattern extracts the first MAJOR.MINOR[.PATCH] from version output.
+var defaultVersionPattern = regexp.MustCompile(`v?(\d+\.\d+(?:\.\d+)?)`)
+
 // BaseTool provides common functionality for quality tools.
 type BaseTool struct {
 	name           string
@@ -21,6 +32,21 @@ type BaseTool struct {
 	executable     string
 	installCmd     []string
 	configPatterns []string
+	packages       []Package
+
+	// versionArgs and versionPattern describe how to query and extract the
+	// installed version; empty means common flags and defaultVersionPattern.
+	versionArgs    []string
+	versionPattern *regexp.Regexp
+
+	// findingExitCodes lists non-zero exit codes that mean "issues found"
+	// rather than "the tool failed".
+	findingExitCodes map[int]bool
+
+	// builder and parser point at the concrete tool embedding this BaseTool.
+	// Go method promotion cannot dispatch "virtually", so Execute uses these.
+	builder CommandBuilder
+	parser  OutputParser
 }
 
 // NewBaseTool creates a new base tool.
@@ -33,6 +59,33 @@ func NewBaseTool(name, language, executable string, toolType ToolType) *BaseTool
 	}
 }
 
+// Bind registers the concrete tool embedding this BaseTool so that Execute
+// dispatches to its BuildCommand and ParseOutput instead of the base defaults.
+func (t *BaseTool) Bind(impl interface{}) {
+	if builder, ok := impl.(CommandBuilder); ok {
+		t.builder = builder
+	}
+	if parser, ok := impl.(OutputParser); ok {
+		t.parser = parser
+	}
+}
+
+// commandBuilder returns the bound command builder or the base default.
+func (t *BaseTool) commandBuilder() CommandBuilder {
+	if t.builder != nil {
+		return t.builder
+	}
+	return t
+}
+
+// outputParser returns the bound output parser or the base default.
+func (t *BaseTool) outputParser() OutputParser {
+	if t.parser != nil {
+		return t.parser
+	}
+	return t
+}
+
 // Name returns the tool name.
 func (t *BaseTool) Name() string {
 	return t.name
@@ -54,18 +107,72 @@ func (t *BaseTool) IsAvailable() bool {
 	return err == nil
 }
 
-// SetInstallCommand sets the command to install this tool.
+// Executable returns the name of the tool's executable.
+func (t *BaseTool) Executable() string {
+	return t.executable
+}
+
+// SetExecutable replaces the tool's executable, e.g. with the absolute path of
+// a project-local installation.
+func (t *BaseTool) SetExecutable(executable string) {
+	t.executable = executable
+}
+
+// AddPackage adds a package the tool can be installed from. Packages are
+// tried in the order they were added.
+func (t *BaseTool) AddPackage(manager PackageManager, name string) {
+	t.packages = append(t.packages, Package{Manager: manager, Name: name})
+}
+
+// Packages returns the packages the tool can be installed from.
+func (t *BaseTool) Packages() []Package {
+	return t.packages
+}
+
+// Package returns the tool's first registry package, if it has one.
+func (t *BaseTool) Package() (Package, bool) {
+	for _, pkg := range t.packages {
+		if pkg.Manager.IsRegistry() {
+			return pkg, true
+		}
+	}
+	return Package{}, false
+}
+
+// SetInstallCommand sets a fixed command to install this tool. It takes
+// precedence over the tool's packages.
 func (t *BaseTool) SetInstallCommand(cmd []string) {
 	t.installCmd = cmd
 }
 
+// InstallCommand returns the command that installs the tool, or upgrades it
+// to the latest version, using the first available package manager.
+func (t *BaseTool) InstallCommand(upgrade bool) ([]string, error) {
+	if len(t.installCmd) > 0 {
+		return t.installCmd, nil
+	}
+	if len(t.packages) == 0 {
+		return nil, fmt.Errorf("no install command configured for %s", t.name)
+	}
+
+	installer, pkg, err := SelectInstaller(t.packages, DetectInstallers())
+	if err != nil {
+		return nil, fmt.Errorf("cannot install %s: %w", t.name, err)
+	}
+	if upgrade {
+		return installer.UpgradeCommand(pkg.Name), nil
+	}
+	return installer.InstallCommand(pkg.Name), nil
+}
+
 // Install attempts to install the tool automatically.
 func (t *BaseTool) Install() error {
-	if len(t.installCmd) == 0 {
-		return fmt.Errorf("no install command configured for %s", t.name)
+	args, err := t.InstallCommand(false)
+	if err != nil {
+		return err
 	}
 
-	cmd := exec.Command(t.installCmd[0], t.installCmd[1:]...)
+	cmd := exec.Command(args[0], args[1:]...)
 	output, err := cmd.CombinedOutput()
 	if err != nil {
 		return fmt.Errorf("failed to install %s: %w\nOutput: %s", t.name, err, string(output))
@@ -74,21 +181,28 @@ func (t *BaseTool) Install() error {
 	return nil
 }
 
-// GetVersion returns the version of the installed tool.
+// GetVersion returns the version of the installed tool, e.g. "0.6.2".
+// If the output contains no version number its first line is returned.
 func (t *BaseTool) GetVersion() (string, error) {
+	return t.GetVersionWith(LocalRunner{})
+}
+
+// GetVersionWith is GetVersion running the version command through runner.
+func (t *BaseTool) GetVersionWith(runner CommandRunner) (string, error) {
 	if !t.IsAvailable() {
 		return "", fmt.Errorf("tool %s is not installed", t.name)
 	}
 
 	// Try common version flags
-	versionFlags := []string{"--version", "-v", "-V", "version"}
+	versionArgs := [][]string{{"--version"}, {"-v"}, {"-V"}, {"version"}}
+	if len(t.versionArgs) > 0 {
+		versionArgs = [][]string{t.versionArgs}
+	}
 
-	for _, flag := range versionFlags {
-		cmd := exec.Command(t.executable, flag)
-		output, err := cmd.Output()
+	for _, args := range versionArgs {
+		output, err := runner.Run(context.Background(), Invocation{Args: append([]string{t.executable}, args...)})
 		if err == nil {
-			version := strings.TrimSpace(string(output))
-			if version != "" {
+			if version := t.ExtractVersion(string(output.Stdout)); version != "" {
 				return version, nil
 			}
 		}
@@ -97,14 +211,51 @@ func (t *BaseTool) GetVersion() (string, error) {
 	return "unknown", nil
 }
 
+// SetVersionCommand sets the arguments that print the tool's version.
+func (t *BaseTool) SetVersionCommand(args ...string) {
+	t.versionArgs = args
+}
+
+// SetVersionPattern sets the regex whose first group is the version number.
+func (t *BaseTool) SetVersionPattern(pattern string) {
+	t.versionPattern = regexp.MustCompile(pattern)
+}
+
+// ExtractVersion extracts the version number from version command output.
+func (t *BaseTool) ExtractVersion(output string) string {
+	pattern := defaultVersionPattern
+	if t.versionPattern != nil {
+		pattern = t.versionPattern
+	}
+	if matches := pattern.FindStringSubmatch(output); len(matches) > 1 {
+		return matches[1]
+	}
+
+	output = strings.TrimSpace(output)
+	if i := strings.IndexByte(output, '\n'); i >= 0 {
+		output = strings.TrimSpace(output[:i])
+	}
+	return output
+}
+
 // Upgrade attempts to upgrade the tool to the latest version.
 func (t *BaseTool) Upgrade() error {
 	if !t.IsAvailable() {
 		return fmt.Errorf("tool %s is not installed, use Install() instead", t.name)
 	}
 
-	// For most tools, upgrade is the same as install
-	return t.Install()
+	args, err := t.InstallCommand(true)
+	if err != nil {
+		return err
+	}
+
+	cmd := exec.Command(args[0], args[1:]...)
+	output, err := cmd.CombinedOutput()
+	if err != nil {
+		return fmt.Errorf("failed to upgrade %s: %w\nOutput: %s", t.name, err, string(output))
+	}
+
+	return nil
 }
 
 // SetConfigPatterns sets the configuration file patterns to search for.
@@ -112,6 +263,30 @@ func (t *BaseTool) SetConfigPatterns(patterns []string) {
 	t.configPatterns = patterns
 }
 
+// SetFindingExitCodes sets the non-zero exit codes with which the tool reports
+// findings. Any other non-zero exit code is treated as a tool error.
+func (t *BaseTool) SetFindingExitCodes(codes ...int) {
+	t.findingExitCodes = make(map[int]bool, len(codes))
+	for _, code := range codes {
+		t.findingExitCodes[code] = true
+	}
+}
+
+// IsFindingExitCode reports whether code means the tool reported findings.
+func (t *BaseTool) IsFindingExitCode(code int) bool {
+	return code == 0 || t.findingExitCodes[code]
+}
+
+// exitCodeRange returns the exit codes from low to high inclusive, for tools
+// whose exit code counts or encodes their findings.
+func exitCodeRange(low, high int) []int {
+	codes := make([]int, 0, high-low+1)
+	for code := low; code <= high; code++ {
+		codes = append(codes, code)
+	}
+	return codes
+}
+
 // FindConfigFiles returns configuration files the tool would use.
 func (t *BaseTool) FindConfigFiles(projectRoot string) []string {
 	var configs []string
@@ -126,10 +301,17 @@ func (t *BaseTool) FindConfigFiles(projectRoot string) []string {
 	return configs
 }
 
-// ExecuteCommand runs a command and returns the result.
+// ExecuteCommand runs a command locally and returns the result.
+// The process is tied to ctx: on cancellation it receives SIGTERM and, after a
+// grace period, its whole process group is killed.
+// Completed runs are left without a Status; see ClassifyResult.
 func (t *BaseTool) ExecuteCommand(ctx context.Context, cmd *exec.Cmd, files []string) (*Result, error) {
-	startTime := time.Now()
+	return t.ExecuteCommandWith(ctx, LocalRunner{}, cmd, files)
+}
 
+// ExecuteCommandWith runs the invocation cmd describes through runner and
+// returns the result. cmd itself is never started.
+func (t *BaseTool) ExecuteCommandWith(ctx context.Context, runner CommandRunner, cmd *exec.Cmd, files []string) (*Result, error) {
 	result := &Result{
 		Tool:     t.name,
 		Language: t.language,
@@ -137,14 +319,24 @@ func (t *BaseTool) ExecuteCommand(ctx context.Context, cmd *exec.Cmd, files []st
 		Issues:   []Issue{},
 	}
 
-	output, err := cmd.CombinedOutput()
-	duration := time.Since(startTime)
-	result.Duration = duration
-	result.Output = string(output)
+	output, err := runner.Run(ctx, InvocationOf(cmd))
+	result.Duration = output.Duration
+	result.Stdout = string(output.Stdout)
+	result.Stderr = string(output.Stderr)
+	result.Output = result.Stdout + result.Stderr
+	result.ExitCode = output.ExitCode
 	result.FilesProcessed = len(files)
 
 	if err != nil {
 		result.Error = err.Error()
+		switch {
+		case IsInterrupted(err):
+			result.Error = InterruptionReason(err)
+			result.Status = InterruptionStatus(err)
+		case errors.Is(err, ErrNotStarted):
+			// The process never started
+			result.Status = StatusToolError
+		}
 		return result, nil //nolint:nilerr // 오류를 결과에 캡처하여 반환하므로 에러는 무시
 	}
 
@@ -152,6 +344,112 @@ func (t *BaseTool) ExecuteCommand(ctx context.Context, cmd *exec.Cmd, files []st
 	return result, nil
 }
 
+// ClassifyResult sets the Status of a completed run from its exit code and
+// parsed issues. Results that already carry a Status are left untouched.
+//
+// Exit code 0 is clean (or issues, if any were parsed). A non-zero exit code
+// from the tool's findings table counts as issues only if issues were parsed;
+// anything else is a tool error.
+func (t *BaseTool) ClassifyResult(result *Result) {
+	if result.Status != "" {
+		return
+	}
+
+	switch {
+	case result.ExitCode == 0 && len(result.Issues) == 0:
+		result.Status = StatusClean
+	case t.IsFindingExitCode(result.ExitCode) && len(result.Issues) > 0:
+		result.Status = StatusIssues
+	default:
+		result.Status = StatusToolError
+	}
+
+	result.Success = !result.Status.IsFailure()
+	if result.Success {
+		result.Error = ""
+	} else if result.Error == "" {
+		result.Error = fmt.Sprintf("exit status %d", result.ExitCode)
+	}
+}
+
+// runCommand starts cmd in its own process group and waits for it, collecting
+// stdout and stderr separately. If ctx is done first, the group gets SIGTERM and,
+// once the leader exits or after killGracePeriod, SIGKILL; the context error is
+// returned.
+func runCommand(ctx context.Context, cmd *exec.Cmd) (stdout, stderr []byte, err error) {
+	if err := ctx.Err(); err != nil {
+		return nil, nil, err
+	}
+
+	var outBuf, errBuf bytes.Buffer
+	cmd.Stdout = &outBuf
+	cmd.Stderr = &errBuf
+	if cmd.WaitDelay == 0 {
+		cmd.WaitDelay = killGracePeriod
+	}
+	setProcessGroup(cmd)
+
+	if err := cmd.Start(); err != nil {
+		return nil, nil, err
+	}
+
+	done := make(chan struct{})
+	interrupted := make(chan error, 1)
+	go func() {
+		select {
+		case <-done:
+			interrupted <- nil
+		case <-ctx.Done():
+			_ = terminateProcessGroup(cmd)
+			select {
+			case <-done:
+			case <-time.After(killGracePeriod):
+			}
+			// Children that ignore SIGTERM outlive a leader that exited on it
+			_ = killProcessGroup(cmd)
+			interrupted <- ctx.Err()
+		}
+	}()
+
+	waitErr := cmd.Wait()
+	close(done)
+
+	if ctxErr := <-interrupted; ctxErr != nil {
+		return outBuf.Bytes(), errBuf.Bytes(), ctxErr
+	}
+	return outBuf.Bytes(), errBuf.Bytes(), waitErr
+}
+
+// exitCodeOf returns the exit code of a finished command, or -1 if it never
+// started or was terminated by a signal.
+func exitCodeOf(cmd *exec.Cmd) int {
+	if cmd.ProcessState == nil {
+		return -1
+	}
+	return cmd.ProcessState.ExitCode()
+}
+
+// IsInterrupted reports whether err stems from a cancelled or expired context.
+func IsInterrupted(err error) bool {
+	return errors.Is(err, context.Canceled) || errors.Is(err, context.DeadlineExceeded)
+}
+
+// InterruptionReason describes why a run was interrupted ("timed out" or "cancelled").
+func InterruptionReason(err error) string {
+	if errors.Is(err, context.DeadlineExceeded) {
+		return "timed out"
+	}
+	return "cancelled"
+}
+
+// InterruptionStatus maps an interruption error to StatusTimeout or StatusCancelled.
+func InterruptionStatus(err error) Status {
+	if errors.Is(err, context.DeadlineExceeded) {
+		return StatusTimeout
+	}
+	return StatusCancelled
+}
+
 // ParseOutput parses tool output into issues (to be implemented by specific tools).
 func (t *BaseTool) ParseOutput(output string) []Issue {
 	// Default implementation returns empty slice
@@ -175,42 +473,90 @@ func (t *BaseTool) BuildCommand(files []string, options ExecuteOptions) *exec.Cm
 	}
 
 	// Set environment variables
-	if len(options.Env) > 0 {
-		env := os.Environ()
-		for k, v := range options.Env {
-			env = append(env, fmt.Sprintf("%s=%s", k, v))
-		}
-		cmd.Env = env
-	}
+	cmd.Env = commandEnv(options)
 
 	return cmd
 }
 
+// commandEnv builds the environment of a tool command. Later entries take
+// precedence: the current process's environment, then options.Env, then vars
+// ("KEY=value"). It returns nil, inheriting the environment, when there is
+// nothing to add.
+func commandEnv(options ExecuteOptions, vars ...string) []string {
+	if len(options.Env) == 0 && len(vars) == 0 {
+		return nil
+	}
+
+	env := os.Environ()
+	keys := make([]string, 0, len(options.Env))
+	for key := range options.Env {
+		keys = append(keys, key)
+	}
+	sort.Strings(keys)
+	for _, key := range keys {
+		env = append(env, key+"="+options.Env[key])
+	}
+
+	return append(env, vars...)
+}
+
+// lookupEnv returns the value of key a command built with commandEnv(options)
+// sees before any vars are added.
+func lookupEnv(options ExecuteOptions, key string) string {
+	if value, ok := options.Env[key]; ok {
+		return value
+	}
+	return os.Getenv(key)
+}
+
 // Execute runs the tool on the specified files.
+// The command and output parsing are delegated to the concrete tool bound via Bind.
 func (t *BaseTool) Execute(ctx context.Context, files []string, options ExecuteOptions) (*Result, error) {
+	return t.executeWith(ctx, t.commandBuilder(), t.outputParser(), files, options)
+}
+
+// executeWith runs a command built by builder and parses its output with parser.
+func (t *BaseTool) executeWith(ctx context.Context, builder CommandBuilder, parser OutputParser, files []string, options ExecuteOptions) (*Result, error) {
 	if !t.IsAvailable() {
 		return &Result{
 			Tool:     t.name,
 			Language: t.language,
 			Success:  false,
+			Status:   StatusSkipped,
+			ExitCode: -1,
 			Error:    fmt.Sprintf("tool %s is not available", t.name),
 		}, nil
 	}
 
-	cmd := t.BuildCommand(files, options)
-	result, err := t.ExecuteCommand(ctx, cmd, files)
+	cmd := builder.BuildCommand(files, options)
+	if cmd.Env == nil {
+		// Builders that set no environment of their own still get options.Env
+		cmd.Env = commandEnv(options)
+	}
+	result, err := t.ExecuteCommandWith(ctx, runnerOf(options), cmd, files)
 	if err != nil {
 		return result, err
 	}
 
-	// Parse output for issues if the tool failed
-	if !result.Success {
-		result.Issues = t.ParseOutput(result.Output)
-	}
+	t.parseIssues(parser, result)
+	t.ClassifyResult(result)
 
 	return result, nil
 }
 
+// parseIssues parses findings from stdout, falling back to the combined output
+// for tools that report on stderr. Linters report findings on both zero and
+// non-zero exits, so this always runs.
+func (t *BaseTool) parseIssues(parser OutputParser, result *Result) {
+	issues := parser.ParseOutput(result.Stdout)
+	if len(issues) == 0 && result.Stderr != "" {
+		issues = parser.ParseOutput(result.Output)
+	}
+	if issues != nil {
+		result.Issues = issues
+	}
+}
+
 // FilterFilesByExtensions filters files by supported extensions.
 func FilterFilesByExtensions(files, extensions []string) []string {
 	var filtered []string
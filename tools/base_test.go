@@ -4,12 +4,18 @@
 package tools
 
 import (
+	"context"
 	"os"
+	"os/exec"
 	"path/filepath"
+	"strings"
 	"testing"
+	"time"
 
 	"github.com/stretchr/testify/assert"
 	"github.com/stretchr/testify/require"
+
+	"github.com/Gizzahub/gzh-cli-quality/internal/testutil"
 )
 
 func TestNewBaseTool(t *testing.T) {
@@ -122,7 +128,55 @@ func TestBaseTool_GetVersion_Installed(t *testing.T) {
 
 	assert.NoError(t, err)
 	assert.NotEmpty(t, version)
-	assert.Contains(t, version, "go version")
+	// "go version go1.22.0 linux/amd64" is reduced to the version number
+	assert.Regexp(t, `^\d+\.\d+`, version)
+}
+
+func TestBaseTool_GetVersion_VersionCommand(t *testing.T) {
+	binDir := t.TempDir()
+	testutil.WriteExecutable(t, binDir, "cargo", `if [ "$1" = "clippy" ]; then echo "clippy 0.1.75 (82e1608 2023-12-21)"; else echo "cargo 1.75.0 (1d8b05cdd 2023-11-20)"; fi
+`)
+	t.Setenv("PATH", binDir+string(os.PathListSeparator)+os.Getenv("PATH"))
+
+	version, err := NewClippyTool().GetVersion()
+	require.NoError(t, err)
+	assert.Equal(t, "0.1.75", version)
+}
+
+func TestExtractVersion(t *testing.T) {
+	tests := []struct {
+		tool     QualityTool
+		output   string
+		expected string
+	}{
+		{tool: NewGofumptTool(), output: "v0.6.0 (go1.22.0)\n", expected: "0.6.0"},
+		{tool: NewGolangciLintTool(), output: "golangci-lint has version 1.55.2 built with go1.21.3 from e3c2265f on 2023-11-03T12:59:25Z\n", expected: "1.55.2"},
+		{tool: NewGosecTool(), output: "Version: 2.18.2\nGit tag: v2.18.2\n", expected: "2.18.2"},
+		{tool: NewGovulncheckTool(), output: "Go: go1.22.1\nScanner: govulncheck@v1.0.4\nDB: https://vuln.go.dev\n", expected: "1.0.4"},
+		{tool: NewBlackTool(), output: "black, 24.1.1 (compiled: yes)\nPython (CPython) 3.11.6\n", expected: "24.1.1"},
+		{tool: NewRuffTool(), output: "ruff 0.4.1\n", expected: "0.4.1"},
+		{tool: NewPylintTool(), output: "pylint 3.0.3\nastroid 3.0.2\nPython 3.11.6\n", expected: "3.0.3"},
+		{tool: NewMypyTool(), output: "mypy 1.8.0 (compiled: yes)\n", expected: "1.8.0"},
+		{tool: NewPrettierTool(), output: "3.2.4\n", expected: "3.2.4"},
+		{tool: NewESLintTool(), output: "v8.56.0\n", expected: "8.56.0"},
+		{tool: NewTSCTool(), output: "Version 5.3.3\n", expected: "5.3.3"},
+		{tool: NewRustfmtTool(), output: "rustfmt 1.7.0-stable (82e1608d 2023-12-21)\n", expected: "1.7.0"},
+		{tool: NewClippyTool(), output: "clippy 0.1.75 (82e1608 2023-12-21)\n", expected: "0.1.75"},
+		{tool: NewShellcheckTool(), output: "ShellCheck - shell script analysis tool\nversion: 0.9.0\nlicense: GNU GPLv3\n", expected: "0.9.0"},
+		{tool: NewShfmtTool(), output: "v3.7.0\n", expected: "3.7.0"},
+		{tool: NewClangFormatTool(), output: "Ubuntu clang-format version 14.0.0-1ubuntu1.1\n", expected: "14.0.0"},
+		{tool: NewGoogleJavaFormatTool(), output: "google-java-format: Version 1.19.2\n", expected: "1.19.2"},
+		{tool: NewYamllintTool(), output: "yamllint 1.33.0\n", expected: "1.33.0"},
+		{tool: NewRuffTool(), output: "no version here\nsecond line\n", expected: "no version here"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.tool.Name(), func(t *testing.T) {
+			extractor, ok := tt.tool.(interface{ ExtractVersion(string) string })
+			require.True(t, ok)
+			assert.Equal(t, tt.expected, extractor.ExtractVersion(tt.output))
+		})
+	}
 }
 
 func TestBaseTool_Upgrade_NoCommand(t *testing.T) {
@@ -309,6 +363,186 @@ func TestBaseTool_BuildCommand_WithEnv(t *testing.T) {
 	assert.Equal(t, "true", envMap["DEBUG"])
 }
 
+func TestBaseTool_Execute_DispatchesToBoundTool(t *testing.T) {
+	binDir := t.TempDir()
+	argsFile := filepath.Join(t.TempDir(), "args")
+	testutil.WriteExecutable(t, binDir, "cargo", `printf '%s\n' "$@" > "$GZQ_ARGS_FILE"
+echo '{"reason":"compiler-message","message":{"message":"unneeded return statement","code":{"code":"clippy::needless_return"},"level":"warning","spans":[{"file_name":"src/main.rs","line_start":3,"column_start":5}]}}'
+exit 101
+`)
+	t.Setenv("GZQ_ARGS_FILE", argsFile)
+	t.Setenv("PATH", binDir+string(os.PathListSeparator)+os.Getenv("PATH"))
+
+	tool := NewClippyTool()
+	result, err := tool.Execute(context.Background(), []string{"src/main.rs"}, ExecuteOptions{})
+	require.NoError(t, err)
+
+	recorded, err := os.ReadFile(argsFile)
+	require.NoError(t, err)
+	args := strings.Split(strings.TrimSuffix(string(recorded), "\n"), "\n")
+	assert.Equal(t, tool.BuildCommand([]string{"src/main.rs"}, ExecuteOptions{}).Args[1:], args)
+
+	require.Len(t, result.Issues, 1)
+	assert.Equal(t, "clippy::needless_return", result.Issues[0].Rule)
+	assert.Equal(t, "src/main.rs", result.Issues[0].File)
+
+	// Exit code 101 with parsed findings is "issues", not a tool failure
+	assert.Equal(t, StatusIssues, result.Status)
+	assert.Equal(t, 101, result.ExitCode)
+	assert.True(t, result.Success)
+}
+
+func TestBaseTool_Execute_ClassifiesExitCodes(t *testing.T) {
+	finding := `echo '{"reason":"compiler-message","message":{"message":"m","level":"warning","spans":[{"file_name":"src/lib.rs","line_start":1,"column_start":1}]}}'`
+
+	tests := []struct {
+		name     string
+		script   string
+		status   Status
+		exitCode int
+	}{
+		{name: "clean", script: "exit 0", status: StatusClean},
+		{name: "findings on success", script: finding + "\nexit 0", status: StatusIssues},
+		{name: "findings exit code", script: finding + "\nexit 101", status: StatusIssues, exitCode: 101},
+		{name: "findings exit code without issues", script: "echo 'error: could not parse manifest' >&2\nexit 101", status: StatusToolError, exitCode: 101},
+		{name: "unknown exit code", script: finding + "\nexit 2", status: StatusToolError, exitCode: 2},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			binDir := t.TempDir()
+			testutil.WriteExecutable(t, binDir, "cargo", tt.script+"\n")
+			t.Setenv("PATH", binDir+string(os.PathListSeparator)+os.Getenv("PATH"))
+
+			result, err := NewClippyTool().Execute(context.Background(), []string{"src/lib.rs"}, ExecuteOptions{})
+			require.NoError(t, err)
+
+			assert.Equal(t, tt.status, result.Status)
+			assert.Equal(t, tt.exitCode, result.ExitCode)
+			assert.Equal(t, !tt.status.IsFailure(), result.Success)
+			if tt.status == StatusToolError {
+				assert.NotEmpty(t, result.Error)
+			} else {
+				assert.Empty(t, result.Error)
+			}
+		})
+	}
+}
+
+func TestBaseTool_ExecuteCommand_SeparatesStreams(t *testing.T) {
+	tool := NewBaseTool("sh", "Shell", "sh", LINT)
+
+	result, err := tool.ExecuteCommand(context.Background(), exec.Command("sh", "-c", "echo out; echo err >&2; exit 3"), nil)
+	require.NoError(t, err)
+
+	assert.Equal(t, "out\n", result.Stdout)
+	assert.Equal(t, "err\n", result.Stderr)
+	assert.Equal(t, "out\nerr\n", result.Output)
+	assert.Equal(t, 3, result.ExitCode)
+}
+
+func TestBaseTool_Execute_NotAvailableIsSkipped(t *testing.T) {
+	tool := NewBaseTool("missing", "Go", "nonexistent-tool-xyz", LINT)
+
+	result, err := tool.Execute(context.Background(), []string{"main.go"}, ExecuteOptions{})
+	require.NoError(t, err)
+
+	assert.Equal(t, StatusSkipped, result.Status)
+	assert.False(t, result.Success)
+}
+
+func TestBaseTool_Execute_UnboundUsesDefaults(t *testing.T) {
+	tool := NewBaseTool("echo", "Go", "echo", FORMAT)
+
+	result, err := tool.Execute(context.Background(), []string{"file1.go"}, ExecuteOptions{})
+	require.NoError(t, err)
+
+	assert.True(t, result.Success)
+	assert.Equal(t, "file1.go", strings.TrimSpace(result.Output))
+	assert.Empty(t, result.Issues)
+}
+
+func TestBaseTool_ExecuteCommand_Timeout(t *testing.T) {
+	tool := NewBaseTool("sh", "Shell", "sh", LINT)
+
+	ctx, cancel := context.WithTimeout(context.Background(), 100*time.Millisecond)
+	defer cancel()
+
+	// The background sleep shares the process group and must die with it
+	cmd := exec.Command("sh", "-c", "sleep 30 & wait")
+	start := time.Now()
+	result, err := tool.ExecuteCommand(ctx, cmd, nil)
+
+	require.NoError(t, err)
+	assert.False(t, result.Success)
+	assert.Equal(t, "timed out", result.Error)
+	assert.Equal(t, StatusTimeout, result.Status)
+	assert.Less(t, time.Since(start), 10*time.Second)
+}
+
+func TestBaseTool_ExecuteCommand_KillsAfterGracePeriod(t *testing.T) {
+	original := killGracePeriod
+	killGracePeriod = 200 * time.Millisecond
+	t.Cleanup(func() { killGracePeriod = original })
+
+	tool := NewBaseTool("sh", "Shell", "sh", LINT)
+
+	ctx, cancel := context.WithCancel(context.Background())
+	time.AfterFunc(100*time.Millisecond, cancel)
+
+	// SIGTERM is ignored, so only the SIGKILL of the process group stops it
+	cmd := exec.Command("sh", "-c", "trap '' TERM; sleep 30")
+	start := time.Now()
+	result, err := tool.ExecuteCommand(ctx, cmd, nil)
+
+	require.NoError(t, err)
+	assert.False(t, result.Success)
+	assert.Equal(t, "cancelled", result.Error)
+	assert.Equal(t, StatusCancelled, result.Status)
+	assert.Less(t, time.Since(start), 10*time.Second)
+}
+
+func TestBaseTool_ExecuteCommand_KillsGroupAfterLeaderExits(t *testing.T) {
+	tool := NewBaseTool("sh", "Shell", "sh", LINT)
+	counter := filepath.Join(t.TempDir(), "counter")
+
+	ctx, cancel := context.WithCancel(context.Background())
+	time.AfterFunc(300*time.Millisecond, cancel)
+
+	// The leader exits on SIGTERM right away; the grandchild ignores it and
+	// keeps counting until the process group is killed
+	script := `(trap '' TERM; i=0; while :; do i=$((i+1)); echo $i > "$1"; sleep 0.05; done) >/dev/null 2>&1 & wait`
+	cmd := exec.Command("sh", "-c", script, "sh", counter)
+	start := time.Now()
+	result, err := tool.ExecuteCommand(ctx, cmd, nil)
+
+	require.NoError(t, err)
+	assert.Equal(t, StatusCancelled, result.Status)
+	assert.Less(t, time.Since(start), killGracePeriod)
+
+	time.Sleep(100 * time.Millisecond)
+	before, err := os.ReadFile(counter)
+	require.NoError(t, err)
+	time.Sleep(300 * time.Millisecond)
+	after, err := os.ReadFile(counter)
+	require.NoError(t, err)
+	assert.Equal(t, string(before), string(after), "grandchild still running")
+}
+
+func TestBaseTool_ExecuteCommand_AlreadyCancelled(t *testing.T) {
+	tool := NewBaseTool("echo", "Go", "echo", LINT)
+
+	ctx, cancel := context.WithCancel(context.Background())
+	cancel()
+
+	result, err := tool.ExecuteCommand(ctx, exec.Command("echo", "never"), nil)
+
+	require.NoError(t, err)
+	assert.False(t, result.Success)
+	assert.Equal(t, "cancelled", result.Error)
+	assert.Empty(t, result.Output)
+}
+
 // Helper to split "KEY=VALUE" env strings
 func splitEnv(env string) []string {
 	for i := 0; i < len(env); i++ {
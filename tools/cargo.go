@@ -0,0 +1,854 @@
+// Copyright (c) 2025 Archmagece
+// SPDX-License-Identifier: MIT
+
+package tools
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"os"
+	"path/filepath"
+	"reflect"
+	"slices"
+	"sort"
+	"strings"
+	"sync"
+)
+
+// cargoPackage is a workspace member as reported by `cargo metadata`.
+type cargoPackage struct {
+	Name         string `json:"name"`
+	ID           string `json:"id"`
+	ManifestPath string `json:"manifest_path"`
+}
+
+// cargoMetadata is the subset of `cargo metadata --format-version 1` output
+// needed to map source files to workspace members.
+type cargoMetadata struct {
+	Packages         []cargoPackage `json:"packages"`
+	WorkspaceMembers []string       `json:"workspace_members"`
+	WorkspaceRoot    string         `json:"workspace_root"`
+}
+
+// cargoMetadataKey identifies memoized metadata: the same project read by
+// another cargo, or through another runner (a dry run or a replay), may differ.
+type cargoMetadataKey struct {
+	runner     CommandRunner
+	executable string
+	root       string
+}
+
+// cargoMetadataEntry is memoized metadata with the state of the manifests it
+// was read from.
+type cargoMetadataEntry struct {
+	metadata  *cargoMetadata
+	manifests []string
+	state     string
+}
+
+// cargoMetadataCache memoizes workspace metadata, since BuildCommand may be
+// called several times per task. An entry is valid while the root and member
+// manifests are unchanged.
+var cargoMetadataCache = struct {
+	sync.Mutex
+	entries map[cargoMetadataKey]cargoMetadataEntry
+}{entries: make(map[cargoMetadataKey]cargoMetadataEntry)}
+
+// loadCargoMetadata runs `cargo metadata --offline --no-deps` in projectRoot
+// through runner. Output that is not workspace metadata is an error.
+func loadCargoMetadata(ctx context.Context, runner CommandRunner, executable, projectRoot string) (*cargoMetadata, error) {
+	root := resolvePath(projectRoot)
+	key := cargoMetadataKey{runner: runner, executable: executable, root: root}
+
+	// Runners that cannot be told apart are not memoized
+	memoize := reflect.TypeOf(runner).Comparable()
+
+	cargoMetadataCache.Lock()
+	defer cargoMetadataCache.Unlock()
+	if memoize {
+		if entry, ok := cargoMetadataCache.entries[key]; ok && manifestState(entry.manifests) == entry.state {
+			return entry.metadata, nil
+		}
+	}
+
+	output, err := runner.Run(ctx, Invocation{
+		Args: []string{executable, "metadata", "--format-version", "1", "--offline", "--no-deps"},
+		Dir:  root,
+	})
+	if err != nil {
+		return nil, fmt.Errorf("cargo metadata failed: %w", err)
+	}
+
+	var metadata cargoMetadata
+	if err := json.Unmarshal(output.Stdout, &metadata); err != nil {
+		return nil, fmt.Errorf("failed to parse cargo metadata: %w", err)
+	}
+	if metadata.WorkspaceRoot == "" || len(metadata.Packages) == 0 {
+		return nil, fmt.Errorf("cargo metadata reported no workspace")
+	}
+
+	if memoize {
+		manifests := []string{filepath.Join(root, "Cargo.toml")}
+		for _, pkg := range metadata.Packages {
+			manifests = append(manifests, pkg.ManifestPath)
+		}
+		cargoMetadataCache.entries[key] = cargoMetadataEntry{metadata: &metadata, manifests: manifests, state: manifestState(manifests)}
+	}
+	return &metadata, nil
+}
+
+// manifestState describes the modification time and size of each manifest.
+func manifestState(manifests []string) string {
+	var state strings.Builder
+	for _, manifest := range manifests {
+		if info, err := os.Stat(manifest); err == nil {
+			fmt.Fprintf(&state, "%s|%d|%d;", manifest, info.ModTime().UnixNano(), info.Size())
+		} else {
+			fmt.Fprintf(&state, "%s|-;", manifest)
+		}
+	}
+	return state.String()
+}
+
+// preloadCargoMetadata loads the workspace metadata under the task's ctx
+// before the command is built, so that cancelling the run stops cargo
+// metadata and BuildCommand, which has no context, finds it memoized. It
+// returns the interrupted result when ctx ends first.
+func (t *BaseTool) preloadCargoMetadata(ctx context.Context, executable string, options ExecuteOptions) *Result {
+	root := options.ProjectRoot
+	if root == "" {
+		root = "."
+	}
+	_, _ = loadCargoMetadata(ctx, runnerOf(options), executable, root)
+
+	if err := ctx.Err(); err != nil {
+		return &Result{
+			Tool:     t.name,
+			Language: t.language,
+			Success:  false,
+			Status:   InterruptionStatus(err),
+			ExitCode: -1,
+			Error:    InterruptionReason(err),
+			Issues:   []Issue{},
+		}
+	}
+	return nil
+}
+
+// members returns the workspace members that own the given files. A file
+// belongs to the member whose manifest directory is its closest ancestor;
+// files outside every member (e.g. next to a virtual manifest) are skipped.
+func (m *cargoMetadata) members(files []string, projectRoot string) []string {
+	isMember := make(map[string]bool, len(m.WorkspaceMembers))
+	for _, id := range m.WorkspaceMembers {
+		isMember[id] = true
+	}
+
+	// With --no-deps every package is a member; the check guards older output
+	dirs := make(map[string]string)
+	for _, pkg := range m.Packages {
+		if len(isMember) == 0 || isMember[pkg.ID] {
+			dirs[resolvePath(filepath.Dir(pkg.ManifestPath))] = pkg.Name
+		}
+	}
+
+	owners := make(map[string]bool)
+	for _, file := range files {
+		if !filepath.IsAbs(file) {
+			file = filepath.Join(projectRoot, file)
+		}
+
+		// The closest ancestor directory that is a member wins
+		for dir := filepath.Dir(resolvePath(file)); ; dir = filepath.Dir(dir) {
+			if name, ok := dirs[dir]; ok {
+				owners[name] = true
+				break
+			}
+			if filepath.Dir(dir) == dir {
+				break
+			}
+		}
+	}
+
+	names := make([]string, 0, len(owners))
+	for name := range owners {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+
+	return names
+}
+
+// resolvePath makes path absolute and resolves symlinks in its longest
+// existing prefix, so paths (even of deleted files) compare equal to the
+// canonical ones cargo reports.
+func resolvePath(path string) string {
+	if abs, err := filepath.Abs(path); err == nil {
+		path = abs
+	}
+	for prefix, rest := path, ""; ; {
+		if resolved, err := filepath.EvalSymlinks(prefix); err == nil {
+			return filepath.Join(resolved, rest)
+		}
+		parent := filepath.Dir(prefix)
+		if parent == prefix {
+			return path
+		}
+		rest = filepath.Join(filepath.Base(prefix), rest)
+		prefix = parent
+	}
+}
+
+// cargoPackageArgs returns `-p <member>` flags limiting a cargo command to the
+// workspace members owning the given .rs files. It returns nil, meaning the
+// whole workspace, when no files are given or the metadata is unavailable.
+func cargoPackageArgs(runner CommandRunner, executable string, files []string, projectRoot string) []string {
+	rustFiles := FilterFilesByExtensions(files, []string{".rs"})
+	if len(rustFiles) == 0 {
+		return nil
+	}
+
+	root := projectRoot
+	if root == "" {
+		root = "."
+	}
+	// BuildCommand has no context; Execute preloads the metadata with the task's
+	metadata, err := loadCargoMetadata(context.Background(), runner, executable, root)
+	if err != nil {
+		return nil
+	}
+
+	members := metadata.members(rustFiles, root)
+	if len(members) == 0 {
+		return nil
+	}
+
+	args := make([]string, 0, 2*len(members))
+	for _, member := range members {
+		args = append(args, "-p", member)
+	}
+	return args
+}
+
+// cargoProjectInputs returns the manifests and lockfiles that results about
+// the crates of files depend on: each crate's Cargo.toml and every Cargo.toml
+// and Cargo.lock above it up to the project root, where workspaces keep theirs.
+func cargoProjectInputs(files []string, projectRoot string) []string {
+	root := absPath(projectRoot, "")
+
+	seen := make(map[string]bool)
+	var inputs []string
+	add := func(dir string) {
+		for _, name := range []string{"Cargo.toml", "Cargo.lock"} {
+			path := filepath.Join(dir, name)
+			if seen[path] {
+				continue
+			}
+			seen[path] = true
+			if _, err := os.Stat(path); err == nil {
+				inputs = append(inputs, path)
+			}
+		}
+	}
+
+	add(root)
+	for _, crate := range RustCrates(files, projectRoot) {
+		for dir := crate.Dir; dir != root && filepath.Dir(dir) != dir; dir = filepath.Dir(dir) {
+			add(dir)
+		}
+	}
+
+	sort.Strings(inputs)
+	return inputs
+}
+
+// cargoSourceInputs returns the Cargo project inputs along with the toolchain
+// files, manifests and Rust sources under projectRoot. Compiling tools report
+// on whole crates, and a change in one source, or in a workspace crate it
+// depends on, can change the findings in another.
+func cargoSourceInputs(files []string, projectRoot string) []string {
+	root := absPath(projectRoot, "")
+
+	seen := make(map[string]bool)
+	var inputs []string
+	add := func(path string) {
+		if !seen[path] {
+			seen[path] = true
+			inputs = append(inputs, path)
+		}
+	}
+
+	for _, input := range cargoProjectInputs(files, projectRoot) {
+		add(input)
+	}
+	_ = filepath.Walk(root, func(path string, info os.FileInfo, err error) error {
+		if err != nil {
+			return nil
+		}
+		if info.IsDir() {
+			// Build output and VCS metadata are not sources
+			if path != root && (info.Name() == "target" || strings.HasPrefix(info.Name(), ".")) {
+				return filepath.SkipDir
+			}
+			return nil
+		}
+		switch name := info.Name(); {
+		case filepath.Ext(name) == ".rs", name == "Cargo.toml", name == "rust-toolchain", name == "rust-toolchain.toml":
+			add(path)
+		}
+		return nil
+	})
+
+	sort.Strings(inputs)
+	return inputs
+}
+
+// cargoMessage is one line of `cargo ... --message-format json` output.
+type cargoMessage struct {
+	Reason  string          `json:"reason"`
+	Message cargoDiagnostic `json:"message"`
+}
+
+// cargoDiagnostic is a rustc JSON diagnostic; children are notes and help
+// messages attached to it.
+type cargoDiagnostic struct {
+	Message string `json:"message"`
+	Code    *struct {
+		Code string `json:"code"`
+	} `json:"code"`
+	Level    string            `json:"level"`
+	Spans    []cargoSpan       `json:"spans"`
+	Children []cargoDiagnostic `json:"children"`
+	Rendered string            `json:"rendered"`
+}
+
+// cargoSpan is a source span in rustc's JSON diagnostics.
+type cargoSpan struct {
+	FileName                string  `json:"file_name"`
+	ByteStart               int     `json:"byte_start"`
+	ByteEnd                 int     `json:"byte_end"`
+	LineStart               int     `json:"line_start"`
+	LineEnd                 int     `json:"line_end"`
+	ColumnStart             int     `json:"column_start"`
+	ColumnEnd               int     `json:"column_end"`
+	IsPrimary               bool    `json:"is_primary"`
+	SuggestedReplacement    *string `json:"suggested_replacement"`
+	SuggestionApplicability *string `json:"suggestion_applicability"`
+}
+
+// parseCargoDiagnostics converts the compiler messages of cargo's JSON output
+// (clippy, check, doc) into issues. Artifact, build-script and build-finished
+// messages are skipped, as are summaries without a source location.
+func parseCargoDiagnostics(output string) []Issue {
+	if strings.TrimSpace(output) == "" {
+		return []Issue{}
+	}
+
+	lines := strings.Split(output, "\n")
+	issues := make([]Issue, 0, len(lines))
+	seen := make(map[string]bool)
+
+	for _, line := range lines {
+		line = strings.TrimSpace(line)
+		if line == "" {
+			continue
+		}
+
+		var message cargoMessage
+		if err := json.Unmarshal([]byte(line), &message); err != nil || message.Reason != "compiler-message" {
+			continue
+		}
+
+		issue, ok := message.Message.issue()
+		if !ok {
+			continue
+		}
+
+		// Targets sharing sources (lib, tests, examples) repeat diagnostics
+		key := fmt.Sprintf("%s:%d:%d:%s:%s", issue.File, issue.Line, issue.Column, issue.Rule, issue.Message)
+		if seen[key] {
+			continue
+		}
+		seen[key] = true
+
+		issues = append(issues, issue)
+	}
+
+	return issues
+}
+
+// issue converts a diagnostic located at its primary span into an Issue.
+func (d cargoDiagnostic) issue() (Issue, bool) {
+	span, ok := d.primarySpan()
+	if !ok {
+		return Issue{}, false
+	}
+
+	severity := severityInfo
+	switch d.Level {
+	case severityError, "error: internal compiler error":
+		severity = severityError
+	case severityWarning:
+		severity = severityWarning
+	}
+
+	rule := ""
+	if d.Code != nil {
+		rule = d.Code.Code
+	}
+
+	// Suggestions are attached to the message's own spans or to its "help"
+	// children. Separate children are alternatives, so only one becomes the
+	// issue's edits; the others are only listed in the suggestion
+	alternatives := [][]TextEdit{suggestionEdits(d.Spans)}
+	var notes, helps []string
+	for _, child := range d.Children {
+		help := child.Message
+		for _, span := range child.Spans {
+			if span.SuggestedReplacement != nil && *span.SuggestedReplacement != "" {
+				help += fmt.Sprintf(": `%s`", *span.SuggestedReplacement)
+			}
+		}
+		alternatives = append(alternatives, suggestionEdits(child.Spans))
+
+		if child.Level == "help" {
+			helps = append(helps, help)
+		} else {
+			notes = append(notes, child.Level+": "+child.Message)
+		}
+	}
+
+	return Issue{
+		File:       span.FileName,
+		Line:       span.LineStart,
+		Column:     span.ColumnStart,
+		EndLine:    span.LineEnd,
+		EndColumn:  span.ColumnEnd,
+		Severity:   severity,
+		Rule:       rule,
+		Message:    d.Message,
+		Suggestion: strings.Join(helps, "\n"),
+		Notes:      notes,
+		Rendered:   d.Rendered,
+		Edits:      chooseEdits(alternatives),
+	}, true
+}
+
+// suggestionEdits converts the suggestions of one diagnostic's spans into
+// edits. Suggestions for the same span are alternatives; the first one wins.
+func suggestionEdits(spans []cargoSpan) []TextEdit {
+	var edits []TextEdit
+	for _, span := range spans {
+		edit, ok := span.edit()
+		if !ok {
+			continue
+		}
+		overlaps := slices.ContainsFunc(edits, func(other TextEdit) bool {
+			sameSpan := edit.StartOffset == other.StartOffset && edit.EndOffset == other.EndOffset
+			overlapping := edit.StartOffset < other.EndOffset && other.StartOffset < edit.EndOffset
+			return edit.File == other.File && (sameSpan || overlapping)
+		})
+		if !overlaps {
+			edits = append(edits, edit)
+		}
+	}
+	return edits
+}
+
+// chooseEdits picks the edits of the first alternative with a
+// machine-applicable suggestion, or else of the first one with any.
+func chooseEdits(alternatives [][]TextEdit) []TextEdit {
+	var first []TextEdit
+	for _, edits := range alternatives {
+		if len(edits) == 0 {
+			continue
+		}
+		if slices.ContainsFunc(edits, func(edit TextEdit) bool { return edit.Applicability == ApplicabilitySafe }) {
+			return edits
+		}
+		if first == nil {
+			first = edits
+		}
+	}
+	return first
+}
+
+// primarySpan returns the span rustc marks as primary, falling back to the
+// first span for output that does not mark one.
+func (d cargoDiagnostic) primarySpan() (cargoSpan, bool) {
+	if len(d.Spans) == 0 {
+		return cargoSpan{}, false
+	}
+	for _, span := range d.Spans {
+		if span.IsPrimary {
+			return span, true
+		}
+	}
+	return d.Spans[0], true
+}
+
+// edit converts a span carrying a suggested replacement into a TextEdit.
+func (s cargoSpan) edit() (TextEdit, bool) {
+	if s.SuggestedReplacement == nil {
+		return TextEdit{}, false
+	}
+
+	applicability := ApplicabilityManual
+	if s.SuggestionApplicability != nil {
+		switch *s.SuggestionApplicability {
+		case "MachineApplicable":
+			applicability = ApplicabilitySafe
+		case "MaybeIncorrect":
+			applicability = ApplicabilityUnsafe
+		}
+	}
+
+	return TextEdit{
+		File:          s.FileName,
+		StartOffset:   s.ByteStart,
+		EndOffset:     s.ByteEnd,
+		Replacement:   *s.SuggestedReplacement,
+		Applicability: applicability,
+	}, true
+}
+
+// normalizeCargoIssues rewrites issue and edit paths, which cargo reports
+// relative to the workspace root, relative to projectRoot, and drops
+// diagnostics located outside the workspace (path and registry dependencies).
+func normalizeCargoIssues(ctx context.Context, result *Result, runner CommandRunner, executable, projectRoot string) {
+	if result == nil || len(result.Issues) == 0 {
+		return
+	}
+
+	root := projectRoot
+	if root == "" {
+		root = "."
+	}
+	root = resolvePath(root)
+
+	workspaceRoot := root
+	if metadata, err := loadCargoMetadata(ctx, runner, executable, root); err == nil {
+		workspaceRoot = resolvePath(metadata.WorkspaceRoot)
+	}
+
+	normalize := func(file string) (string, bool) {
+		if !filepath.IsAbs(file) {
+			file = filepath.Join(workspaceRoot, file)
+		}
+		file = resolvePath(file)
+		if rel, err := filepath.Rel(workspaceRoot, file); err != nil || rel == ".." || strings.HasPrefix(rel, ".."+string(filepath.Separator)) {
+			return "", false
+		}
+		rel, err := filepath.Rel(root, file)
+		if err != nil {
+			return "", false
+		}
+		return rel, true
+	}
+
+	issues := result.Issues[:0]
+	for _, issue := range result.Issues {
+		file, ok := normalize(issue.File)
+		if !ok {
+			continue
+		}
+		issue.File = file
+
+		edits := issue.Edits[:0]
+		for _, edit := range issue.Edits {
+			if edit.File, ok = normalize(edit.File); ok {
+				edits = append(edits, edit)
+			}
+		}
+		if len(edits) == 0 {
+			edits = nil
+		}
+		issue.Edits = edits
+
+		issues = append(issues, issue)
+	}
+	result.Issues = issues
+}
+
+// lintTools are the tools whose lints a Cargo.toml [lints] table can configure.
+var lintTools = []string{"rust", "clippy", "rustdoc"}
+
+// manifestLints returns the lints configured by the [lints] table of the
+// Cargo.toml in projectRoot, following `workspace = true` to the workspace's
+// [workspace.lints], as command-line names ("unsafe_code", "clippy::pedantic").
+// The bool reports whether the manifest has a lints table at all.
+func manifestLints(runner CommandRunner, executable, projectRoot string) (map[string]bool, bool) {
+	root := projectRoot
+	if root == "" {
+		root = "."
+	}
+	manifest, err := readTOML(filepath.Join(root, "Cargo.toml"))
+	if err != nil {
+		return nil, false
+	}
+
+	lints := make(map[string]bool)
+	found := collectLints(manifest, "lints", lints)
+	found = collectLints(manifest, "workspace.lints", lints) || found
+
+	if inherit, ok := manifest.Get("lints", "workspace"); ok && inherit.Value == "true" {
+		// Called from BuildCommand, which has no context; see preloadCargoMetadata
+		if metadata, err := loadCargoMetadata(context.Background(), runner, executable, root); err == nil {
+			if workspace, err := readTOML(filepath.Join(metadata.WorkspaceRoot, "Cargo.toml")); err == nil {
+				collectLints(workspace, "workspace.lints", lints)
+			}
+		}
+	}
+
+	return lints, found
+}
+
+// collectLints adds the lints of a [lints]-style table (and its per-tool
+// subtables) to lints and reports whether the table exists.
+func collectLints(doc *tomlDocument, table string, lints map[string]bool) bool {
+	found := doc.HasTable(table)
+	for _, entry := range doc.Table(table) {
+		// Dotted keys: clippy.pedantic = "warn"
+		if tool, lint, ok := strings.Cut(entry.Key, "."); ok {
+			lints[lintName(tool, lint)] = true
+		}
+	}
+	for _, tool := range lintTools {
+		name := table + "." + tool
+		if !doc.HasTable(name) {
+			continue
+		}
+		found = true
+		for _, entry := range doc.Table(name) {
+			lints[lintName(tool, entry.Key)] = true
+		}
+	}
+	return found
+}
+
+// lintName returns the command-line name of a lint of the given tool.
+func lintName(tool, lint string) string {
+	if tool == "rust" {
+		return lint
+	}
+	return tool + "::" + lint
+}
+
+// dependencyTables are the tables a Cargo.toml declares dependencies in,
+// also below [target.'cfg(...)']; workspaces add [workspace.dependencies].
+var dependencyTables = []string{"dependencies", "dev-dependencies", "build-dependencies"}
+
+// cargoDependency is a dependency declared in a Cargo.toml.
+type cargoDependency struct {
+	// Table is the table declaring it ("dependencies", "target.'cfg(unix)'.dependencies")
+	Table string
+
+	// Key is the name it is declared under, Package the crate name, which
+	// differs when the dependency is renamed
+	Key     string
+	Package string
+
+	// Line and EndLine are the first and last line of the declaration
+	Line    int
+	EndLine int
+}
+
+// manifestDependencies returns the dependencies declared in a Cargo.toml in
+// document order, both as `name = ...` entries and as [dependencies.name] tables.
+func manifestDependencies(manifest *tomlDocument) []cargoDependency {
+	var deps []cargoDependency
+	for _, table := range manifest.Tables() {
+		if isDependencyTable(table) {
+			index := make(map[string]int)
+			for _, entry := range manifest.Table(table) {
+				// Dotted keys: serde.version = "1"
+				key, field, _ := strings.Cut(entry.Key, ".")
+				i, ok := index[key]
+				if !ok {
+					i = len(deps)
+					index[key] = i
+					deps = append(deps, cargoDependency{Table: table, Key: key, Package: key, Line: entry.Line})
+				}
+				deps[i].EndLine = entry.EndLine()
+
+				if field == "package" {
+					deps[i].Package, _ = tomlString(entry.Value)
+				} else if fields, ok := tomlInlineTable(entry.Value); ok && fields["package"] != "" {
+					deps[i].Package = fields["package"]
+				}
+			}
+			continue
+		}
+
+		// [dependencies.serde]
+		i := strings.LastIndex(table, ".")
+		if i < 0 || !isDependencyTable(table[:i]) {
+			continue
+		}
+		dep := cargoDependency{Table: table[:i], Key: table[i+1:], Package: table[i+1:], Line: manifest.TableLine(table)}
+		dep.EndLine = dep.Line
+		for _, entry := range manifest.Table(table) {
+			dep.EndLine = entry.EndLine()
+		}
+		if name, ok := manifest.String(table, "package"); ok {
+			dep.Package = name
+		}
+		deps = append(deps, dep)
+	}
+	return deps
+}
+
+// isDependencyTable reports whether a table name is a dependency table.
+func isDependencyTable(table string) bool {
+	if table == "workspace.dependencies" {
+		return true
+	}
+	for _, name := range dependencyTables {
+		if table == name || strings.HasPrefix(table, "target.") && strings.HasSuffix(table, "."+name) {
+			return true
+		}
+	}
+	return false
+}
+
+// cargoLockfile locates the crates that dependency tools such as cargo-audit
+// report on: in the project's Cargo.toml when they are direct dependencies,
+// otherwise in the Cargo.lock governing the project.
+type cargoLockfile struct {
+	// root is the project root locations are reported relative to
+	root string
+
+	// path is the Cargo.lock, found at or above root
+	path     string
+	lock     *tomlDocument
+	manifest *tomlDocument
+}
+
+// loadCargoLockfile reads the Cargo.toml of projectRoot and the nearest
+// Cargo.lock; either may be missing.
+func loadCargoLockfile(projectRoot string) *cargoLockfile {
+	root := projectRoot
+	if root == "" {
+		root = "."
+	}
+	root = resolvePath(root)
+
+	l := &cargoLockfile{root: root, path: findCargoLock(root)}
+	l.manifest, _ = readTOML(filepath.Join(root, "Cargo.toml"))
+	if l.path == "" {
+		l.path = filepath.Join(root, "Cargo.lock")
+	} else {
+		l.lock, _ = readTOML(l.path)
+	}
+	return l
+}
+
+// findCargoLock returns the nearest Cargo.lock at or above dir, or "".
+func findCargoLock(dir string) string {
+	for current := dir; ; current = filepath.Dir(current) {
+		lockfile := filepath.Join(current, "Cargo.lock")
+		if _, err := os.Stat(lockfile); err == nil {
+			return lockfile
+		}
+		if filepath.Dir(current) == current {
+			return ""
+		}
+	}
+}
+
+// locate returns the file, relative to the project root, and line where a
+// crate is declared. Without a lockfile, or for crates it does not list, the
+// location is Cargo.lock without a line.
+func (l *cargoLockfile) locate(name, version string) (string, int) {
+	if l == nil {
+		return "Cargo.lock", 0
+	}
+
+	if l.manifest != nil {
+		for _, dep := range manifestDependencies(l.manifest) {
+			if dep.Package == name {
+				return "Cargo.toml", dep.Line
+			}
+		}
+	}
+
+	lockfile := "Cargo.lock"
+	if rel, err := filepath.Rel(l.root, l.path); err == nil {
+		lockfile = rel
+	}
+	if l.lock == nil {
+		return lockfile, 0
+	}
+
+	// [[package]] entries all land in the "package" table: name, version, ...
+	packages := l.lock.Table("package")
+	for i, entry := range packages {
+		if entry.Key != "name" {
+			continue
+		}
+		if n, _ := tomlString(entry.Value); n != name {
+			continue
+		}
+		if i+1 < len(packages) && packages[i+1].Key == "version" {
+			if v, _ := tomlString(packages[i+1].Value); version != "" && v != version {
+				continue
+			}
+		}
+		return lockfile, entry.Line
+	}
+	return lockfile, 0
+}
+
+// unusedDependencyIssue reports a dependency of the manifest at manifestPath
+// (relative to projectRoot) as unused, located at its declaration with an
+// edit removing it. table restricts the lookup to one kind of dependency
+// table ("dev-dependencies"); empty matches any.
+func unusedDependencyIssue(projectRoot, manifestPath, name, table string) Issue {
+	root := resolvePath(absPath(".", projectRoot))
+	manifestPath = resolvePath(absPath(manifestPath, root))
+
+	issue := Issue{
+		File:       manifestPath,
+		Severity:   severityWarning,
+		Rule:       "unused-dependency",
+		Message:    fmt.Sprintf("dependency `%s` is unused", name),
+		Suggestion: "remove it from Cargo.toml",
+	}
+	if rel, err := filepath.Rel(root, manifestPath); err == nil {
+		issue.File = rel
+	}
+
+	manifest, err := readTOML(manifestPath)
+	if err != nil {
+		return issue
+	}
+	for _, dep := range manifestDependencies(manifest) {
+		if !sameCrateName(dep.Key, name) && !sameCrateName(dep.Package, name) {
+			continue
+		}
+		if table != "" && dep.Table != table && !strings.HasSuffix(dep.Table, "."+table) {
+			continue
+		}
+
+		issue.Line, issue.EndLine = dep.Line, dep.EndLine
+		issue.Message = fmt.Sprintf("dependency `%s` in [%s] is unused", dep.Key, dep.Table)
+		// Usage hidden in macros can go unnoticed, so removal needs review
+		issue.Edits = []TextEdit{{
+			File:          issue.File,
+			StartLine:     dep.Line,
+			StartColumn:   1,
+			EndLine:       dep.EndLine + 1,
+			EndColumn:     1,
+			Applicability: ApplicabilityUnsafe,
+		}}
+		break
+	}
+	return issue
+}
+
+// sameCrateName compares crate names the way cargo does, treating "-" and
+// "_" as equal.
+func sameCrateName(a, b string) bool {
+	return strings.ReplaceAll(a, "-", "_") == strings.ReplaceAll(b, "-", "_")
+}
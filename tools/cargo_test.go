@@ -0,0 +1,342 @@
+// Copyright (c) 2025 Archmagece
+// SPDX-License-Identifier: MIT
+
+package tools
+
+import (
+	"context"
+	"encoding/json"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/Gizzahub/gzh-cli-quality/internal/testutil"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+// writeCargoWorkspace creates manifests for members (relative directories)
+// below root and a fake cargo on PATH whose `metadata` reports them.
+func writeCargoWorkspace(t *testing.T, root string, members map[string]string) {
+	t.Helper()
+
+	root = resolvePath(root)
+	metadata := cargoMetadata{WorkspaceRoot: root}
+	for dir, name := range members {
+		manifest := filepath.Join(root, dir, "Cargo.toml")
+		require.NoError(t, os.MkdirAll(filepath.Dir(manifest), 0o755))
+		require.NoError(t, os.WriteFile(manifest, []byte("[package]\nname = \""+name+"\"\n"), 0o644))
+
+		id := "path+file://" + filepath.Dir(manifest) + "#" + name + "@0.1.0"
+		metadata.Packages = append(metadata.Packages, cargoPackage{Name: name, ID: id, ManifestPath: manifest})
+		metadata.WorkspaceMembers = append(metadata.WorkspaceMembers, id)
+	}
+	if _, ok := members["."]; !ok {
+		// Virtual manifest
+		require.NoError(t, os.WriteFile(filepath.Join(root, "Cargo.toml"), []byte("[workspace]\n"), 0o644))
+	}
+
+	data, err := json.Marshal(metadata)
+	require.NoError(t, err)
+
+	binDir := t.TempDir()
+	testutil.WriteExecutable(t, binDir, "cargo", "if [ \"$1\" = metadata ]; then\ncat <<'EOF'\n"+string(data)+"\nEOF\nfi\n")
+	t.Setenv("PATH", binDir)
+}
+
+func TestCargoPackageArgs(t *testing.T) {
+	t.Run("virtual workspace", func(t *testing.T) {
+		root := t.TempDir()
+		writeCargoWorkspace(t, root, map[string]string{
+			"crates/core":      "app-core",
+			"crates/cli":       "app-cli",
+			"crates/cli/macro": "app-macro",
+			"crates/unchanged": "app-unchanged",
+		})
+
+		files := []string{
+			filepath.Join(root, "crates", "core", "src", "lib.rs"),
+			filepath.Join(root, "crates", "core", "tests", "it.rs"),
+			"crates/cli/macro/src/lib.rs", // relative to the project root
+			filepath.Join(root, "crates", "cli", "README.md"),
+			filepath.Join(root, "build_helpers.rs"), // not owned by any member
+		}
+
+		args := cargoPackageArgs(LocalRunner{}, "cargo", files, root)
+		assert.Equal(t, []string{"-p", "app-core", "-p", "app-macro"}, args)
+
+		// Running from inside a member still sees the whole workspace
+		args = cargoPackageArgs(LocalRunner{}, "cargo", files[:1], filepath.Join(root, "crates", "core"))
+		assert.Equal(t, []string{"-p", "app-core"}, args)
+	})
+
+	t.Run("single package", func(t *testing.T) {
+		root := t.TempDir()
+		writeCargoWorkspace(t, root, map[string]string{".": "app"})
+
+		args := cargoPackageArgs(LocalRunner{}, "cargo", []string{filepath.Join(root, "src", "main.rs")}, root)
+		assert.Equal(t, []string{"-p", "app"}, args)
+	})
+
+	t.Run("whole workspace", func(t *testing.T) {
+		root := t.TempDir()
+		writeCargoWorkspace(t, root, map[string]string{"app": "app"})
+
+		// No Rust files, or no file inside a member
+		assert.Nil(t, cargoPackageArgs(LocalRunner{}, "cargo", nil, root))
+		assert.Nil(t, cargoPackageArgs(LocalRunner{}, "cargo", []string{filepath.Join(root, "Cargo.toml")}, root))
+		assert.Nil(t, cargoPackageArgs(LocalRunner{}, "cargo", []string{filepath.Join(root, "x.rs")}, root))
+
+		// cargo metadata failing (e.g. no cargo) falls back as well
+		t.Setenv("PATH", t.TempDir())
+		assert.Nil(t, cargoPackageArgs(LocalRunner{}, "cargo", []string{filepath.Join(t.TempDir(), "src", "lib.rs")}, t.TempDir()))
+	})
+}
+
+func TestRustTools_BuildCommand_WorkspaceMembers(t *testing.T) {
+	root := t.TempDir()
+	writeCargoWorkspace(t, root, map[string]string{
+		"crates/core": "app-core",
+		"crates/cli":  "app-cli",
+	})
+	files := []string{filepath.Join(root, "crates", "cli", "src", "main.rs")}
+
+	cmd := NewClippyTool().BuildCommand(files, ExecuteOptions{ProjectRoot: root, Fix: true})
+	assert.Equal(t, []string{"clippy", "--fix", "-p", "app-cli", "--message-format", "json", "--", "-D", "warnings"}, cmd.Args[1:])
+
+	cmd = NewCargoFmtTool().BuildCommand(files, ExecuteOptions{ProjectRoot: root, Check: true})
+	assert.Equal(t, []string{"fmt", "-p", "app-cli", "--", "--check"}, cmd.Args[1:])
+}
+
+// metadataRunner answers cargo metadata with stdout and counts its runs
+type metadataRunner struct {
+	stdout string
+	runs   int
+	ctxErr error
+}
+
+func (r *metadataRunner) Run(ctx context.Context, invocation Invocation) (*RunOutput, error) {
+	r.runs++
+	r.ctxErr = ctx.Err()
+	if r.ctxErr != nil {
+		return &RunOutput{ExitCode: -1}, r.ctxErr
+	}
+	return &RunOutput{Stdout: []byte(r.stdout)}, nil
+}
+
+func TestLoadCargoMetadata(t *testing.T) {
+	root := resolvePath(t.TempDir())
+	manifest := filepath.Join(root, "crates", "core", "Cargo.toml")
+	require.NoError(t, os.MkdirAll(filepath.Dir(manifest), 0o755))
+	require.NoError(t, os.WriteFile(filepath.Join(root, "Cargo.toml"), []byte("[workspace]\n"), 0o644))
+	require.NoError(t, os.WriteFile(manifest, []byte("[package]\nname = \"app-core\"\n"), 0o644))
+	data, err := json.Marshal(cargoMetadata{
+		WorkspaceRoot: root,
+		Packages:      []cargoPackage{{Name: "app-core", ManifestPath: manifest}},
+	})
+	require.NoError(t, err)
+
+	t.Run("memoized per runner and executable", func(t *testing.T) {
+		runner := &metadataRunner{stdout: string(data)}
+		for i := 0; i < 2; i++ {
+			metadata, err := loadCargoMetadata(context.Background(), runner, "cargo", root)
+			require.NoError(t, err)
+			assert.Equal(t, root, metadata.WorkspaceRoot)
+		}
+		assert.Equal(t, 1, runner.runs)
+
+		// Another cargo, or another runner such as a dry run, is asked again
+		_, err := loadCargoMetadata(context.Background(), runner, "/opt/rust/bin/cargo", root)
+		require.NoError(t, err)
+		assert.Equal(t, 2, runner.runs)
+		other := &metadataRunner{stdout: string(data)}
+		_, err = loadCargoMetadata(context.Background(), other, "cargo", root)
+		require.NoError(t, err)
+		assert.Equal(t, 1, other.runs)
+	})
+
+	t.Run("member manifest change invalidates", func(t *testing.T) {
+		runner := &metadataRunner{stdout: string(data)}
+		_, err := loadCargoMetadata(context.Background(), runner, "cargo", root)
+		require.NoError(t, err)
+
+		require.NoError(t, os.WriteFile(manifest, []byte("[package]\nname = \"app-core\"\nedition = \"2021\"\n"), 0o644))
+		_, err = loadCargoMetadata(context.Background(), runner, "cargo", root)
+		require.NoError(t, err)
+		assert.Equal(t, 2, runner.runs)
+	})
+
+	t.Run("output without a workspace is rejected", func(t *testing.T) {
+		for _, stdout := range []string{"{}", `{"workspace_root":"` + root + `","packages":[]}`} {
+			runner := &metadataRunner{stdout: stdout}
+			for i := 0; i < 2; i++ {
+				_, err := loadCargoMetadata(context.Background(), runner, "cargo", root)
+				assert.Error(t, err, stdout)
+			}
+			assert.Equal(t, 2, runner.runs, "%s must not be memoized", stdout)
+		}
+	})
+
+	t.Run("runs under the caller's context", func(t *testing.T) {
+		ctx, cancel := context.WithCancel(context.Background())
+		cancel()
+		runner := &metadataRunner{stdout: string(data)}
+
+		_, err := loadCargoMetadata(ctx, runner, "cargo", root)
+		assert.Error(t, err)
+		assert.ErrorIs(t, runner.ctxErr, context.Canceled)
+	})
+}
+
+func TestNormalizeCargoIssues(t *testing.T) {
+	root := t.TempDir()
+	writeCargoWorkspace(t, root, map[string]string{
+		"crates/core": "app-core",
+		"crates/cli":  "app-cli",
+	})
+	member := filepath.Join(root, "crates", "cli")
+
+	result := &Result{Issues: []Issue{
+		// cargo reports workspace members relative to the workspace root
+		{File: "crates/cli/src/main.rs", Edits: []TextEdit{{File: "crates/cli/src/main.rs"}}},
+		{File: "crates/core/src/lib.rs"},
+		{File: filepath.Join(root, "crates", "cli", "src", "args.rs")},
+		// Dependencies outside the workspace are dropped
+		{File: "/home/user/.cargo/registry/src/index.crates.io-6f17d22bba15001f/serde-1.0.0/src/lib.rs"},
+		{File: filepath.Join(filepath.Dir(root), "vendored", "src", "lib.rs")},
+	}}
+
+	normalizeCargoIssues(context.Background(), result, LocalRunner{}, "cargo", member)
+
+	require.Len(t, result.Issues, 3)
+	assert.Equal(t, filepath.Join("src", "main.rs"), result.Issues[0].File)
+	assert.Equal(t, filepath.Join("src", "main.rs"), result.Issues[0].Edits[0].File)
+	assert.Equal(t, filepath.Join("..", "core", "src", "lib.rs"), result.Issues[1].File)
+	assert.Equal(t, filepath.Join("src", "args.rs"), result.Issues[2].File)
+}
+
+func TestManifestDependencies(t *testing.T) {
+	manifest, err := parseTOML(`[package]
+name = "app"
+
+[dependencies]
+serde = { version = "1", features = ["derive"] }
+rand_core = { package = "rand", version = "0.8" }
+tokio.workspace = true
+tokio.features = ["rt"]
+regex = [
+  "1",
+]
+
+[dependencies.log]
+version = "0.4"
+package = "log"
+
+[target.'cfg(unix)'.dev-dependencies]
+nix = "0.27"
+
+[features]
+default = []
+`)
+	require.NoError(t, err)
+
+	assert.Equal(t, []cargoDependency{
+		{Table: "dependencies", Key: "serde", Package: "serde", Line: 5, EndLine: 5},
+		{Table: "dependencies", Key: "rand_core", Package: "rand", Line: 6, EndLine: 6},
+		{Table: "dependencies", Key: "tokio", Package: "tokio", Line: 7, EndLine: 8},
+		{Table: "dependencies", Key: "regex", Package: "regex", Line: 9, EndLine: 11},
+		{Table: "dependencies", Key: "log", Package: "log", Line: 13, EndLine: 15},
+		{Table: "target.cfg(unix).dev-dependencies", Key: "nix", Package: "nix", Line: 18, EndLine: 18},
+	}, manifestDependencies(manifest))
+}
+
+func TestParseTOML(t *testing.T) {
+	doc, err := parseTOML(`"foo.bar" = 1
+'a' . "b" = 2
+
+[patch."https://github.com/x/y"]
+y = { path = "../y" }
+
+[package]
+description = """
+Not # a comment,
+nor "this" # one"""
+license = 'MIT # really' # a comment
+dep = {
+  version = "1", # trailing
+  features = ["a#b"],
+}
+`)
+	require.NoError(t, err)
+
+	assert.Equal(t, []tomlEntry{
+		{Key: `"foo.bar"`, Value: "1", Line: 1},
+		{Key: "a.b", Value: "2", Line: 2},
+	}, doc.Table(""))
+	assert.True(t, doc.HasTable(`patch."https://github.com/x/y"`))
+	assert.Equal(t, 4, doc.TableLine(`patch."https://github.com/x/y"`))
+
+	description, ok := doc.Get("package", "description")
+	require.True(t, ok)
+	assert.Equal(t, "\"\"\"\nNot # a comment,\nnor \"this\" # one\"\"\"", description.Value)
+	assert.Equal(t, 10, description.EndLine())
+
+	license, ok := doc.String("package", "license")
+	require.True(t, ok)
+	assert.Equal(t, "MIT # really", license)
+
+	dep, ok := doc.Get("package", "dep")
+	require.True(t, ok)
+	assert.Equal(t, 12, dep.Line)
+	assert.Equal(t, 15, dep.EndLine())
+	fields, ok := tomlInlineTable(dep.Value)
+	require.True(t, ok)
+	assert.Equal(t, map[string]string{"version": "1", "features": `["a#b"]`}, fields)
+}
+
+func TestParseTOML_Errors(t *testing.T) {
+	tests := []struct {
+		name string
+		data string
+	}{
+		{"unterminated string", "name = \"app\n"},
+		{"unterminated multi-line string", "description = \"\"\"\nno end\n"},
+		{"unterminated array", "members = [\n  \"a\",\n"},
+		{"unbalanced bracket", "members = \"a\"]\n"},
+		{"not a key/value pair", "[package]\nname\n"},
+		{"missing value", "name =\n"},
+		{"invalid key", "na$me = 1\n"},
+		{"invalid header", "[package\nname = \"app\"\n"},
+		{"text after header", "[package] name = \"app\"\n"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			_, err := parseTOML(tt.data)
+			assert.Error(t, err)
+		})
+	}
+}
+
+func TestCargoProjectInputs(t *testing.T) {
+	root := resolvePath(t.TempDir())
+	testutil.WriteFile(t, root, "Cargo.toml", "[workspace]\nmembers = [\"crates/*\"]\n")
+	testutil.WriteFile(t, root, "Cargo.lock", "version = 3\n")
+	testutil.WriteFile(t, filepath.Join(root, "crates", "core"), "Cargo.toml", "[package]\nname = \"core\"\n")
+	testutil.WriteFile(t, filepath.Join(root, "crates", "core", "src"), "lib.rs", "pub fn f() {}\n")
+	testutil.WriteFile(t, filepath.Join(root, "crates", "cli"), "Cargo.toml", "[package]\nname = \"cli\"\n")
+
+	inputs := cargoProjectInputs([]string{"crates/core/src/lib.rs"}, root)
+
+	assert.Equal(t, []string{
+		filepath.Join(root, "Cargo.lock"),
+		filepath.Join(root, "Cargo.toml"),
+		filepath.Join(root, "crates", "core", "Cargo.toml"),
+	}, inputs)
+
+	for _, tool := range []QualityTool{NewCargoAuditTool(), NewCargoDenyTool()} {
+		scoped, ok := tool.(ProjectScoped)
+		require.True(t, ok, tool.Name())
+		assert.Equal(t, inputs, scoped.ProjectInputs([]string{"crates/core/src/lib.rs"}, root), tool.Name())
+	}
+}
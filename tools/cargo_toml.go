@@ -0,0 +1,407 @@
+// Copyright (c) 2025 Archmagece
+// SPDX-License-Identifier: MIT
+
+package tools
+
+import (
+	"fmt"
+	"os"
+	"strconv"
+	"strings"
+)
+
+// tomlEntry is a key of a TOML table with its raw value and the line it starts on.
+type tomlEntry struct {
+	Key   string
+	Value string
+	Line  int
+}
+
+// tomlDocument is a minimal view of a TOML file such as Cargo.toml or
+// rust-toolchain.toml: entries grouped by table name ("package",
+// "lints.clippy"). Values are kept raw; multi-line arrays and strings are
+// joined onto the line they start on.
+type tomlDocument struct {
+	tables map[string][]tomlEntry
+
+	// order lists table names in document order, headers the line of each
+	// table's (first) header
+	order   []string
+	headers map[string]int
+}
+
+// readTOML reads and parses a TOML file.
+func readTOML(path string) (*tomlDocument, error) {
+	data, err := os.ReadFile(path)
+	if err != nil {
+		return nil, err
+	}
+	doc, err := parseTOML(string(data))
+	if err != nil {
+		return nil, fmt.Errorf("%s: %w", path, err)
+	}
+	return doc, nil
+}
+
+// parseTOML parses the table and key/value structure of a TOML document.
+// Syntax it does not understand, such as an unterminated string or a line
+// that is neither a header nor a key/value pair, is an error rather than a
+// silently wrong document.
+func parseTOML(data string) (*tomlDocument, error) {
+	doc := &tomlDocument{tables: make(map[string][]tomlEntry), headers: make(map[string]int)}
+	table := ""
+	doc.tables[table] = nil
+	doc.order = append(doc.order, table)
+
+	p := &tomlParser{data: data, line: 1}
+	for {
+		p.skip(true)
+		if p.pos >= len(p.data) {
+			return doc, nil
+		}
+		line := p.line
+
+		// [table] and [[array.of.tables]] headers
+		if p.data[p.pos] == '[' {
+			name, err := p.header()
+			if err != nil {
+				return nil, err
+			}
+			table = name
+			if _, ok := doc.tables[table]; !ok {
+				doc.tables[table] = nil
+				doc.order = append(doc.order, table)
+				doc.headers[table] = line
+			}
+			continue
+		}
+
+		key, err := p.key()
+		if err != nil {
+			return nil, err
+		}
+		if p.pos >= len(p.data) || p.data[p.pos] != '=' {
+			return nil, p.errorf("expected \"=\" after key %s", key)
+		}
+		p.pos++
+
+		value, err := p.value()
+		if err != nil {
+			return nil, err
+		}
+		if value == "" {
+			return nil, p.errorf("missing value of key %s", key)
+		}
+		doc.tables[table] = append(doc.tables[table], tomlEntry{Key: key, Value: value, Line: line})
+	}
+}
+
+// tomlParser scans a TOML document, keeping track of the current line.
+type tomlParser struct {
+	data string
+	pos  int
+	line int
+}
+
+// errorf returns an error located at the current line.
+func (p *tomlParser) errorf(format string, args ...any) error {
+	return fmt.Errorf("line %d: %s", p.line, fmt.Sprintf(format, args...))
+}
+
+// skip skips blanks and comments, and newlines too when newlines is set.
+func (p *tomlParser) skip(newlines bool) {
+	for p.pos < len(p.data) {
+		switch c := p.data[p.pos]; {
+		case c == ' ' || c == '\t' || c == '\r':
+			p.pos++
+		case c == '\n' && newlines:
+			p.pos++
+			p.line++
+		case c == '#':
+			for p.pos < len(p.data) && p.data[p.pos] != '\n' {
+				p.pos++
+			}
+		default:
+			return
+		}
+	}
+}
+
+// header reads a [table] or [[array.of.tables]] header and returns the
+// table's name.
+func (p *tomlParser) header() (string, error) {
+	closing := "]"
+	p.pos++
+	if p.pos < len(p.data) && p.data[p.pos] == '[' {
+		closing = "]]"
+		p.pos++
+	}
+
+	name, err := p.key()
+	if err != nil {
+		return "", err
+	}
+	if !strings.HasPrefix(p.data[p.pos:], closing) {
+		return "", p.errorf("invalid table header")
+	}
+	p.pos += len(closing)
+
+	// Only a comment may follow
+	p.skip(false)
+	if p.pos < len(p.data) && p.data[p.pos] != '\n' {
+		return "", p.errorf("unexpected %q after table header", p.data[p.pos])
+	}
+	return name, nil
+}
+
+// key reads a possibly dotted and quoted key and normalizes it
+// ("a . \"b\"" -> "a.b"). A quoted part containing a dot stays quoted, so
+// that it cannot be mistaken for a dotted key.
+func (p *tomlParser) key() (string, error) {
+	var parts []string
+	for {
+		p.skip(false)
+		var part string
+		switch {
+		case p.pos < len(p.data) && (p.data[p.pos] == '"' || p.data[p.pos] == '\''):
+			raw, err := p.str()
+			if err != nil {
+				return "", err
+			}
+			if len(raw) >= 6 && strings.HasPrefix(raw, strings.Repeat(raw[:1], 3)) {
+				return "", p.errorf("multi-line string used as a key")
+			}
+			part, _ = tomlString(raw)
+			if strings.Contains(part, ".") {
+				part = strconv.Quote(part)
+			}
+		default:
+			start := p.pos
+			for p.pos < len(p.data) && isBareKeyChar(p.data[p.pos]) {
+				p.pos++
+			}
+			if p.pos == start {
+				return "", p.errorf("invalid key")
+			}
+			part = p.data[start:p.pos]
+		}
+		parts = append(parts, part)
+
+		p.skip(false)
+		if p.pos >= len(p.data) || p.data[p.pos] != '.' {
+			return strings.Join(parts, "."), nil
+		}
+		p.pos++
+	}
+}
+
+// isBareKeyChar reports whether c may appear in an unquoted key.
+func isBareKeyChar(c byte) bool {
+	return c >= 'a' && c <= 'z' || c >= 'A' && c <= 'Z' || c >= '0' && c <= '9' || c == '_' || c == '-'
+}
+
+// str reads the string starting at the current position and returns it raw,
+// quotes included. Basic strings may contain escapes, literal ones may not;
+// only multi-line strings may contain newlines.
+func (p *tomlParser) str() (string, error) {
+	start, line := p.pos, p.line
+	delimiter := p.data[p.pos : p.pos+1]
+	if strings.HasPrefix(p.data[p.pos:], strings.Repeat(delimiter, 3)) {
+		delimiter = strings.Repeat(delimiter, 3)
+	}
+	p.pos += len(delimiter)
+
+	for p.pos < len(p.data) {
+		c := p.data[p.pos]
+		switch {
+		case c == '\\' && delimiter[0] == '"':
+			// The escaped character, or the newline of a line-ending backslash
+			p.pos++
+			if p.pos < len(p.data) && p.data[p.pos] == '\n' {
+				p.line++
+			}
+		case c == '\n':
+			if len(delimiter) == 1 {
+				return "", fmt.Errorf("line %d: unterminated string", line)
+			}
+			p.line++
+		case strings.HasPrefix(p.data[p.pos:], delimiter):
+			p.pos += len(delimiter)
+			// A multi-line string may end with up to two quotes of its own
+			for n := 0; len(delimiter) == 3 && n < 2 && p.pos < len(p.data) && p.data[p.pos] == delimiter[0]; n++ {
+				p.pos++
+			}
+			return p.data[start:p.pos], nil
+		}
+		p.pos++
+	}
+	return "", fmt.Errorf("line %d: unterminated string", line)
+}
+
+// value reads a raw value up to the end of its last line. Values spanning
+// several lines (arrays, inline tables, multi-line strings) keep their
+// newlines, so that an entry's EndLine is right; comments are dropped.
+func (p *tomlParser) value() (string, error) {
+	var value strings.Builder
+	line, depth := p.line, 0
+	for p.pos < len(p.data) {
+		c := p.data[p.pos]
+		switch {
+		case c == '"' || c == '\'':
+			raw, err := p.str()
+			if err != nil {
+				return "", err
+			}
+			value.WriteString(raw)
+			continue
+		case c == '#':
+			p.skip(false)
+			continue
+		case c == '[' || c == '{':
+			depth++
+		case c == ']' || c == '}':
+			if depth--; depth < 0 {
+				return "", p.errorf("unbalanced %q", c)
+			}
+		case c == '\n':
+			if depth == 0 {
+				return strings.TrimSpace(value.String()), nil
+			}
+			p.line++
+			trimmed := strings.TrimRight(value.String(), " \t\r")
+			value.Reset()
+			value.WriteString(trimmed)
+			value.WriteByte('\n')
+			p.pos++
+			p.skip(false)
+			continue
+		}
+		value.WriteByte(c)
+		p.pos++
+	}
+
+	if depth > 0 {
+		return "", fmt.Errorf("line %d: unterminated value", line)
+	}
+	return strings.TrimSpace(value.String()), nil
+}
+
+// HasTable reports whether the document declares the table.
+func (d *tomlDocument) HasTable(name string) bool {
+	_, ok := d.tables[name]
+	return ok
+}
+
+// Table returns the entries of a table in document order.
+func (d *tomlDocument) Table(name string) []tomlEntry {
+	return d.tables[name]
+}
+
+// Tables returns the table names in document order, starting with the
+// root table "".
+func (d *tomlDocument) Tables() []string {
+	return d.order
+}
+
+// TableLine returns the line of a table's header, or 0 for the root table
+// and tables that are only implied by dotted keys.
+func (d *tomlDocument) TableLine(name string) int {
+	return d.headers[name]
+}
+
+// Get returns the entry of key in table.
+func (d *tomlDocument) Get(table, key string) (tomlEntry, bool) {
+	for _, entry := range d.tables[table] {
+		if entry.Key == key {
+			return entry, true
+		}
+	}
+	return tomlEntry{}, false
+}
+
+// String returns the value of key in table if it is a string.
+func (d *tomlDocument) String(table, key string) (string, bool) {
+	entry, ok := d.Get(table, key)
+	if !ok {
+		return "", false
+	}
+	return tomlString(entry.Value)
+}
+
+// EndLine returns the last line of the entry's value.
+func (e tomlEntry) EndLine() int {
+	return e.Line + strings.Count(e.Value, "\n")
+}
+
+// tomlString unquotes a basic ("...") or literal ('...') string value.
+func tomlString(value string) (string, bool) {
+	value = strings.TrimSpace(value)
+	if len(value) < 2 {
+		return "", false
+	}
+	switch {
+	case value[0] == '\'' && value[len(value)-1] == '\'':
+		return value[1 : len(value)-1], true
+	case value[0] == '"' && value[len(value)-1] == '"':
+		if unquoted, err := strconv.Unquote(value); err == nil {
+			return unquoted, true
+		}
+		return value[1 : len(value)-1], true
+	}
+	return "", false
+}
+
+// tomlInlineTable returns the string values of an inline table value such
+// as `{ level = "deny", priority = -1 }`; non-string values are kept raw.
+func tomlInlineTable(value string) (map[string]string, bool) {
+	value = strings.TrimSpace(value)
+	if !strings.HasPrefix(value, "{") || !strings.HasSuffix(value, "}") {
+		return nil, false
+	}
+
+	fields := make(map[string]string)
+	for _, field := range splitTOMLList(value[1 : len(value)-1]) {
+		p := &tomlParser{data: field, line: 1}
+		key, err := p.key()
+		if err != nil || p.pos >= len(field) || field[p.pos] != '=' {
+			return nil, false
+		}
+		raw := strings.TrimSpace(field[p.pos+1:])
+		if unquoted, ok := tomlString(raw); ok {
+			raw = unquoted
+		}
+		fields[key] = raw
+	}
+	return fields, true
+}
+
+// splitTOMLList splits comma-separated items at the top nesting level.
+func splitTOMLList(list string) []string {
+	var items []string
+	depth, start := 0, 0
+	var quote byte
+	for i := 0; i < len(list); i++ {
+		c := list[i]
+		switch {
+		case quote != 0:
+			if c == '\\' && quote == '"' {
+				i++
+			} else if c == quote {
+				quote = 0
+			}
+		case c == '"' || c == '\'':
+			quote = c
+		case c == '[' || c == '{':
+			depth++
+		case c == ']' || c == '}':
+			depth--
+		case c == ',' && depth == 0:
+			items = append(items, strings.TrimSpace(list[start:i]))
+			start = i + 1
+		}
+	}
+	if last := strings.TrimSpace(list[start:]); last != "" {
+		items = append(items, last)
+	}
+	return items
+}
@@ -21,7 +21,14 @@ func NewClangFormatTool() *ClangFormatTool {
 		BaseTool: NewBaseTool("clang-format", "C/C++", "clang-format", FORMAT),
 	}
 
-	tool.SetInstallCommand([]string{"pacman", "-S", "--noconfirm", "clang"})
+	tool.Bind(tool)
+	// clang-format --dry-run -Werror exits 1 when files need formatting
+	tool.SetFindingExitCodes(1)
+	tool.AddPackage(PackageApt, "clang-format")
+	tool.AddPackage(PackageDnf, "clang-tools-extra")
+	tool.AddPackage(PackagePacman, "clang")
+	tool.AddPackage(PackageBrew, "clang-format")
+	tool.AddPackage(PackagePip, "clang-format")
 	tool.SetConfigPatterns([]string{".clang-format", "_clang-format"})
 
 	return tool
@@ -65,7 +72,14 @@ func NewClangTidyTool() *ClangTidyTool {
 		BaseTool: NewBaseTool("clang-tidy", "C/C++", "clang-tidy", LINT),
 	}
 
-	tool.SetInstallCommand([]string{"pacman", "-S", "--noconfirm", "clang"})
+	tool.Bind(tool)
+	// clang-tidy exits 1 when diagnostics are promoted to errors
+	tool.SetFindingExitCodes(1)
+	tool.AddPackage(PackageApt, "clang-tidy")
+	tool.AddPackage(PackageDnf, "clang-tools-extra")
+	tool.AddPackage(PackagePacman, "clang")
+	tool.AddPackage(PackageBrew, "llvm")
+	tool.AddPackage(PackagePip, "clang-tidy")
 	tool.SetConfigPatterns([]string{".clang-tidy"})
 
 	return tool
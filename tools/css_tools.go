@@ -20,7 +20,10 @@ func NewStylelintTool() *StylelintTool {
 		BaseTool: NewBaseTool("stylelint", "CSS", "stylelint", LINT),
 	}
 
-	tool.SetInstallCommand([]string{"npm", "install", "-g", "stylelint", "stylelint-config-standard"})
+	tool.Bind(tool)
+	// stylelint exits 2 on lint problems (78 is a config error)
+	tool.SetFindingExitCodes(2)
+	tool.AddPackage(PackageNpm, "stylelint")
 	tool.SetConfigPatterns([]string{".stylelintrc", ".stylelintrc.json", ".stylelintrc.yml", "stylelint.config.js"})
 
 	return tool
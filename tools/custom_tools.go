@@ -0,0 +1,325 @@
+// Copyright (c) 2025 Archmagece
+// SPDX-License-Identifier: MIT
+
+package tools
+
+import (
+	"encoding/json"
+	"fmt"
+	"os/exec"
+	"regexp"
+	"strconv"
+	"strings"
+)
+
+// Placeholders recognised in CustomToolSpec.Args.
+const (
+	// argFiles expands to the files to process (dropped when there are none)
+	argFiles = "{files}"
+
+	// argFix expands to FixArgs when fixing
+	argFix = "{fix}"
+
+	// argConfig expands to ConfigArgs when a config file was found
+	argConfig = "{config}"
+
+	// argConfigFile and argProjectRoot are replaced inside any argument
+	argConfigFile  = "{config_file}"
+	argProjectRoot = "{project_root}"
+)
+
+// Output formats a custom tool can declare.
+const (
+	OutputFormatRegex = "regex"
+	OutputFormatJSON  = "json"
+	OutputFormatSARIF = "sarif"
+)
+
+// CustomToolSpec declares a tool without Go code, e.g. from the
+// custom_tools section of .gzquality.yml.
+type CustomToolSpec struct {
+	Name       string
+	Language   string
+	Type       ToolType
+	Executable string
+
+	// Args is the argument template (see the arg* placeholders)
+	Args []string
+
+	// FixArgs and ConfigArgs are what {fix} and {config} expand to
+	FixArgs    []string
+	ConfigArgs []string
+
+	// Extensions limits {files} to these extensions (empty means all files)
+	Extensions []string
+
+	ConfigPatterns   []string
+	FindingExitCodes []int
+	InstallCommand   []string
+
+	Output OutputSpec
+}
+
+// OutputSpec describes how to turn a custom tool's output into issues.
+type OutputSpec struct {
+	// Format is regex, json or sarif
+	Format string
+
+	// Pattern is the regex for the regex format. Named groups file, line,
+	// column, severity, rule and message fill the matching Issue fields.
+	Pattern string
+
+	// DefaultSeverity is used when no severity is captured
+	DefaultSeverity string
+
+	// Root is the dot path to the array of issues in JSON output
+	// (empty means the document itself, or one object per line)
+	Root string
+
+	// Fields maps Issue fields (file, line, column, severity, rule, message)
+	// to dot paths inside each JSON issue, e.g. "location.path"
+	Fields map[string]string
+}
+
+// CustomTool runs a tool declared by a CustomToolSpec.
+type CustomTool struct {
+	*BaseTool
+
+	spec        CustomToolSpec
+	parseConfig TextParseConfig
+}
+
+// NewCustomTool creates a tool from spec, validating its output parser.
+func NewCustomTool(spec CustomToolSpec) (*CustomTool, error) {
+	if spec.Name == "" || spec.Executable == "" {
+		return nil, fmt.Errorf("custom tool needs a name and an executable")
+	}
+
+	tool := &CustomTool{
+		BaseTool: NewBaseTool(spec.Name, spec.Language, spec.Executable, spec.Type),
+		spec:     spec,
+	}
+
+	switch spec.Output.Format {
+	case OutputFormatRegex:
+		config, err := regexParseConfig(spec.Output)
+		if err != nil {
+			return nil, fmt.Errorf("custom tool %s: %w", spec.Name, err)
+		}
+		tool.parseConfig = config
+	case OutputFormatJSON:
+		if spec.Output.Fields["file"] == "" && spec.Output.Fields["message"] == "" {
+			return nil, fmt.Errorf("custom tool %s: json output needs fields for at least file or message", spec.Name)
+		}
+	case OutputFormatSARIF, "":
+	default:
+		return nil, fmt.Errorf("custom tool %s: unknown output format %q (supported: regex, json, sarif)", spec.Name, spec.Output.Format)
+	}
+
+	tool.Bind(tool)
+	tool.SetFindingExitCodes(spec.FindingExitCodes...)
+	tool.SetConfigPatterns(spec.ConfigPatterns)
+	if len(spec.InstallCommand) > 0 {
+		tool.SetInstallCommand(spec.InstallCommand)
+	}
+
+	return tool, nil
+}
+
+// regexParseConfig compiles the pattern and maps its named groups.
+func regexParseConfig(spec OutputSpec) (TextParseConfig, error) {
+	if spec.Pattern == "" {
+		return TextParseConfig{}, fmt.Errorf("regex output needs a pattern")
+	}
+	pattern, err := regexp.Compile(spec.Pattern)
+	if err != nil {
+		return TextParseConfig{}, fmt.Errorf("invalid output pattern: %w", err)
+	}
+
+	group := func(name string) int {
+		return max(pattern.SubexpIndex(name), 0)
+	}
+
+	config := TextParseConfig{
+		Pattern:         pattern,
+		FileIndex:       group("file"),
+		LineIndex:       group("line"),
+		ColumnIndex:     group("column"),
+		SeverityIndex:   group("severity"),
+		RuleIndex:       group("rule"),
+		MessageIndex:    group("message"),
+		DefaultSeverity: spec.DefaultSeverity,
+	}
+	if config.FileIndex == 0 && config.MessageIndex == 0 {
+		return TextParseConfig{}, fmt.Errorf("output pattern needs a (?P<file>...) or (?P<message>...) group")
+	}
+	if config.DefaultSeverity == "" {
+		config.DefaultSeverity = severityWarning
+	}
+	return config, nil
+}
+
+// BuildCommand expands the argument template.
+func (t *CustomTool) BuildCommand(files []string, options ExecuteOptions) *exec.Cmd {
+	if len(t.spec.Extensions) > 0 {
+		files = FilterFilesByExtensions(files, t.spec.Extensions)
+	}
+
+	var args []string
+	for _, arg := range t.spec.Args {
+		switch arg {
+		case argFiles:
+			args = append(args, files...)
+		case argFix:
+			if options.Fix {
+				args = append(args, t.expand(t.spec.FixArgs, options)...)
+			}
+		case argConfig:
+			if options.ConfigFile != "" {
+				args = append(args, t.expand(t.spec.ConfigArgs, options)...)
+			}
+		default:
+			args = append(args, t.expand([]string{arg}, options)...)
+		}
+	}
+
+	// Add extra flags if provided
+	args = append(args, options.ExtraArgs...)
+
+	cmd := exec.Command(t.executable, args...)
+
+	if options.ProjectRoot != "" {
+		cmd.Dir = options.ProjectRoot
+	}
+
+	return cmd
+}
+
+// expand replaces {config_file} and {project_root} inside args.
+func (t *CustomTool) expand(args []string, options ExecuteOptions) []string {
+	replacer := strings.NewReplacer(argConfigFile, options.ConfigFile, argProjectRoot, options.ProjectRoot)
+	expanded := make([]string, len(args))
+	for i, arg := range args {
+		expanded[i] = replacer.Replace(arg)
+	}
+	return expanded
+}
+
+// ParseOutput parses output according to the spec's output format.
+func (t *CustomTool) ParseOutput(output string) []Issue {
+	if strings.TrimSpace(output) == "" {
+		return []Issue{}
+	}
+
+	switch t.spec.Output.Format {
+	case OutputFormatRegex:
+		return ParseTextLines(output, t.parseConfig)
+	case OutputFormatJSON:
+		return parseMappedJSON(output, t.spec.Output)
+	case OutputFormatSARIF:
+		return ParseSARIF(output)
+	default:
+		return nil
+	}
+}
+
+// parseMappedJSON extracts issues from JSON output using the spec's field paths.
+func parseMappedJSON(output string, spec OutputSpec) []Issue {
+	var items []interface{}
+
+	var document interface{}
+	if err := json.Unmarshal([]byte(output), &document); err == nil {
+		items = jsonItems(lookupJSONPath(document, spec.Root))
+	} else {
+		// One JSON object per line
+		for _, line := range strings.Split(output, "\n") {
+			var item interface{}
+			if json.Unmarshal([]byte(strings.TrimSpace(line)), &item) == nil {
+				items = append(items, jsonItems(lookupJSONPath(item, spec.Root))...)
+			}
+		}
+	}
+
+	issues := make([]Issue, 0, len(items))
+	for _, item := range items {
+		field := func(name string) string {
+			path, ok := spec.Fields[name]
+			if !ok {
+				return ""
+			}
+			return jsonString(lookupJSONPath(item, path))
+		}
+
+		issue := Issue{
+			File:     field("file"),
+			Severity: field("severity"),
+			Rule:     field("rule"),
+			Message:  field("message"),
+		}
+		issue.Line, _ = strconv.Atoi(field("line"))
+		issue.Column, _ = strconv.Atoi(field("column"))
+		if issue.Severity == "" {
+			issue.Severity = spec.DefaultSeverity
+		}
+		if issue.Severity == "" {
+			issue.Severity = severityWarning
+		}
+
+		issues = append(issues, issue)
+	}
+
+	return issues
+}
+
+// lookupJSONPath follows a dot path ("results.0.path") through decoded JSON.
+func lookupJSONPath(value interface{}, path string) interface{} {
+	if path == "" {
+		return value
+	}
+	for _, key := range strings.Split(path, ".") {
+		switch v := value.(type) {
+		case map[string]interface{}:
+			value = v[key]
+		case []interface{}:
+			index, err := strconv.Atoi(key)
+			if err != nil || index < 0 || index >= len(v) {
+				return nil
+			}
+			value = v[index]
+		default:
+			return nil
+		}
+	}
+	return value
+}
+
+// jsonItems returns value as a list of issue objects.
+func jsonItems(value interface{}) []interface{} {
+	switch v := value.(type) {
+	case []interface{}:
+		return v
+	case nil:
+		return nil
+	default:
+		return []interface{}{v}
+	}
+}
+
+// jsonString formats a decoded JSON scalar as a string.
+func jsonString(value interface{}) string {
+	switch v := value.(type) {
+	case string:
+		return v
+	case float64:
+		return strconv.FormatFloat(v, 'f', -1, 64)
+	case bool:
+		return strconv.FormatBool(v)
+	default:
+		return ""
+	}
+}
+
+// Ensure CustomTool implements QualityTool interface.
+var (
+	_ QualityTool = (*CustomTool)(nil)
+)
@@ -0,0 +1,191 @@
+// Copyright (c) 2025 Archmagece
+// SPDX-License-Identifier: MIT
+
+package tools
+
+import (
+	"context"
+	"os"
+	"testing"
+
+	"github.com/Gizzahub/gzh-cli-quality/internal/testutil"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestNewCustomTool_Validation(t *testing.T) {
+	tests := []struct {
+		name     string
+		spec     CustomToolSpec
+		expected string
+	}{
+		{name: "missing executable", spec: CustomToolSpec{Name: "x"}, expected: "executable"},
+		{name: "missing pattern", spec: CustomToolSpec{Name: "x", Executable: "x", Output: OutputSpec{Format: OutputFormatRegex}}, expected: "pattern"},
+		{name: "invalid pattern", spec: CustomToolSpec{Name: "x", Executable: "x", Output: OutputSpec{Format: OutputFormatRegex, Pattern: "("}}, expected: "invalid output pattern"},
+		{name: "unnamed groups", spec: CustomToolSpec{Name: "x", Executable: "x", Output: OutputSpec{Format: OutputFormatRegex, Pattern: `(\S+):(\d+)`}}, expected: "(?P<file>...)"},
+		{name: "json without fields", spec: CustomToolSpec{Name: "x", Executable: "x", Output: OutputSpec{Format: OutputFormatJSON}}, expected: "fields"},
+		{name: "unknown format", spec: CustomToolSpec{Name: "x", Executable: "x", Output: OutputSpec{Format: "xml"}}, expected: "unknown output format"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			_, err := NewCustomTool(tt.spec)
+			require.Error(t, err)
+			assert.Contains(t, err.Error(), tt.expected)
+		})
+	}
+}
+
+func TestCustomTool_BuildCommand(t *testing.T) {
+	tool, err := NewCustomTool(CustomToolSpec{
+		Name:       "sqlfluff",
+		Language:   "SQL",
+		Type:       BOTH,
+		Executable: "sqlfluff",
+		Args:       []string{"lint", "{fix}", "{config}", "--format=json", "--root={project_root}", "{files}"},
+		FixArgs:    []string{"--fix", "--force"},
+		ConfigArgs: []string{"--config", "{config_file}"},
+		Extensions: []string{".sql"},
+		Output:     OutputSpec{Format: OutputFormatSARIF},
+	})
+	require.NoError(t, err)
+
+	assert.Equal(t, "sqlfluff", tool.Name())
+	assert.Equal(t, "SQL", tool.Language())
+	assert.Equal(t, BOTH, tool.Type())
+
+	files := []string{"a.sql", "main.go", "b.sql"}
+
+	cmd := tool.BuildCommand(files, ExecuteOptions{ProjectRoot: "/repo"})
+	assert.Equal(t, []string{"sqlfluff", "lint", "--format=json", "--root=/repo", "a.sql", "b.sql"}, cmd.Args)
+	assert.Equal(t, "/repo", cmd.Dir)
+
+	cmd = tool.BuildCommand(files, ExecuteOptions{Fix: true, ConfigFile: ".sqlfluff", ExtraArgs: []string{"-v"}})
+	assert.Equal(t, []string{"sqlfluff", "lint", "--fix", "--force", "--config", ".sqlfluff", "--format=json", "--root=", "a.sql", "b.sql", "-v"}, cmd.Args)
+}
+
+func TestCustomTool_ParseOutput_Regex(t *testing.T) {
+	tool, err := NewCustomTool(CustomToolSpec{
+		Name:       "hadolint",
+		Executable: "hadolint",
+		Output: OutputSpec{
+			Format:  OutputFormatRegex,
+			Pattern: `^(?P<file>[^:]+):(?P<line>\d+) (?P<rule>DL\d+) (?P<severity>\w+): (?P<message>.+)$`,
+		},
+	})
+	require.NoError(t, err)
+
+	issues := tool.ParseOutput("Dockerfile:3 DL3008 warning: Pin versions in apt get install\nsome noise\n")
+	require.Len(t, issues, 1)
+	assert.Equal(t, Issue{
+		File:     "Dockerfile",
+		Line:     3,
+		Severity: "warning",
+		Rule:     "DL3008",
+		Message:  "Pin versions in apt get install",
+	}, issues[0])
+
+	assert.Empty(t, tool.ParseOutput(""))
+}
+
+func TestCustomTool_ParseOutput_JSON(t *testing.T) {
+	spec := CustomToolSpec{
+		Name:       "custom",
+		Executable: "custom",
+		Output: OutputSpec{
+			Format:          OutputFormatJSON,
+			Root:            "report.findings",
+			DefaultSeverity: "error",
+			Fields: map[string]string{
+				"file":    "location.path",
+				"line":    "location.lines.0",
+				"rule":    "check",
+				"message": "description",
+			},
+		},
+	}
+	tool, err := NewCustomTool(spec)
+	require.NoError(t, err)
+
+	output := `{"report":{"findings":[
+  {"check":"C1","description":"first","location":{"path":"a.txt","lines":[4,5]}},
+  {"check":"C2","description":"second","location":{"path":"b.txt"}}
+]}}`
+
+	issues := tool.ParseOutput(output)
+	require.Len(t, issues, 2)
+	assert.Equal(t, Issue{File: "a.txt", Line: 4, Severity: "error", Rule: "C1", Message: "first"}, issues[0])
+	assert.Equal(t, "b.txt", issues[1].File)
+	assert.Zero(t, issues[1].Line)
+
+	// One object per line
+	spec.Output.Root = ""
+	spec.Output.Fields = map[string]string{"file": "file", "line": "line", "severity": "level", "message": "msg"}
+	tool, err = NewCustomTool(spec)
+	require.NoError(t, err)
+
+	issues = tool.ParseOutput("{\"file\":\"x.txt\",\"line\":2,\"level\":\"warning\",\"msg\":\"m\"}\n{\"file\":\"y.txt\",\"msg\":\"n\"}\n")
+	require.Len(t, issues, 2)
+	assert.Equal(t, Issue{File: "x.txt", Line: 2, Severity: "warning", Message: "m"}, issues[0])
+	assert.Equal(t, "error", issues[1].Severity)
+}
+
+func TestParseSARIF(t *testing.T) {
+	output := `{"version":"2.1.0","runs":[{"results":[
+  {"ruleId":"R1","level":"error","message":{"text":"bad"},
+   "locations":[{"physicalLocation":{"artifactLocation":{"uri":"file://src/a.c"},"region":{"startLine":7,"startColumn":3}}}]},
+  {"ruleId":"R2","level":"note","message":{"text":"fyi"},
+   "locations":[{"physicalLocation":{"artifactLocation":{"uri":"src/b.c"},"region":{"startLine":1}}}]},
+  {"ruleId":"R3","message":{"text":"no location"}}
+]}]}`
+
+	issues := ParseSARIF(output)
+	require.Len(t, issues, 3)
+	assert.Equal(t, Issue{File: "src/a.c", Line: 7, Column: 3, Severity: "error", Rule: "R1", Message: "bad"}, issues[0])
+	assert.Equal(t, "info", issues[1].Severity)
+	assert.Equal(t, "src/b.c", issues[1].File)
+	assert.Equal(t, "warning", issues[2].Severity)
+	assert.Empty(t, issues[2].File)
+
+	assert.Nil(t, ParseSARIF("not json"))
+}
+
+func TestCustomTool_Execute(t *testing.T) {
+	binDir := t.TempDir()
+	testutil.WriteExecutable(t, binDir, "mylint", `for f in "$@"; do echo "$f:1: warning: trailing whitespace"; done
+exit 1
+`)
+	t.Setenv("PATH", binDir+string(os.PathListSeparator)+os.Getenv("PATH"))
+
+	tool, err := NewCustomTool(CustomToolSpec{
+		Name:             "mylint",
+		Executable:       "mylint",
+		Args:             []string{"{files}"},
+		FindingExitCodes: []int{1},
+		Output: OutputSpec{
+			Format:  OutputFormatRegex,
+			Pattern: `^(?P<file>[^:]+):(?P<line>\d+): (?P<severity>\w+): (?P<message>.+)$`,
+		},
+	})
+	require.NoError(t, err)
+
+	result, err := tool.Execute(context.Background(), []string{"a.txt", "b.txt"}, ExecuteOptions{})
+	require.NoError(t, err)
+
+	assert.Equal(t, StatusIssues, result.Status)
+	assert.Equal(t, 1, result.ExitCode)
+	require.Len(t, result.Issues, 2)
+	assert.Equal(t, "b.txt", result.Issues[1].File)
+	assert.Equal(t, "trailing whitespace", result.Issues[1].Message)
+}
+
+func TestParseToolType(t *testing.T) {
+	for input, expected := range map[string]ToolType{"format": FORMAT, "linter": LINT, "both": BOTH} {
+		toolType, err := ParseToolType(input)
+		require.NoError(t, err)
+		assert.Equal(t, expected, toolType)
+	}
+
+	_, err := ParseToolType("checker")
+	assert.Error(t, err)
+}
@@ -0,0 +1,366 @@
+// Copyright (c) 2025 Archmagece
+// SPDX-License-Identifier: MIT
+
+package tools
+
+import (
+	"fmt"
+	"strconv"
+	"strings"
+)
+
+const (
+	// formatRule is the rule reported for files a formatter would change.
+	formatRule = "format"
+
+	// diffContext is the number of unchanged lines shown around each hunk.
+	diffContext = 3
+
+	// maxDiffCells bounds the LCS table; larger inputs get a single hunk.
+	maxDiffCells = 4_000_000
+)
+
+// diffOp is one line of an edit script: ' ' keep, '-' delete, '+' insert.
+type diffOp struct {
+	kind byte
+	line string
+}
+
+// UnifiedDiff returns a unified diff that turns original into formatted,
+// or "" if they are equal.
+func UnifiedDiff(path, original, formatted string) string {
+	if original == formatted {
+		return ""
+	}
+
+	ops := diffLines(splitLines(original), splitLines(formatted))
+
+	var sb strings.Builder
+	fmt.Fprintf(&sb, "--- %s\n+++ %s\n", path, path)
+	writeHunks(&sb, ops)
+	return sb.String()
+}
+
+// splitLines splits s into lines, keeping their line terminators.
+func splitLines(s string) []string {
+	if s == "" {
+		return nil
+	}
+	lines := strings.SplitAfter(s, "\n")
+	if lines[len(lines)-1] == "" {
+		lines = lines[:len(lines)-1]
+	}
+	return lines
+}
+
+// diffLines computes a line edit script from a to b.
+func diffLines(a, b []string) []diffOp {
+	prefix := 0
+	for prefix < len(a) && prefix < len(b) && a[prefix] == b[prefix] {
+		prefix++
+	}
+	suffix := 0
+	for suffix < len(a)-prefix && suffix < len(b)-prefix && a[len(a)-1-suffix] == b[len(b)-1-suffix] {
+		suffix++
+	}
+
+	ops := make([]diffOp, 0, len(a)+len(b))
+	for _, line := range a[:prefix] {
+		ops = append(ops, diffOp{' ', line})
+	}
+	ops = append(ops, diffMiddle(a[prefix:len(a)-suffix], b[prefix:len(b)-suffix])...)
+	for _, line := range a[len(a)-suffix:] {
+		ops = append(ops, diffOp{' ', line})
+	}
+	return ops
+}
+
+// diffMiddle diffs the differing middle of two inputs using an LCS table.
+func diffMiddle(a, b []string) []diffOp {
+	var ops []diffOp
+
+	if len(a)*len(b) > maxDiffCells {
+		for _, line := range a {
+			ops = append(ops, diffOp{'-', line})
+		}
+		for _, line := range b {
+			ops = append(ops, diffOp{'+', line})
+		}
+		return ops
+	}
+
+	// lcs[i][j] is the length of the longest common subsequence of a[i:] and b[j:]
+	lcs := make([][]int, len(a)+1)
+	for i := range lcs {
+		lcs[i] = make([]int, len(b)+1)
+	}
+	for i := len(a) - 1; i >= 0; i-- {
+		for j := len(b) - 1; j >= 0; j-- {
+			if a[i] == b[j] {
+				lcs[i][j] = lcs[i+1][j+1] + 1
+			} else {
+				lcs[i][j] = max(lcs[i+1][j], lcs[i][j+1])
+			}
+		}
+	}
+
+	i, j := 0, 0
+	for i < len(a) && j < len(b) {
+		switch {
+		case a[i] == b[j]:
+			ops = append(ops, diffOp{' ', a[i]})
+			i++
+			j++
+		case lcs[i+1][j] >= lcs[i][j+1]:
+			ops = append(ops, diffOp{'-', a[i]})
+			i++
+		default:
+			ops = append(ops, diffOp{'+', b[j]})
+			j++
+		}
+	}
+	for ; i < len(a); i++ {
+		ops = append(ops, diffOp{'-', a[i]})
+	}
+	for ; j < len(b); j++ {
+		ops = append(ops, diffOp{'+', b[j]})
+	}
+	return ops
+}
+
+// writeHunks writes the changes in ops as unified diff hunks.
+func writeHunks(sb *strings.Builder, ops []diffOp) {
+	// oldAt/newAt hold the 1-based line numbers at each op index
+	oldAt := make([]int, len(ops)+1)
+	newAt := make([]int, len(ops)+1)
+	oldLine, newLine := 1, 1
+	for i, op := range ops {
+		oldAt[i], newAt[i] = oldLine, newLine
+		if op.kind != '+' {
+			oldLine++
+		}
+		if op.kind != '-' {
+			newLine++
+		}
+	}
+	oldAt[len(ops)], newAt[len(ops)] = oldLine, newLine
+
+	for i := 0; i < len(ops); {
+		if ops[i].kind == ' ' {
+			i++
+			continue
+		}
+
+		// Extend the hunk through changes separated by at most two contexts
+		end := i
+		for j := i; j < len(ops); j++ {
+			if ops[j].kind != ' ' {
+				end = j + 1
+			} else if j-end >= 2*diffContext {
+				break
+			}
+		}
+		start := max(0, i-diffContext)
+		end = min(len(ops), end+diffContext)
+
+		oldCount, newCount := 0, 0
+		for _, op := range ops[start:end] {
+			if op.kind != '+' {
+				oldCount++
+			}
+			if op.kind != '-' {
+				newCount++
+			}
+		}
+
+		fmt.Fprintf(sb, "@@ -%s +%s @@\n", hunkRange(oldAt[start], oldCount), hunkRange(newAt[start], newCount))
+		for _, op := range ops[start:end] {
+			writeDiffLine(sb, op.kind, op.line)
+		}
+		i = end
+	}
+}
+
+// hunkRange formats a unified diff range ("start,count").
+func hunkRange(start, count int) string {
+	switch count {
+	case 0:
+		return fmt.Sprintf("%d,0", start-1)
+	case 1:
+		return strconv.Itoa(start)
+	default:
+		return fmt.Sprintf("%d,%d", start, count)
+	}
+}
+
+// writeDiffLine writes one diff line, marking a missing final newline.
+func writeDiffLine(sb *strings.Builder, kind byte, line string) {
+	sb.WriteByte(kind)
+	sb.WriteString(line)
+	if !strings.HasSuffix(line, "\n") {
+		sb.WriteString("\n\\ No newline at end of file\n")
+	}
+}
+
+// ParseUnifiedDiff turns formatter diff output into one Issue per file with
+// the file's diff in Suggestion. Anything outside the diffs (file lists from
+// -l, "would reformat" notes, summaries) is ignored.
+func ParseUnifiedDiff(output, message string) []Issue {
+	var issues []Issue
+	index := make(map[string]int)
+
+	lines := strings.Split(output, "\n")
+	for i := 0; i+1 < len(lines); i++ {
+		if !strings.HasPrefix(lines[i], "--- ") || !strings.HasPrefix(lines[i+1], "+++ ") {
+			continue
+		}
+
+		file := diffHeaderPath(lines[i], lines[i+1])
+		block := []string{lines[i], lines[i+1]}
+		firstLine := 0
+		i += 2
+
+		// Consume exactly the lines each hunk header announces
+		for i < len(lines) {
+			oldStart, oldCount, newCount, ok := parseHunkHeader(lines[i])
+			if !ok {
+				break
+			}
+			if firstLine == 0 {
+				firstLine = max(oldStart, 1)
+			}
+			block = append(block, lines[i])
+			i++
+			for i < len(lines) && (oldCount > 0 || newCount > 0 || strings.HasPrefix(lines[i], "\\")) {
+				line := lines[i]
+				switch {
+				case strings.HasPrefix(line, "+"):
+					newCount--
+				case strings.HasPrefix(line, "-"):
+					oldCount--
+				case strings.HasPrefix(line, "\\"):
+				default:
+					oldCount--
+					newCount--
+				}
+				block = append(block, line)
+				i++
+			}
+		}
+		i-- // the loop increment revisits the line that ended the block
+
+		diff := strings.Join(block, "\n") + "\n"
+		if existing, ok := index[file]; ok {
+			issues[existing].Suggestion += diff
+			continue
+		}
+		index[file] = len(issues)
+		issues = append(issues, formatIssue(file, firstLine, message, diff))
+	}
+
+	return issues
+}
+
+// diffHeaderPath extracts the file path from "---"/"+++" headers, dropping
+// timestamps and git-style a/ b/ prefixes.
+func diffHeaderPath(oldHeader, newHeader string) string {
+	oldPath, _, _ := strings.Cut(strings.TrimPrefix(oldHeader, "--- "), "\t")
+	newPath, _, _ := strings.Cut(strings.TrimPrefix(newHeader, "+++ "), "\t")
+	if strings.HasPrefix(oldPath, "a/") && strings.HasPrefix(newPath, "b/") {
+		return strings.TrimPrefix(newPath, "b/")
+	}
+	return strings.TrimSpace(newPath)
+}
+
+// parseHunkHeader parses "@@ -l,s +l,s @@" into the old start and line counts.
+func parseHunkHeader(line string) (oldStart, oldCount, newCount int, ok bool) {
+	matches := hunkHeaderPattern.FindStringSubmatch(line)
+	if matches == nil {
+		return 0, 0, 0, false
+	}
+	oldStart, _ = strconv.Atoi(matches[1])
+	oldCount, newCount = 1, 1
+	if matches[2] != "" {
+		oldCount, _ = strconv.Atoi(matches[2])
+	}
+	if matches[4] != "" {
+		newCount, _ = strconv.Atoi(matches[4])
+	}
+	return oldStart, oldCount, newCount, true
+}
+
+// parseRustfmtCheck converts `rustfmt --check` output ("Diff in FILE at line N:"
+// or "Diff in FILE:N:" followed by ' '/'-'/'+' lines) into one Issue per file
+// carrying a unified diff.
+func parseRustfmtCheck(output, message string) []Issue {
+	type hunk struct {
+		start int
+		lines []string
+	}
+
+	var files []string
+	hunks := make(map[string][]hunk)
+
+	lines := strings.Split(output, "\n")
+	for i := 0; i < len(lines); i++ {
+		matches := rustfmtDiffPattern.FindStringSubmatch(strings.TrimSpace(lines[i]))
+		if matches == nil {
+			continue
+		}
+
+		file := matches[1]
+		start, _ := strconv.Atoi(matches[2] + matches[3])
+		h := hunk{start: max(start, 1)}
+		for i+1 < len(lines) && lines[i+1] != "" && strings.ContainsRune(" +-", rune(lines[i+1][0])) {
+			i++
+			h.lines = append(h.lines, lines[i])
+		}
+
+		if _, seen := hunks[file]; !seen {
+			files = append(files, file)
+		}
+		hunks[file] = append(hunks[file], h)
+	}
+
+	issues := make([]Issue, 0, len(files))
+	for _, file := range files {
+		var sb strings.Builder
+		fmt.Fprintf(&sb, "--- %s\n+++ %s\n", file, file)
+
+		// New-file line numbers shift by the size change of earlier hunks
+		delta := 0
+		for _, h := range hunks[file] {
+			oldCount, newCount := 0, 0
+			for _, line := range h.lines {
+				if line[0] != '+' {
+					oldCount++
+				}
+				if line[0] != '-' {
+					newCount++
+				}
+			}
+			fmt.Fprintf(&sb, "@@ -%s +%s @@\n", hunkRange(h.start, oldCount), hunkRange(h.start+delta, newCount))
+			for _, line := range h.lines {
+				sb.WriteString(line)
+				sb.WriteByte('\n')
+			}
+			delta += newCount - oldCount
+		}
+
+		issues = append(issues, formatIssue(file, hunks[file][0].start, message, sb.String()))
+	}
+
+	return issues
+}
+
+// formatIssue creates the Issue reported for an unformatted file.
+func formatIssue(file string, line int, message, diff string) Issue {
+	return Issue{
+		File:       file,
+		Line:       line,
+		Severity:   "warning",
+		Rule:       formatRule,
+		Message:    message,
+		Suggestion: diff,
+	}
+}
@@ -0,0 +1,185 @@
+// Copyright (c) 2025 Archmagece
+// SPDX-License-Identifier: MIT
+
+package tools
+
+import (
+	"context"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/Gizzahub/gzh-cli-quality/internal/testutil"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestUnifiedDiff(t *testing.T) {
+	tests := []struct {
+		name      string
+		original  string
+		formatted string
+		expected  string
+	}{
+		{
+			name:      "identical",
+			original:  "a\nb\n",
+			formatted: "a\nb\n",
+			expected:  "",
+		},
+		{
+			name:      "single change with context",
+			original:  "1\n2\n3\n4\n5\n6\n7\n8\n",
+			formatted: "1\n2\n3\n4\nfive\n6\n7\n8\n",
+			expected: "--- x.go\n+++ x.go\n" +
+				"@@ -2,7 +2,7 @@\n 2\n 3\n 4\n-5\n+five\n 6\n 7\n 8\n",
+		},
+		{
+			name:      "distant changes get separate hunks",
+			original:  "a\n1\n2\n3\n4\n5\n6\n7\n8\nb\n",
+			formatted: "A\n1\n2\n3\n4\n5\n6\n7\n8\nB\n",
+			expected: "--- x.go\n+++ x.go\n" +
+				"@@ -1,4 +1,4 @@\n-a\n+A\n 1\n 2\n 3\n" +
+				"@@ -7,4 +7,4 @@\n 6\n 7\n 8\n-b\n+B\n",
+		},
+		{
+			name:      "insertion into empty file",
+			original:  "",
+			formatted: "package x\n",
+			expected:  "--- x.go\n+++ x.go\n@@ -0,0 +1 @@\n+package x\n",
+		},
+		{
+			name:      "missing final newline",
+			original:  "a\nb",
+			formatted: "a\nb\n",
+			expected: "--- x.go\n+++ x.go\n" +
+				"@@ -1,2 +1,2 @@\n a\n-b\n\\ No newline at end of file\n+b\n",
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			assert.Equal(t, tt.expected, UnifiedDiff("x.go", tt.original, tt.formatted))
+		})
+	}
+}
+
+func TestParseUnifiedDiff(t *testing.T) {
+	// gofumpt -l -d: file list, then a diff per file with .orig headers
+	output := "main.go\nutil.go\n" +
+		"diff main.go.orig main.go\n" +
+		"--- main.go.orig\n+++ main.go\n" +
+		"@@ -3,3 +3,3 @@\n import \"fmt\"\n-func main(){\n+func main() {\n }\n" +
+		"@@ -10 +10 @@\n-var x=1\n+var x = 1\n" +
+		"diff util.go.orig util.go\n" +
+		"--- util.go.orig\n+++ util.go\n" +
+		"@@ -1,2 +1,3 @@\n package main\n+\n // util\n"
+
+	issues := ParseUnifiedDiff(output, "file is not gofumpt-formatted")
+
+	require.Len(t, issues, 2)
+	assert.Equal(t, "main.go", issues[0].File)
+	assert.Equal(t, 3, issues[0].Line)
+	assert.Equal(t, "format", issues[0].Rule)
+	assert.Equal(t, "warning", issues[0].Severity)
+	assert.Equal(t, "file is not gofumpt-formatted", issues[0].Message)
+	assert.Equal(t, "--- main.go.orig\n+++ main.go\n"+
+		"@@ -3,3 +3,3 @@\n import \"fmt\"\n-func main(){\n+func main() {\n }\n"+
+		"@@ -10 +10 @@\n-var x=1\n+var x = 1\n", issues[0].Suggestion)
+	assert.Equal(t, "util.go", issues[1].File)
+	assert.Equal(t, 1, issues[1].Line)
+}
+
+func TestParseUnifiedDiff_HeadersWithTimestamps(t *testing.T) {
+	// black --check --diff
+	output := "--- app.py\t2025-01-01 00:00:00.000000+00:00\n" +
+		"+++ app.py\t2025-01-01 00:00:01.000000+00:00\n" +
+		"@@ -1 +1 @@\n-x=1\n+x = 1\n" +
+		"would reformat app.py\n\nOh no! 1 file would be reformatted.\n"
+
+	issues := ParseUnifiedDiff(output, "file would be reformatted by black")
+
+	require.Len(t, issues, 1)
+	assert.Equal(t, "app.py", issues[0].File)
+	assert.NotContains(t, issues[0].Suggestion, "would reformat")
+}
+
+func TestParseUnifiedDiff_GitPrefixes(t *testing.T) {
+	output := "--- a/src/app.py\n+++ b/src/app.py\n@@ -2 +2 @@\n-x=1\n+x = 1\n"
+
+	issues := ParseUnifiedDiff(output, "")
+
+	require.Len(t, issues, 1)
+	assert.Equal(t, "src/app.py", issues[0].File)
+	assert.Equal(t, 2, issues[0].Line)
+}
+
+func TestParseUnifiedDiff_RoundTrip(t *testing.T) {
+	diff := UnifiedDiff("x.go", "a\nb\nc", "a\nB\nc\n")
+
+	issues := ParseUnifiedDiff(diff, "")
+
+	require.Len(t, issues, 1)
+	assert.Equal(t, diff, issues[0].Suggestion)
+}
+
+func TestParseRustfmtCheck(t *testing.T) {
+	output := "Diff in /p/src/main.rs at line 1:\n" +
+		" fn main() {\n-    let x=1;\n+    let x = 1;\n }\n" +
+		"Diff in /p/src/main.rs:10:\n-fn f(){}\n+fn f() {}\n" +
+		"Diff in /p/src/lib.rs:4:\n pub mod a;\n+\n"
+
+	issues := parseRustfmtCheck(output, "file is not rustfmt-formatted")
+
+	require.Len(t, issues, 2)
+	assert.Equal(t, "/p/src/main.rs", issues[0].File)
+	assert.Equal(t, 1, issues[0].Line)
+	assert.Equal(t, "--- /p/src/main.rs\n+++ /p/src/main.rs\n"+
+		"@@ -1,3 +1,3 @@\n fn main() {\n-    let x=1;\n+    let x = 1;\n }\n"+
+		"@@ -10 +10 @@\n-fn f(){}\n+fn f() {}\n", issues[0].Suggestion)
+	assert.Equal(t, "/p/src/lib.rs", issues[1].File)
+	assert.Equal(t, 4, issues[1].Line)
+	assert.Contains(t, issues[1].Suggestion, "@@ -4 +4,2 @@")
+}
+
+func TestPrettierTool_ParseOutput(t *testing.T) {
+	output := "Checking formatting...\n[warn] src/a.ts\n[warn] src/b.css\n" +
+		"[warn] Code style issues found in 2 files. Run Prettier with --write to fix.\n"
+
+	issues := NewPrettierTool().ParseOutput(output)
+
+	require.Len(t, issues, 2)
+	assert.Equal(t, "src/a.ts", issues[0].File)
+	assert.Equal(t, "src/b.css", issues[1].File)
+	assert.Equal(t, "format", issues[1].Rule)
+}
+
+func TestPrettierTool_Execute_CheckAttachesDiff(t *testing.T) {
+	binDir := t.TempDir()
+	projectDir := t.TempDir()
+	require.NoError(t, os.WriteFile(filepath.Join(projectDir, "a.js"), []byte("let x=1\n"), 0o644))
+
+	// Fake prettier: --check lists the file, --stdin-filepath formats stdin
+	testutil.WriteExecutable(t, binDir, "prettier", `#!/bin/sh
+if [ "$1" = "--check" ]; then
+  echo "[warn] a.js" >&2
+  exit 1
+fi
+sed 's/x=1/x = 1;/'
+`)
+	t.Setenv("PATH", binDir+string(os.PathListSeparator)+os.Getenv("PATH"))
+
+	result, err := NewPrettierTool().Execute(context.Background(), []string{"a.js"},
+		ExecuteOptions{ProjectRoot: projectDir, Check: true})
+
+	require.NoError(t, err)
+	assert.Equal(t, StatusIssues, result.Status)
+	require.Len(t, result.Issues, 1)
+	assert.Equal(t, 1, result.Issues[0].Line)
+	assert.Equal(t, "--- a.js\n+++ a.js\n@@ -1 +1 @@\n-let x=1\n+let x = 1;\n", result.Issues[0].Suggestion)
+
+	// The file itself is untouched
+	content, err := os.ReadFile(filepath.Join(projectDir, "a.js"))
+	require.NoError(t, err)
+	assert.Equal(t, "let x=1\n", string(content))
+}
@@ -20,7 +20,10 @@ func NewHadolintTool() *HadolintTool {
 		BaseTool: NewBaseTool("hadolint", "Dockerfile", "hadolint", LINT),
 	}
 
-	tool.SetInstallCommand([]string{"brew", "install", "hadolint"})
+	tool.Bind(tool)
+	// hadolint exits 1 when rules fail
+	tool.SetFindingExitCodes(1)
+	tool.AddPackage(PackageBrew, "hadolint")
 	tool.SetConfigPatterns([]string{".hadolint.yaml", ".hadolint.yml", "hadolint.yaml"})
 
 	return tool
@@ -0,0 +1,132 @@
+// Copyright (c) 2025 Archmagece
+// SPDX-License-Identifier: MIT
+
+package tools
+
+import (
+	"bytes"
+	"fmt"
+	"unicode/utf16"
+	"unicode/utf8"
+)
+
+// Applicability says whether an edit can be applied without review.
+type Applicability string
+
+const (
+	// ApplicabilitySafe edits preserve behaviour and can be applied automatically
+	ApplicabilitySafe Applicability = "safe"
+
+	// ApplicabilityUnsafe edits are probably right but may change behaviour
+	ApplicabilityUnsafe Applicability = "unsafe"
+
+	// ApplicabilityManual edits contain placeholders or are display-only
+	ApplicabilityManual Applicability = "manual"
+)
+
+// OffsetUnit is the unit StartOffset and EndOffset are counted in.
+type OffsetUnit string
+
+const (
+	// OffsetBytes counts offsets in bytes (the default)
+	OffsetBytes OffsetUnit = ""
+
+	// OffsetUTF16 counts offsets in UTF-16 code units (JavaScript strings)
+	OffsetUTF16 OffsetUnit = "utf16"
+)
+
+// TextEdit is a machine-applicable replacement of a range in a file.
+//
+// When StartLine is set the range is [StartLine:StartColumn, EndLine:EndColumn)
+// with 1-based lines and 1-based character columns. Otherwise the range is
+// [StartOffset, EndOffset) counted in Unit.
+type TextEdit struct {
+	// File is the path to the edited file
+	File string
+
+	// StartLine and StartColumn are where the range starts (1-based)
+	StartLine   int
+	StartColumn int
+
+	// EndLine and EndColumn are where the range ends, exclusive (1-based)
+	EndLine   int
+	EndColumn int
+
+	// StartOffset and EndOffset delimit the range when no lines are set
+	StartOffset int
+	EndOffset   int
+
+	// Unit is the unit of StartOffset and EndOffset
+	Unit OffsetUnit
+
+	// Replacement is the text that replaces the range
+	Replacement string
+
+	// Applicability says whether the edit is safe to apply automatically
+	Applicability Applicability
+}
+
+// IsSafe reports whether the edit can be applied without review.
+func (e TextEdit) IsSafe() bool {
+	return e.Applicability == ApplicabilitySafe
+}
+
+// Resolve returns the byte range [start, end) the edit replaces in content.
+func (e TextEdit) Resolve(content []byte) (start, end int, err error) {
+	if e.StartLine > 0 {
+		if start, err = lineColumnOffset(content, e.StartLine, e.StartColumn); err != nil {
+			return 0, 0, err
+		}
+		if end, err = lineColumnOffset(content, e.EndLine, e.EndColumn); err != nil {
+			return 0, 0, err
+		}
+	} else {
+		start, end = e.StartOffset, e.EndOffset
+		if e.Unit == OffsetUTF16 {
+			start, end = utf16ByteOffset(content, start), utf16ByteOffset(content, end)
+		}
+	}
+
+	if start < 0 || end > len(content) || start > end {
+		return 0, 0, fmt.Errorf("edit range %d-%d is outside %s (%d bytes)", start, end, e.File, len(content))
+	}
+	return start, end, nil
+}
+
+// lineColumnOffset converts a 1-based line and character column into a byte
+// offset. A column past the end of the line points at its line break, and
+// line count+1 column 1 points at the end of the content.
+func lineColumnOffset(content []byte, line, column int) (int, error) {
+	offset := 0
+	for current := 1; current < line; current++ {
+		next := bytes.IndexByte(content[offset:], '\n')
+		if next < 0 {
+			if current == line-1 && column <= 1 {
+				return len(content), nil
+			}
+			return 0, fmt.Errorf("line %d is beyond the end of the file", line)
+		}
+		offset += next + 1
+	}
+
+	for col := 1; col < column && offset < len(content) && content[offset] != '\n'; col++ {
+		_, size := utf8.DecodeRune(content[offset:])
+		offset += size
+	}
+	return offset, nil
+}
+
+// utf16ByteOffset converts an offset in UTF-16 code units into bytes.
+// Out-of-range offsets map past the end so Resolve rejects them.
+func utf16ByteOffset(content []byte, units int) int {
+	offset := 0
+	for units > 0 {
+		if offset >= len(content) {
+			return len(content) + units
+		}
+		r, size := utf8.DecodeRune(content[offset:])
+		units -= utf16.RuneLen(r)
+		offset += size
+	}
+	return offset
+}
@@ -4,11 +4,13 @@
 package tools
 
 import (
+	"context"
 	"encoding/json"
 	"fmt"
 	"os/exec"
 	"path/filepath"
 	"strings"
+	"unicode/utf8"
 )
 
 // GofumptTool implements Go formatting using gofumpt.
@@ -22,7 +24,10 @@ func NewGofumptTool() *GofumptTool {
 		BaseTool: NewBaseTool("gofumpt", "Go", "gofumpt", FORMAT),
 	}
 
-	tool.SetInstallCommand([]string{"go", "install", "mvdan.cc/gofumpt@latest"})
+	tool.Bind(tool)
+	// -d exits 1 when a file differs (check mode)
+	tool.SetFindingExitCodes(1)
+	tool.AddPackage(PackageGo, "mvdan.cc/gofumpt")
 	tool.SetConfigPatterns([]string{".gofumpt"})
 
 	return tool
@@ -30,7 +35,10 @@ func NewGofumptTool() *GofumptTool {
 
 // BuildCommand builds the gofumpt command.
 func (t *GofumptTool) BuildCommand(files []string, options ExecuteOptions) *exec.Cmd {
-	args := []string{"-w"} // Always write changes
+	args := []string{"-w"} // Write changes unless only checking
+	if options.Check {
+		args = []string{"-l", "-d"}
+	}
 
 	// Add extra flags if provided
 	args = append(args, options.ExtraArgs...)
@@ -48,6 +56,16 @@ func (t *GofumptTool) BuildCommand(files []string, options ExecuteOptions) *exec
 	return cmd
 }
 
+// ParseOutput parses gofumpt -d output into one issue per unformatted file.
+func (t *GofumptTool) ParseOutput(output string) []Issue {
+	return ParseUnifiedDiff(output, "file is not gofumpt-formatted")
+}
+
+// SupportsCheck reports that gofumpt can verify formatting without writing.
+func (t *GofumptTool) SupportsCheck() bool {
+	return true
+}
+
 // GoimportsTool implements Go import formatting using goimports.
 type GoimportsTool struct {
 	*BaseTool
@@ -59,19 +77,25 @@ func NewGoimportsTool() *GoimportsTool {
 		BaseTool: NewBaseTool("goimports", "Go", "goimports", FORMAT),
 	}
 
-	tool.SetInstallCommand([]string{"go", "install", "golang.org/x/tools/cmd/goimports@latest"})
+	tool.Bind(tool)
+	// -d exits 1 when a file differs (check mode)
+	tool.SetFindingExitCodes(1)
+	tool.AddPackage(PackageGo, "golang.org/x/tools/cmd/goimports")
 
 	return tool
 }
 
 // BuildCommand builds the goimports command.
 func (t *GoimportsTool) BuildCommand(files []string, options ExecuteOptions) *exec.Cmd {
-	args := []string{"-w"} // Always write changes
+	args := []string{"-w"} // Write changes unless only checking
+	if options.Check {
+		args = []string{"-l", "-d"}
+	}
 
 	// Add local import setting if project root is available
 	if options.ProjectRoot != "" {
 		// Try to determine module name from go.mod
-		if modName := getGoModuleName(options.ProjectRoot); modName != "" {
+		if modName := getGoModuleName(runnerOf(options), options.ProjectRoot); modName != "" {
 			args = append(args, "-local", modName)
 		}
 	}
@@ -92,6 +116,16 @@ func (t *GoimportsTool) BuildCommand(files []string, options ExecuteOptions) *ex
 	return cmd
 }
 
+// ParseOutput parses goimports -d output into one issue per unformatted file.
+func (t *GoimportsTool) ParseOutput(output string) []Issue {
+	return ParseUnifiedDiff(output, "file is not goimports-formatted")
+}
+
+// SupportsCheck reports that goimports can verify formatting without writing.
+func (t *GoimportsTool) SupportsCheck() bool {
+	return true
+}
+
 // GolangciLintTool implements Go linting using golangci-lint.
 type GolangciLintTool struct {
 	*BaseTool
@@ -103,7 +137,10 @@ func NewGolangciLintTool() *GolangciLintTool {
 		BaseTool: NewBaseTool("golangci-lint", "Go", "golangci-lint", LINT),
 	}
 
-	tool.SetInstallCommand([]string{"go", "install", "github.com/golangci/golangci-lint/cmd/golangci-lint@latest"})
+	tool.Bind(tool)
+	// golangci-lint --issues-exit-code defaults to 1
+	tool.SetFindingExitCodes(1)
+	tool.AddPackage(PackageGo, "github.com/golangci/golangci-lint/cmd/golangci-lint")
 	tool.SetConfigPatterns([]string{".golangci.yml", ".golangci.yaml", "golangci.yml", "golangci.yaml"})
 
 	return tool
@@ -165,14 +202,12 @@ func (t *GolangciLintTool) ParseOutput(output string) []Issue {
 
 	var lintResults struct {
 		Issues []struct {
-			FromLinter  string   `json:"FromLinter"`
-			Text        string   `json:"Text"`
-			Severity    string   `json:"Severity"`
-			SourceLines []string `json:"SourceLines"`
-			Replacement *struct {
-				NewLines []string `json:"NewLines"`
-			} `json:"Replacement,omitempty"`
-			Pos struct {
+			FromLinter  string               `json:"FromLinter"`
+			Text        string               `json:"Text"`
+			Severity    string               `json:"Severity"`
+			SourceLines []string             `json:"SourceLines"`
+			Replacement *golangciReplacement `json:"Replacement,omitempty"`
+			Pos         struct {
 				Filename string `json:"Filename"`
 				Offset   int    `json:"Offset"`
 				Line     int    `json:"Line"`
@@ -197,8 +232,13 @@ func (t *GolangciLintTool) ParseOutput(output string) []Issue {
 			Message:  item.Text,
 		}
 
-		if item.Replacement != nil && len(item.Replacement.NewLines) > 0 {
-			issue.Suggestion = strings.Join(item.Replacement.NewLines, "\n")
+		if item.Replacement != nil {
+			if len(item.Replacement.NewLines) > 0 {
+				issue.Suggestion = strings.Join(item.Replacement.NewLines, "\n")
+			}
+			if edit, ok := item.Replacement.edit(item.Pos.Filename, item.Pos.Line, item.SourceLines); ok {
+				issue.Edits = []TextEdit{edit}
+			}
 		}
 
 		issues = append(issues, issue)
@@ -207,22 +247,75 @@ func (t *GolangciLintTool) ParseOutput(output string) []Issue {
 	return issues
 }
 
+// golangciReplacement is the fix golangci-lint attaches to an issue.
+type golangciReplacement struct {
+	NeedOnlyDelete bool     `json:"NeedOnlyDelete"`
+	NewLines       []string `json:"NewLines"`
+	Inline         *struct {
+		StartCol  int    `json:"StartCol"` // zero-based byte column
+		Length    int    `json:"Length"`
+		NewString string `json:"NewString"`
+	} `json:"Inline"`
+}
+
+// edit converts the replacement into a TextEdit. Line replacements swap the
+// issue's whole source lines; inline ones replace a span of the first line.
+func (r *golangciReplacement) edit(file string, line int, sourceLines []string) (TextEdit, bool) {
+	if line <= 0 {
+		return TextEdit{}, false
+	}
+
+	if r.Inline != nil {
+		if len(sourceLines) == 0 || r.Inline.StartCol+r.Inline.Length > len(sourceLines[0]) {
+			return TextEdit{}, false
+		}
+		source := sourceLines[0]
+		startColumn := utf8.RuneCountInString(source[:r.Inline.StartCol]) + 1
+		endColumn := utf8.RuneCountInString(source[:r.Inline.StartCol+r.Inline.Length]) + 1
+		return TextEdit{
+			File:          file,
+			StartLine:     line,
+			StartColumn:   startColumn,
+			EndLine:       line,
+			EndColumn:     endColumn,
+			Replacement:   r.Inline.NewString,
+			Applicability: ApplicabilitySafe,
+		}, true
+	}
+
+	if len(sourceLines) == 0 || (!r.NeedOnlyDelete && len(r.NewLines) == 0) {
+		return TextEdit{}, false
+	}
+
+	replacement := ""
+	if !r.NeedOnlyDelete {
+		replacement = strings.Join(r.NewLines, "\n") + "\n"
+	}
+	return TextEdit{
+		File:          file,
+		StartLine:     line,
+		StartColumn:   1,
+		EndLine:       line + len(sourceLines),
+		EndColumn:     1,
+		Replacement:   replacement,
+		Applicability: ApplicabilitySafe,
+	}, true
+}
+
 // parseTextOutput parses plain text output as fallback.
 func (t *GolangciLintTool) parseTextOutput(output string) []Issue {
 	return ParseTextLines(output, GolangciLintParseConfig)
 }
 
-// getGoModuleName extracts module name from go.mod file.
-func getGoModuleName(projectRoot string) string {
-	cmd := exec.Command("go", "list", "-m")
-	cmd.Dir = projectRoot
-
-	output, err := cmd.Output()
+// getGoModuleName returns the module path `go list -m` reports for
+// projectRoot, run through runner.
+func getGoModuleName(runner CommandRunner, projectRoot string) string {
+	output, err := runner.Run(context.Background(), Invocation{Args: []string{"go", "list", "-m"}, Dir: projectRoot})
 	if err != nil {
 		return ""
 	}
 
-	return strings.TrimSpace(string(output))
+	return strings.TrimSpace(string(output.Stdout))
 }
 
 // GosecTool implements Go security scanning using gosec.
@@ -236,7 +329,10 @@ func NewGosecTool() *GosecTool {
 		BaseTool: NewBaseTool("gosec", "Go", "gosec", LINT),
 	}
 
-	tool.SetInstallCommand([]string{"go", "install", "github.com/securego/gosec/v2/cmd/gosec@latest"})
+	tool.Bind(tool)
+	// gosec exits 1 when issues are found
+	tool.SetFindingExitCodes(1)
+	tool.AddPackage(PackageGo, "github.com/securego/gosec/v2/cmd/gosec")
 
 	return tool
 }
@@ -326,7 +422,12 @@ func NewGovulncheckTool() *GovulncheckTool {
 		BaseTool: NewBaseTool("govulncheck", "Go", "govulncheck", LINT),
 	}
 
-	tool.SetInstallCommand([]string{"go", "install", "golang.org/x/vuln/cmd/govulncheck@latest"})
+	tool.Bind(tool)
+	// govulncheck exits 3 when vulnerabilities are found
+	tool.SetFindingExitCodes(3)
+	tool.AddPackage(PackageGo, "golang.org/x/vuln/cmd/govulncheck")
+	// "Go: go1.22.1\nScanner: govulncheck@v1.0.4" - the Go version comes first
+	tool.SetVersionPattern(`govulncheck@v(\d+\.\d+\.\d+)`)
 
 	return tool
 }
@@ -350,6 +451,11 @@ func (t *GovulncheckTool) BuildCommand(files []string, options ExecuteOptions) *
 	return cmd
 }
 
+// OmitsFiles reports that govulncheck scans packages, not files.
+func (t *GovulncheckTool) OmitsFiles() bool {
+	return true
+}
+
 // ParseOutput parses govulncheck JSON output.
 func (t *GovulncheckTool) ParseOutput(output string) []Issue {
 	if strings.TrimSpace(output) == "" {
@@ -400,7 +506,8 @@ func NewGciTool() *GciTool {
 		BaseTool: NewBaseTool("gci", "Go", "gci", FORMAT),
 	}
 
-	tool.SetInstallCommand([]string{"go", "install", "github.com/daixiang0/gci@latest"})
+	tool.Bind(tool)
+	tool.AddPackage(PackageGo, "github.com/daixiang0/gci")
 	tool.SetConfigPatterns([]string{".gci.yml", ".gci.yaml"})
 
 	return tool
@@ -412,7 +519,7 @@ func (t *GciTool) BuildCommand(files []string, options ExecuteOptions) *exec.Cmd
 
 	// Default section order: standard, default, prefix
 	if options.ProjectRoot != "" {
-		if modName := getGoModuleName(options.ProjectRoot); modName != "" {
+		if modName := getGoModuleName(runnerOf(options), options.ProjectRoot); modName != "" {
 			args = append(args, "-s", "standard", "-s", "default", "-s", "prefix("+modName+")")
 		} else {
 			args = append(args, "-s", "standard", "-s", "default")
@@ -452,7 +559,8 @@ func NewGolinesTool() *GolinesTool {
 		BaseTool: NewBaseTool("golines", "Go", "golines", FORMAT),
 	}
 
-	tool.SetInstallCommand([]string{"go", "install", "github.com/segmentio/golines@latest"})
+	tool.Bind(tool)
+	tool.AddPackage(PackageGo, "github.com/segmentio/golines")
 
 	return tool
 }
@@ -490,4 +598,9 @@ var (
 	_ QualityTool = (*GovulncheckTool)(nil)
 	_ QualityTool = (*GciTool)(nil)
 	_ QualityTool = (*GolinesTool)(nil)
+
+	_ FormatChecker = (*GofumptTool)(nil)
+	_ FormatChecker = (*GoimportsTool)(nil)
+
+	_ FilelessCommand = (*GovulncheckTool)(nil)
 )
@@ -10,6 +10,7 @@ import (
 	"testing"
 
 	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
 )
 
 func TestNewGofumptTool(t *testing.T) {
@@ -61,6 +62,14 @@ func TestGofumptTool_BuildCommand(t *testing.T) {
 			options: ExecuteOptions{},
 			expectedArgs: []string{"-w", "main.go"},
 		},
+		{
+			name:  "check mode lists diffs without writing",
+			files: []string{"main.go"},
+			options: ExecuteOptions{
+				Check: true,
+			},
+			expectedArgs: []string{"-l", "-d", "main.go"},
+		},
 	}
 
 	for _, tt := range tests {
@@ -317,6 +326,57 @@ func TestGolangciLintTool_ParseOutput(t *testing.T) {
 			checkIssue: func(t *testing.T, issue Issue) {
 				assert.NotEmpty(t, issue.Suggestion)
 				assert.Contains(t, issue.Suggestion, "formatted line 1")
+				assert.Empty(t, issue.Edits) // no SourceLines to replace
+			},
+		},
+		{
+			name: "with line replacement edit",
+			output: `{
+				"Issues": [
+					{
+						"FromLinter": "gofmt",
+						"Text": "File is not formatted",
+						"Severity": "error",
+						"SourceLines": ["x:=1"],
+						"Pos": {"Filename": "main.go", "Line": 5, "Column": 1},
+						"Replacement": {"NewLines": ["x := 1"]}
+					}
+				]
+			}`,
+			expected: 1,
+			checkIssue: func(t *testing.T, issue Issue) {
+				assert.Equal(t, []TextEdit{{
+					File:          "main.go",
+					StartLine:     5,
+					StartColumn:   1,
+					EndLine:       6,
+					EndColumn:     1,
+					Replacement:   "x := 1\n",
+					Applicability: ApplicabilitySafe,
+				}}, issue.Edits)
+			},
+		},
+		{
+			name: "with inline replacement edit",
+			output: `{
+				"Issues": [
+					{
+						"FromLinter": "misspell",
+						"Text": "\"héllo wrold\" is misspelled",
+						"Severity": "warning",
+						"SourceLines": ["// héllo wrold"],
+						"Pos": {"Filename": "main.go", "Line": 3, "Column": 11},
+						"Replacement": {"Inline": {"StartCol": 10, "Length": 5, "NewString": "world"}}
+					}
+				]
+			}`,
+			expected: 1,
+			checkIssue: func(t *testing.T, issue Issue) {
+				require.Len(t, issue.Edits, 1)
+				assert.Equal(t, 3, issue.Edits[0].StartLine)
+				assert.Equal(t, 10, issue.Edits[0].StartColumn) // zero-based byte 10, é takes two bytes
+				assert.Equal(t, 15, issue.Edits[0].EndColumn)
+				assert.Equal(t, "world", issue.Edits[0].Replacement)
 			},
 		},
 	}
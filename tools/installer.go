@@ -0,0 +1,141 @@
+// Copyright (c) 2025 Archmagece
+// SPDX-License-Identifier: MIT
+
+package tools
+
+import (
+	"fmt"
+	"os/exec"
+	"strings"
+)
+
+// Installer is a package manager front-end that installs and upgrades
+// packages of one ecosystem.
+type Installer struct {
+	// Name is the front-end's executable, e.g. "apt-get" or "uv"
+	Name string
+
+	// Manager is the ecosystem whose package names the front-end accepts
+	Manager PackageManager
+
+	install func(name string) []string
+	upgrade func(name string) []string
+}
+
+// InstallCommand returns the command that installs the named package.
+func (i Installer) InstallCommand(name string) []string {
+	return i.install(name)
+}
+
+// UpgradeCommand returns the command that upgrades the named package to its latest version.
+func (i Installer) UpgradeCommand(name string) []string {
+	return i.upgrade(name)
+}
+
+// knownInstallers lists the supported front-ends. Within an ecosystem the
+// first available one wins (uv before pipx before pip, npm before pnpm).
+var knownInstallers = []Installer{
+	{
+		Name:    "go",
+		Manager: PackageGo,
+		install: func(name string) []string { return []string{"go", "install", name + "@latest"} },
+		upgrade: func(name string) []string { return []string{"go", "install", name + "@latest"} },
+	},
+	{
+		Name:    "uv",
+		Manager: PackagePip,
+		install: func(name string) []string { return []string{"uv", "tool", "install", name} },
+		upgrade: func(name string) []string { return []string{"uv", "tool", "upgrade", name} },
+	},
+	{
+		Name:    "pipx",
+		Manager: PackagePip,
+		install: func(name string) []string { return []string{"pipx", "install", name} },
+		upgrade: func(name string) []string { return []string{"pipx", "upgrade", name} },
+	},
+	{
+		Name:    "pip3",
+		Manager: PackagePip,
+		install: func(name string) []string { return []string{"pip3", "install", "--user", name} },
+		upgrade: func(name string) []string { return []string{"pip3", "install", "--user", "-U", name} },
+	},
+	{
+		Name:    "npm",
+		Manager: PackageNpm,
+		install: func(name string) []string { return []string{"npm", "install", "-g", name} },
+		upgrade: func(name string) []string { return []string{"npm", "install", "-g", name + "@latest"} },
+	},
+	{
+		Name:    "pnpm",
+		Manager: PackageNpm,
+		install: func(name string) []string { return []string{"pnpm", "add", "-g", name} },
+		upgrade: func(name string) []string { return []string{"pnpm", "add", "-g", name + "@latest"} },
+	},
+	{
+		Name:    "cargo",
+		Manager: PackageCargo,
+		install: func(name string) []string { return []string{"cargo", "install", "--locked", name} },
+		// cargo install replaces an older installed version with the latest one
+		upgrade: func(name string) []string { return []string{"cargo", "install", "--locked", name} },
+	},
+	{
+		Name:    "rustup",
+		Manager: PackageRustup,
+		install: func(name string) []string { return []string{"rustup", "component", "add", name} },
+		// Components are upgraded together with their toolchain
+		upgrade: func(string) []string { return []string{"rustup", "update"} },
+	},
+	{
+		Name:    "apt-get",
+		Manager: PackageApt,
+		install: func(name string) []string { return []string{"apt-get", "install", "-y", name} },
+		upgrade: func(name string) []string { return []string{"apt-get", "install", "-y", "--only-upgrade", name} },
+	},
+	{
+		Name:    "dnf",
+		Manager: PackageDnf,
+		install: func(name string) []string { return []string{"dnf", "install", "-y", name} },
+		upgrade: func(name string) []string { return []string{"dnf", "upgrade", "-y", name} },
+	},
+	{
+		Name:    "pacman",
+		Manager: PackagePacman,
+		install: func(name string) []string { return []string{"pacman", "-S", "--noconfirm", "--needed", name} },
+		upgrade: func(name string) []string { return []string{"pacman", "-S", "--noconfirm", name} },
+	},
+	{
+		Name:    "brew",
+		Manager: PackageBrew,
+		install: func(name string) []string { return []string{"brew", "install", name} },
+		upgrade: func(name string) []string { return []string{"brew", "upgrade", name} },
+	},
+}
+
+// DetectInstallers returns the package manager front-ends found on PATH.
+func DetectInstallers() []Installer {
+	var available []Installer
+	for _, installer := range knownInstallers {
+		if _, err := exec.LookPath(installer.Name); err == nil {
+			available = append(available, installer)
+		}
+	}
+	return available
+}
+
+// SelectInstaller picks the first package, in the tool's order of preference,
+// that one of the available installers can handle.
+func SelectInstaller(packages []Package, available []Installer) (Installer, Package, error) {
+	for _, pkg := range packages {
+		for _, installer := range available {
+			if installer.Manager == pkg.Manager {
+				return installer, pkg, nil
+			}
+		}
+	}
+
+	managers := make([]string, len(packages))
+	for i, pkg := range packages {
+		managers[i] = string(pkg.Manager)
+	}
+	return Installer{}, Package{}, fmt.Errorf("no supported package manager found (needs one of: %s)", strings.Join(managers, ", "))
+}
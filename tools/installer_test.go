@@ -0,0 +1,141 @@
+// Copyright (c) 2025 Archmagece
+// SPDX-License-Identifier: MIT
+
+package tools
+
+import (
+	"testing"
+
+	"github.com/Gizzahub/gzh-cli-quality/internal/testutil"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestDetectInstallers(t *testing.T) {
+	dir := t.TempDir()
+	for _, name := range []string{"pip3", "uv", "brew"} {
+		testutil.WriteExecutable(t, dir, name, "exit 0\n")
+	}
+	t.Setenv("PATH", dir)
+
+	var names []string
+	for _, installer := range DetectInstallers() {
+		names = append(names, installer.Name)
+	}
+
+	// Preference order is kept regardless of what was found first
+	assert.Equal(t, []string{"uv", "pip3", "brew"}, names)
+}
+
+func TestSelectInstaller(t *testing.T) {
+	installers := func(names ...string) []Installer {
+		var selected []Installer
+		for _, installer := range knownInstallers {
+			for _, name := range names {
+				if installer.Name == name {
+					selected = append(selected, installer)
+				}
+			}
+		}
+		return selected
+	}
+	shellcheck := NewShellcheckTool().Packages()
+
+	tests := []struct {
+		name      string
+		available []Installer
+		install   []string
+		upgrade   []string
+	}{
+		{
+			name:      "system package first",
+			available: installers("pipx", "apt-get"),
+			install:   []string{"apt-get", "install", "-y", "shellcheck"},
+			upgrade:   []string{"apt-get", "install", "-y", "--only-upgrade", "shellcheck"},
+		},
+		{
+			name:      "dnf package name",
+			available: installers("dnf"),
+			install:   []string{"dnf", "install", "-y", "ShellCheck"},
+			upgrade:   []string{"dnf", "upgrade", "-y", "ShellCheck"},
+		},
+		{
+			name:      "pip front-end preference",
+			available: installers("pip3", "pipx"),
+			install:   []string{"pipx", "install", "shellcheck-py"},
+			upgrade:   []string{"pipx", "upgrade", "shellcheck-py"},
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			installer, pkg, err := SelectInstaller(shellcheck, tt.available)
+			require.NoError(t, err)
+			assert.Equal(t, tt.install, installer.InstallCommand(pkg.Name))
+			assert.Equal(t, tt.upgrade, installer.UpgradeCommand(pkg.Name))
+		})
+	}
+
+	_, _, err := SelectInstaller(shellcheck, installers("npm", "go"))
+	assert.ErrorContains(t, err, "needs one of: apt, dnf, pacman, brew, pip")
+}
+
+func TestBaseTool_InstallCommand(t *testing.T) {
+	dir := t.TempDir()
+	for _, name := range []string{"go", "npm", "uv", "rustup"} {
+		testutil.WriteExecutable(t, dir, name, "exit 0\n")
+	}
+	t.Setenv("PATH", dir)
+
+	tests := []struct {
+		tool    *BaseTool
+		install []string
+		upgrade []string
+	}{
+		{
+			tool:    NewGofumptTool().BaseTool,
+			install: []string{"go", "install", "mvdan.cc/gofumpt@latest"},
+			upgrade: []string{"go", "install", "mvdan.cc/gofumpt@latest"},
+		},
+		{
+			tool:    NewRuffTool().BaseTool,
+			install: []string{"uv", "tool", "install", "ruff"},
+			upgrade: []string{"uv", "tool", "upgrade", "ruff"},
+		},
+		{
+			tool:    NewESLintTool().BaseTool,
+			install: []string{"npm", "install", "-g", "eslint"},
+			upgrade: []string{"npm", "install", "-g", "eslint@latest"},
+		},
+		{
+			tool:    NewClippyTool().BaseTool,
+			install: []string{"rustup", "component", "add", "clippy"},
+			upgrade: []string{"rustup", "update"},
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.tool.Name(), func(t *testing.T) {
+			install, err := tt.tool.InstallCommand(false)
+			require.NoError(t, err)
+			assert.Equal(t, tt.install, install)
+
+			upgrade, err := tt.tool.InstallCommand(true)
+			require.NoError(t, err)
+			assert.Equal(t, tt.upgrade, upgrade)
+		})
+	}
+
+	// No package manager for hadolint on this PATH
+	_, err := NewHadolintTool().InstallCommand(false)
+	assert.ErrorContains(t, err, "cannot install hadolint")
+}
+
+func TestBaseTool_Package(t *testing.T) {
+	pkg, ok := NewClangFormatTool().Package()
+	require.True(t, ok)
+	assert.Equal(t, Package{Manager: PackagePip, Name: "clang-format"}, pkg)
+
+	_, ok = NewHadolintTool().Package()
+	assert.False(t, ok)
+}
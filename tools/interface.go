@@ -6,6 +6,8 @@ package tools
 
 import (
 	"context"
+	"fmt"
+	"os/exec"
 	"time"
 )
 
@@ -31,6 +33,64 @@ func (t ToolType) String() string {
 	}
 }
 
+// ParseToolType parses "format", "lint" or "both" (also the String forms).
+func ParseToolType(s string) (ToolType, error) {
+	switch s {
+	case "format", "formatter":
+		return FORMAT, nil
+	case "lint", "linter":
+		return LINT, nil
+	case "both", "formatter+linter":
+		return BOTH, nil
+	default:
+		return LINT, fmt.Errorf("unknown tool type %q (expected format, lint or both)", s)
+	}
+}
+
+// Status describes the outcome of a tool run.
+type Status string
+
+const (
+	// StatusClean means the tool ran and reported nothing
+	StatusClean Status = "clean"
+	// StatusIssues means the tool ran and reported findings
+	StatusIssues Status = "issues"
+	// StatusToolError means the tool itself failed (crash, bad config, unknown exit code)
+	StatusToolError Status = "tool_error"
+	// StatusTimeout means the tool was killed because its deadline expired
+	StatusTimeout Status = "timeout"
+	// StatusCancelled means the run was cancelled before the tool finished
+	StatusCancelled Status = "cancelled"
+	// StatusSkipped means the tool did not run (e.g. not installed)
+	StatusSkipped Status = "skipped"
+)
+
+// statusSeverity orders statuses from least to most severe for merging.
+var statusSeverity = map[Status]int{
+	StatusSkipped:   0,
+	StatusClean:     1,
+	StatusIssues:    2,
+	StatusCancelled: 3,
+	StatusTimeout:   4,
+	StatusToolError: 5,
+}
+
+// IsFailure reports whether the status means the tool did not complete properly.
+func (s Status) IsFailure() bool {
+	return s == StatusToolError || s == StatusTimeout || s == StatusCancelled
+}
+
+// WorseStatus returns the more severe of two statuses.
+func WorseStatus(a, b Status) Status {
+	if a == "" {
+		return b
+	}
+	if statusSeverity[b] > statusSeverity[a] {
+		return b
+	}
+	return a
+}
+
 // QualityTool represents a code quality tool (formatter or linter).
 type QualityTool interface {
 	// Name returns the tool name (e.g., "gofumpt", "eslint")
@@ -61,6 +121,64 @@ type QualityTool interface {
 	Execute(ctx context.Context, files []string, options ExecuteOptions) (*Result, error)
 }
 
+// CommandBuilder builds the process invocation for a tool run.
+// Every concrete tool implements it so that execution uses the tool's own argv.
+type CommandBuilder interface {
+	// BuildCommand builds the command for the given files and options
+	BuildCommand(files []string, options ExecuteOptions) *exec.Cmd
+}
+
+// OutputParser converts raw tool output into issues.
+type OutputParser interface {
+	// ParseOutput parses tool output into issues
+	ParseOutput(output string) []Issue
+}
+
+// FormatChecker is implemented by formatters that can verify formatting
+// without writing files. In check mode each unformatted file is reported as
+// an Issue whose Suggestion holds the unified diff.
+type FormatChecker interface {
+	// SupportsCheck reports whether the tool honours ExecuteOptions.Check
+	SupportsCheck() bool
+}
+
+// Configurable is implemented by tools with settings from the gz-quality
+// config beyond ExecuteOptions. The digest is part of cache keys, so changing
+// the settings invalidates cached results.
+type Configurable interface {
+	// SettingsDigest returns a deterministic description of the settings
+	SettingsDigest() string
+}
+
+// Superseded is implemented by tools whose findings another tool reports as
+// well, such as cargo check and clippy. The planner leaves them out when a
+// superseding tool runs for the same files; selecting them by name still
+// runs them.
+type Superseded interface {
+	// SupersededBy returns the names of the tools covering this one
+	SupersededBy() []string
+}
+
+// ProjectScoped is implemented by tools whose findings concern the project
+// rather than the files they run on, such as dependency advisories reported
+// against Cargo.lock. Their results cannot be split by file, so they are
+// cached for the whole task, keyed on the task's files and ProjectInputs.
+type ProjectScoped interface {
+	// ProjectInputs returns the files besides the task's own that the
+	// findings depend on, such as manifests and lockfiles
+	ProjectInputs(files []string, projectRoot string) []string
+}
+
+// FilelessCommand is implemented by tools whose command never lists the
+// task's files, such as govulncheck and the cargo tools, which select
+// packages or check the whole project. Their argv does not grow with the file
+// list, so the planner never splits their tasks and never has to build their
+// commands, which may run helpers like cargo metadata.
+type FilelessCommand interface {
+	// OmitsFiles reports whether the command leaves the files off its argv
+	OmitsFiles() bool
+}
+
 // ExecuteOptions contains options for tool execution.
 type ExecuteOptions struct {
 	// ProjectRoot is the root directory of the project
@@ -78,11 +196,21 @@ type ExecuteOptions struct {
 	// LintOnly runs only linting (for tools that support both)
 	LintOnly bool
 
+	// Check verifies formatting without modifying files (see FormatChecker)
+	Check bool
+
 	// ExtraArgs are additional arguments to pass to the tool
 	ExtraArgs []string
 
 	// Env contains environment variables for the tool
 	Env map[string]string
+
+	// BaseRef is the commit the changes are compared against (from --since),
+	// for tools checking what changed rather than the current state
+	BaseRef string
+
+	// Runner runs the tool's commands; nil runs them as local processes
+	Runner CommandRunner
 }
 
 // Result contains the results of tool execution.
@@ -93,9 +221,15 @@ type Result struct {
 	// Language is the programming language
 	Language string `json:"language"`
 
-	// Success indicates whether the tool executed successfully
+	// Success indicates whether the tool executed successfully (clean or issues)
 	Success bool `json:"success"`
 
+	// Status distinguishes findings from tool failures
+	Status Status `json:"status,omitempty"`
+
+	// ExitCode is the process exit code (-1 if the process did not exit normally)
+	ExitCode int `json:"exit_code"`
+
 	// Error contains any execution error
 	Error string `json:"error,omitempty"`
 
@@ -108,13 +242,34 @@ type Result struct {
 	// Issues contains any issues found by the tool
 	Issues []Issue `json:"issues,omitempty"`
 
-	// Output contains the raw output from the tool
+	// Output contains the raw output from the tool (stdout followed by stderr)
 	Output string `json:"output,omitempty"`
 
+	// Stdout contains the tool's standard output
+	Stdout string `json:"stdout,omitempty"`
+
+	// Stderr contains the tool's standard error
+	Stderr string `json:"stderr,omitempty"`
+
 	// Cached indicates whether this result came from cache
 	Cached bool `json:"cached,omitempty"`
 }
 
+// EffectiveStatus returns Status, deriving it from Success and Issues for
+// results that carry no status (e.g. results cached by older versions).
+func (r *Result) EffectiveStatus() Status {
+	switch {
+	case r.Status != "":
+		return r.Status
+	case !r.Success:
+		return StatusToolError
+	case len(r.Issues) > 0:
+		return StatusIssues
+	default:
+		return StatusClean
+	}
+}
+
 // Issue represents a code quality issue found by a tool.
 type Issue struct {
 	// File is the path to the file containing the issue
@@ -126,6 +281,11 @@ type Issue struct {
 	// Column is the column number (1-based)
 	Column int
 
+	// EndLine and EndColumn are where the issue's range ends (1-based);
+	// zero when the tool reports only a position
+	EndLine   int
+	EndColumn int
+
 	// Severity is the issue severity (error, warning, info)
 	Severity string
 
@@ -137,6 +297,16 @@ type Issue struct {
 
 	// Suggestion is an optional fix suggestion
 	Suggestion string
+
+	// Notes are additional explanations attached to the issue (e.g. rustc's
+	// "note:" children)
+	Notes []string
+
+	// Rendered is the tool's own human-readable rendering of the issue, if any
+	Rendered string
+
+	// Edits are machine-applicable changes that fix the issue, applied together
+	Edits []TextEdit
 }
 
 // LanguageDetector detects programming languages in a project.
@@ -200,6 +370,18 @@ type Task struct {
 
 	// Priority affects execution order (higher = earlier)
 	Priority int
+
+	// Timeout bounds this task alone; zero means only the executor timeout applies
+	Timeout time.Duration
+
+	// Group is shared by the batches of one split task; the executor merges
+	// their results into a single Result. Empty for unsplit tasks.
+	Group string
+
+	// WorkspaceRoot is the root the whole run was planned for. When the task
+	// runs from a nested project (Options.ProjectRoot), relative issue paths
+	// are rebased onto it. Empty means the same as Options.ProjectRoot.
+	WorkspaceRoot string
 }
 
 // Executor runs quality tools according to an execution plan.
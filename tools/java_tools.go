@@ -20,7 +20,10 @@ func NewGoogleJavaFormatTool() *GoogleJavaFormatTool {
 		BaseTool: NewBaseTool("google-java-format", "Java", "google-java-format", FORMAT),
 	}
 
-	tool.SetInstallCommand([]string{"brew", "install", "google-java-format"})
+	tool.Bind(tool)
+	// google-java-format --set-exit-if-changed exits 1 for unformatted files
+	tool.SetFindingExitCodes(1)
+	tool.AddPackage(PackageBrew, "google-java-format")
 
 	return tool
 }
@@ -58,7 +61,11 @@ func NewCheckstyleTool() *CheckstyleTool {
 		BaseTool: NewBaseTool("checkstyle", "Java", "checkstyle", LINT),
 	}
 
-	tool.SetInstallCommand([]string{"brew", "install", "checkstyle"})
+	tool.Bind(tool)
+	// checkstyle exit code is the number of errors found
+	tool.SetFindingExitCodes(exitCodeRange(1, 250)...)
+	tool.AddPackage(PackageApt, "checkstyle")
+	tool.AddPackage(PackageBrew, "checkstyle")
 	tool.SetConfigPatterns([]string{"checkstyle.xml", ".checkstyle.xml", "config/checkstyle/checkstyle.xml"})
 
 	return tool
@@ -153,7 +160,8 @@ func NewSpotbugsTool() *SpotbugsTool {
 		BaseTool: NewBaseTool("spotbugs", "Java", "spotbugs", LINT),
 	}
 
-	tool.SetInstallCommand([]string{"brew", "install", "spotbugs"})
+	tool.Bind(tool)
+	tool.AddPackage(PackageBrew, "spotbugs")
 	tool.SetConfigPatterns([]string{"spotbugs.xml", ".spotbugs.xml", "spotbugs-exclude.xml"})
 
 	return tool
@@ -4,8 +4,12 @@
 package tools
 
 import (
+	"bytes"
+	"context"
 	"encoding/json"
+	"os"
 	"os/exec"
+	"path/filepath"
 	"strings"
 )
 
@@ -20,7 +24,10 @@ func NewPrettierTool() *PrettierTool {
 		BaseTool: NewBaseTool("prettier", "JavaScript", "prettier", FORMAT),
 	}
 
-	tool.SetInstallCommand([]string{"npm", "install", "-g", "prettier"})
+	tool.Bind(tool)
+	// prettier --check exits 1 for unformatted files (2 is an error)
+	tool.SetFindingExitCodes(1)
+	tool.AddPackage(PackageNpm, "prettier")
 	tool.SetConfigPatterns([]string{
 		".prettierrc", ".prettierrc.json", ".prettierrc.js", ".prettierrc.yml", ".prettierrc.yaml",
 		"prettier.config.js", "prettier.config.cjs", "package.json",
@@ -31,7 +38,10 @@ func NewPrettierTool() *PrettierTool {
 
 // BuildCommand builds the prettier command.
 func (t *PrettierTool) BuildCommand(files []string, options ExecuteOptions) *exec.Cmd {
-	args := []string{"--write"} // Always write changes
+	args := []string{"--write"} // Write changes unless only checking
+	if options.Check {
+		args = []string{"--check"}
+	}
 
 	// Add config file if specified
 	if options.ConfigFile != "" {
@@ -63,6 +73,71 @@ func (t *PrettierTool) BuildCommand(files []string, options ExecuteOptions) *exe
 	return cmd
 }
 
+// Execute runs prettier and, in check mode, attaches a diff to each
+// unformatted file since prettier --check only lists file names.
+func (t *PrettierTool) Execute(ctx context.Context, files []string, options ExecuteOptions) (*Result, error) {
+	result, err := t.BaseTool.Execute(ctx, files, options)
+	if err != nil || !options.Check {
+		return result, err
+	}
+
+	for i := range result.Issues {
+		diff := t.formatDiff(ctx, result.Issues[i].File, options)
+		if parsed := ParseUnifiedDiff(diff, ""); len(parsed) > 0 {
+			result.Issues[i].Line = parsed[0].Line
+			result.Issues[i].Suggestion = diff
+		}
+	}
+
+	return result, nil
+}
+
+// formatDiff formats file through prettier's stdin and returns the diff
+// against its current content, or "" if that fails.
+func (t *PrettierTool) formatDiff(ctx context.Context, file string, options ExecuteOptions) string {
+	path := file
+	if !filepath.IsAbs(path) && options.ProjectRoot != "" {
+		path = filepath.Join(options.ProjectRoot, path)
+	}
+	original, err := os.ReadFile(path)
+	if err != nil {
+		return ""
+	}
+
+	args := []string{"--stdin-filepath", file}
+	if options.ConfigFile != "" {
+		args = append(args, "--config", options.ConfigFile)
+	}
+	cmd := exec.Command(t.executable, args...)
+	cmd.Dir = options.ProjectRoot
+	cmd.Stdin = bytes.NewReader(original)
+
+	output, err := runnerOf(options).Run(ctx, InvocationOf(cmd))
+	if err != nil {
+		return ""
+	}
+
+	return UnifiedDiff(file, string(original), string(output.Stdout))
+}
+
+// ParseOutput parses prettier --check output into one issue per unformatted file.
+func (t *PrettierTool) ParseOutput(output string) []Issue {
+	var issues []Issue
+	for _, line := range strings.Split(output, "\n") {
+		matches := prettierCheckPattern.FindStringSubmatch(strings.TrimSpace(line))
+		if matches == nil || strings.HasPrefix(matches[1], "Code style issues") {
+			continue
+		}
+		issues = append(issues, formatIssue(matches[1], 0, "file is not prettier-formatted", ""))
+	}
+	return issues
+}
+
+// SupportsCheck reports that prettier can verify formatting without writing.
+func (t *PrettierTool) SupportsCheck() bool {
+	return true
+}
+
 // ESLintTool implements JavaScript/TypeScript linting using eslint.
 type ESLintTool struct {
 	*BaseTool
@@ -74,7 +149,10 @@ func NewESLintTool() *ESLintTool {
 		BaseTool: NewBaseTool("eslint", "JavaScript", "eslint", LINT),
 	}
 
-	tool.SetInstallCommand([]string{"npm", "install", "-g", "eslint"})
+	tool.Bind(tool)
+	// eslint exits 1 on lint errors (2 is a config or crash)
+	tool.SetFindingExitCodes(1)
+	tool.AddPackage(PackageNpm, "eslint")
 	tool.SetConfigPatterns([]string{
 		".eslintrc", ".eslintrc.json", ".eslintrc.js", ".eslintrc.yml", ".eslintrc.yaml",
 		"eslint.config.js", "eslint.config.mjs", "eslint.config.cjs", "package.json",
@@ -178,6 +256,17 @@ func (t *ESLintTool) ParseOutput(output string) []Issue {
 
 			if msg.Fix != nil {
 				issue.Suggestion = msg.Fix.Text
+				if len(msg.Fix.Range) == 2 {
+					// eslint --fix applies these, so they are safe; ranges index the JS string
+					issue.Edits = []TextEdit{{
+						File:          file.FilePath,
+						StartOffset:   msg.Fix.Range[0],
+						EndOffset:     msg.Fix.Range[1],
+						Unit:          OffsetUTF16,
+						Replacement:   msg.Fix.Text,
+						Applicability: ApplicabilitySafe,
+					}}
+				}
 			}
 
 			issues = append(issues, issue)
@@ -203,7 +292,10 @@ func NewTSCTool() *TSCTool {
 		BaseTool: NewBaseTool("tsc", "TypeScript", "tsc", LINT),
 	}
 
-	tool.SetInstallCommand([]string{"npm", "install", "-g", "typescript"})
+	tool.Bind(tool)
+	// tsc exits 1 or 2 when diagnostics are present
+	tool.SetFindingExitCodes(1, 2)
+	tool.AddPackage(PackageNpm, "typescript")
 	tool.SetConfigPatterns([]string{"tsconfig.json", "jsconfig.json"})
 
 	return tool
@@ -254,4 +346,6 @@ var (
 	_ QualityTool = (*PrettierTool)(nil)
 	_ QualityTool = (*ESLintTool)(nil)
 	_ QualityTool = (*TSCTool)(nil)
+
+	_ FormatChecker = (*PrettierTool)(nil)
 )
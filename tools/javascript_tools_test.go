@@ -65,6 +65,14 @@ func TestPrettierTool_BuildCommand(t *testing.T) {
 			options: ExecuteOptions{},
 			expectedArgs: []string{"main.js"},
 		},
+		{
+			name:  "check mode",
+			files: []string{"main.js"},
+			options: ExecuteOptions{
+				Check: true,
+			},
+			expectedArgs: []string{"--check", "main.js"},
+		},
 	}
 
 	for _, tt := range tests {
@@ -248,6 +256,14 @@ func TestESLintTool_ParseOutput(t *testing.T) {
 			expected: 1,
 			checkIssue: func(t *testing.T, issue Issue) {
 				assert.Equal(t, ";", issue.Suggestion)
+				assert.Equal(t, []TextEdit{{
+					File:          "main.js",
+					StartOffset:   100,
+					EndOffset:     100,
+					Unit:          OffsetUTF16,
+					Replacement:   ";",
+					Applicability: ApplicabilitySafe,
+				}}, issue.Edits)
 			},
 		},
 	}
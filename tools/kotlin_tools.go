@@ -21,7 +21,10 @@ func NewKtlintTool() *KtlintTool {
 		BaseTool: NewBaseTool("ktlint", "Kotlin", "ktlint", BOTH),
 	}
 
-	tool.SetInstallCommand([]string{"brew", "install", "ktlint"})
+	tool.Bind(tool)
+	// ktlint exits 1 on violations
+	tool.SetFindingExitCodes(1)
+	tool.AddPackage(PackageBrew, "ktlint")
 	tool.SetConfigPatterns([]string{".editorconfig", ".ktlint"})
 
 	return tool
@@ -109,7 +112,10 @@ func NewDetektTool() *DetektTool {
 		BaseTool: NewBaseTool("detekt", "Kotlin", "detekt", LINT),
 	}
 
-	tool.SetInstallCommand([]string{"brew", "install", "detekt"})
+	tool.Bind(tool)
+	// detekt exits 2 when the issue threshold is reached (1 and 3 are errors)
+	tool.SetFindingExitCodes(2)
+	tool.AddPackage(PackageBrew, "detekt")
 	tool.SetConfigPatterns([]string{"detekt.yml", "detekt.yaml", ".detekt.yml", "config/detekt/detekt.yml"})
 
 	return tool
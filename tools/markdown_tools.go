@@ -21,7 +21,10 @@ func NewMarkdownlintTool() *MarkdownlintTool {
 		BaseTool: NewBaseTool("markdownlint", "Markdown", "markdownlint-cli2", LINT),
 	}
 
-	tool.SetInstallCommand([]string{"npm", "install", "-g", "markdownlint-cli2"})
+	tool.Bind(tool)
+	// markdownlint exits 1 on lint errors (2 is a failure)
+	tool.SetFindingExitCodes(1)
+	tool.AddPackage(PackageNpm, "markdownlint-cli2")
 	tool.SetConfigPatterns([]string{".markdownlint.json", ".markdownlint.yaml", ".markdownlint.yml", ".markdownlint-cli2.jsonc"})
 
 	return tool
@@ -0,0 +1,68 @@
+// Copyright (c) 2025 Archmagece
+// SPDX-License-Identifier: MIT
+
+package tools
+
+// PackageManager identifies the ecosystem a package name belongs to.
+type PackageManager string
+
+const (
+	// PackageGo is a Go module installed with go install
+	PackageGo PackageManager = "go"
+
+	// PackagePip is a Python distribution on PyPI
+	PackagePip PackageManager = "pip"
+
+	// PackageNpm is an npm package
+	PackageNpm PackageManager = "npm"
+
+	// PackageCargo is a crate installed with cargo install
+	PackageCargo PackageManager = "cargo"
+
+	// PackageRustup is a rustup toolchain component
+	PackageRustup PackageManager = "rustup"
+
+	// PackageApt is a Debian/Ubuntu package
+	PackageApt PackageManager = "apt"
+
+	// PackageDnf is a Fedora/RHEL package
+	PackageDnf PackageManager = "dnf"
+
+	// PackagePacman is an Arch Linux package
+	PackagePacman PackageManager = "pacman"
+
+	// PackageBrew is a Homebrew formula
+	PackageBrew PackageManager = "brew"
+)
+
+// IsRegistry reports whether packages of this ecosystem can be installed at a
+// specific version into an arbitrary prefix (go, pip, npm and cargo).
+func (m PackageManager) IsRegistry() bool {
+	switch m {
+	case PackageGo, PackagePip, PackageNpm, PackageCargo:
+		return true
+	default:
+		return false
+	}
+}
+
+// Package describes where a tool is published, so it can be installed with a
+// package manager or at a specific version into a project-local toolchain.
+type Package struct {
+	// Manager is the ecosystem the package belongs to
+	Manager PackageManager
+
+	// Name is the Go module path, PyPI distribution, npm package, crate,
+	// rustup component or system package name
+	Name string
+}
+
+// Packaged is implemented by tools that declare the packages they ship in.
+type Packaged interface {
+	// Package returns the tool's registry package, or false if it is not
+	// published to a registry
+	Package() (Package, bool)
+
+	// Packages returns every package the tool can be installed from, in order of preference
+	Packages() []Package
+}
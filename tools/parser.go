@@ -4,6 +4,7 @@
 package tools
 
 import (
+	"encoding/json"
 	"regexp"
 	"strconv"
 	"strings"
@@ -28,6 +29,15 @@ var (
 
 	// Generic: file:line:col: message
 	genericPattern = regexp.MustCompile(`^(.+):(\d+):(\d+):\s*(.+)$`)
+
+	// unified diff hunk header: @@ -start[,count] +start[,count] @@
+	hunkHeaderPattern = regexp.MustCompile(`^@@ -(\d+)(?:,(\d+))? \+(\d+)(?:,(\d+))? @@`)
+
+	// rustfmt --check: "Diff in FILE at line N:" (old) or "Diff in FILE:N:" (new)
+	rustfmtDiffPattern = regexp.MustCompile(`^Diff in (.+?)(?: at line (\d+)|:(\d+)):?$`)
+
+	// prettier --check: [warn] FILE
+	prettierCheckPattern = regexp.MustCompile(`^\[warn\]\s+(.+)$`)
 )
 
 // TextParseConfig configures how to parse text output.
@@ -161,3 +171,66 @@ var (
 		DefaultSeverity: "error",
 	}
 )
+
+// sarifLog is the subset of a SARIF 2.1.0 log used to extract issues.
+type sarifLog struct {
+	Runs []struct {
+		Results []struct {
+			RuleID  string `json:"ruleId"`
+			Level   string `json:"level"`
+			Message struct {
+				Text string `json:"text"`
+			} `json:"message"`
+			Locations []struct {
+				PhysicalLocation struct {
+					ArtifactLocation struct {
+						URI string `json:"uri"`
+					} `json:"artifactLocation"`
+					Region struct {
+						StartLine   int `json:"startLine"`
+						StartColumn int `json:"startColumn"`
+					} `json:"region"`
+				} `json:"physicalLocation"`
+			} `json:"locations"`
+		} `json:"results"`
+	} `json:"runs"`
+}
+
+// ParseSARIF parses the results of a SARIF log into issues.
+func ParseSARIF(output string) []Issue {
+	var log sarifLog
+	if err := json.Unmarshal([]byte(output), &log); err != nil {
+		return nil
+	}
+
+	var issues []Issue
+	for _, run := range log.Runs {
+		for _, result := range run.Results {
+			issue := Issue{
+				Rule:    result.RuleID,
+				Message: result.Message.Text,
+			}
+
+			// SARIF defaults a missing level to "warning"
+			switch result.Level {
+			case "error":
+				issue.Severity = "error"
+			case "note", "none":
+				issue.Severity = "info"
+			default:
+				issue.Severity = "warning"
+			}
+
+			if len(result.Locations) > 0 {
+				location := result.Locations[0].PhysicalLocation
+				issue.File = strings.TrimPrefix(location.ArtifactLocation.URI, "file://")
+				issue.Line = location.Region.StartLine
+				issue.Column = location.Region.StartColumn
+			}
+
+			issues = append(issues, issue)
+		}
+	}
+
+	return issues
+}
@@ -0,0 +1,416 @@
+// Copyright (c) 2025 Archmagece
+// SPDX-License-Identifier: MIT
+
+package tools
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"sort"
+	"strings"
+	"time"
+)
+
+// PluginProtocolVersion is the version of the stdio plugin protocol.
+//
+// Every call starts the plugin executable once, writes a single JSON request
+// to its stdin and reads a single JSON response from its stdout:
+//
+//	{"protocol": 1, "method": "describe"}
+//	{"protocol": 1, "result": {"name": "...", "language": "...", "type": "lint"}}
+//
+// Methods are describe, is_available, version and execute. A response with
+// a non-empty "error" reports a failed call.
+const PluginProtocolVersion = 1
+
+// Plugin protocol methods.
+const (
+	PluginMethodDescribe    = "describe"
+	PluginMethodIsAvailable = "is_available"
+	PluginMethodVersion     = "version"
+	PluginMethodExecute     = "execute"
+)
+
+// pluginCallTimeout bounds the describe, is_available and version calls.
+const pluginCallTimeout = 10 * time.Second
+
+// PluginRequest is a request sent to a plugin on stdin.
+type PluginRequest struct {
+	Protocol int            `json:"protocol"`
+	Method   string         `json:"method"`
+	Files    []string       `json:"files,omitempty"`
+	Options  *PluginOptions `json:"options,omitempty"`
+}
+
+// PluginOptions mirrors ExecuteOptions on the wire.
+type PluginOptions struct {
+	ProjectRoot string            `json:"project_root,omitempty"`
+	ConfigFile  string            `json:"config_file,omitempty"`
+	Fix         bool              `json:"fix,omitempty"`
+	FormatOnly  bool              `json:"format_only,omitempty"`
+	LintOnly    bool              `json:"lint_only,omitempty"`
+	Check       bool              `json:"check,omitempty"`
+	ExtraArgs   []string          `json:"extra_args,omitempty"`
+	Env         map[string]string `json:"env,omitempty"`
+}
+
+// PluginResponse is a response read from a plugin's stdout.
+type PluginResponse struct {
+	Protocol int             `json:"protocol"`
+	Result   json.RawMessage `json:"result,omitempty"`
+	Error    string          `json:"error,omitempty"`
+}
+
+// PluginDescription is the result of describe.
+type PluginDescription struct {
+	Name           string   `json:"name"`
+	Language       string   `json:"language"`
+	Type           string   `json:"type"`
+	ConfigPatterns []string `json:"config_patterns,omitempty"`
+}
+
+// pluginAvailability is the result of is_available.
+type pluginAvailability struct {
+	Available bool `json:"available"`
+}
+
+// pluginVersion is the result of version.
+type pluginVersion struct {
+	Version string `json:"version"`
+}
+
+// pluginExecution is the result of execute.
+type pluginExecution struct {
+	Issues []PluginIssue `json:"issues"`
+}
+
+// PluginIssue is an issue reported by a plugin.
+type PluginIssue struct {
+	File       string       `json:"file"`
+	Line       int          `json:"line,omitempty"`
+	Column     int          `json:"column,omitempty"`
+	Severity   string       `json:"severity,omitempty"`
+	Rule       string       `json:"rule,omitempty"`
+	Message    string       `json:"message"`
+	Suggestion string       `json:"suggestion,omitempty"`
+	Edits      []PluginEdit `json:"edits,omitempty"`
+}
+
+// PluginEdit is a TextEdit reported by a plugin. Offsets are in bytes.
+type PluginEdit struct {
+	File          string `json:"file"`
+	StartLine     int    `json:"start_line,omitempty"`
+	StartColumn   int    `json:"start_column,omitempty"`
+	EndLine       int    `json:"end_line,omitempty"`
+	EndColumn     int    `json:"end_column,omitempty"`
+	StartOffset   int    `json:"start_offset,omitempty"`
+	EndOffset     int    `json:"end_offset,omitempty"`
+	Replacement   string `json:"replacement"`
+	Applicability string `json:"applicability,omitempty"`
+}
+
+// PluginTool adapts an external plugin executable to QualityTool.
+type PluginTool struct {
+	*BaseTool
+
+	path string
+}
+
+// NewPluginTool describes the plugin at path and returns its adapter.
+func NewPluginTool(path string) (*PluginTool, error) {
+	ctx, cancel := context.WithTimeout(context.Background(), pluginCallTimeout)
+	defer cancel()
+
+	var description PluginDescription
+	if err := callPlugin(ctx, LocalRunner{}, path, PluginRequest{Method: PluginMethodDescribe}, &description); err != nil {
+		return nil, err
+	}
+	if description.Name == "" {
+		return nil, fmt.Errorf("plugin %s: describe returned no name", path)
+	}
+
+	toolType := LINT
+	if description.Type != "" {
+		parsed, err := ParseToolType(description.Type)
+		if err != nil {
+			return nil, fmt.Errorf("plugin %s: %w", path, err)
+		}
+		toolType = parsed
+	}
+
+	tool := &PluginTool{
+		BaseTool: NewBaseTool(description.Name, description.Language, path, toolType),
+		path:     path,
+	}
+	tool.SetConfigPatterns(description.ConfigPatterns)
+
+	return tool, nil
+}
+
+// DiscoverPlugins loads every executable file in dirs as a plugin.
+// Missing directories are ignored; plugins that fail to describe themselves
+// are returned as errors.
+func DiscoverPlugins(dirs []string) ([]*PluginTool, []error) {
+	var plugins []*PluginTool
+	var errs []error
+
+	for _, dir := range dirs {
+		entries, err := os.ReadDir(dir)
+		if err != nil {
+			if !os.IsNotExist(err) {
+				errs = append(errs, fmt.Errorf("failed to read plugin directory %s: %w", dir, err))
+			}
+			continue
+		}
+
+		sort.Slice(entries, func(i, j int) bool { return entries[i].Name() < entries[j].Name() })
+		for _, entry := range entries {
+			info, err := entry.Info()
+			if err != nil || !info.Mode().IsRegular() || info.Mode().Perm()&0o111 == 0 {
+				continue
+			}
+
+			plugin, err := NewPluginTool(filepath.Join(dir, entry.Name()))
+			if err != nil {
+				errs = append(errs, err)
+				continue
+			}
+			plugins = append(plugins, plugin)
+		}
+	}
+
+	return plugins, errs
+}
+
+// Path returns the plugin executable path.
+func (t *PluginTool) Path() string {
+	return t.path
+}
+
+// IsAvailable asks the plugin whether the tool it wraps can run.
+func (t *PluginTool) IsAvailable() bool {
+	return t.isAvailableWith(LocalRunner{})
+}
+
+// isAvailableWith is IsAvailable calling the plugin through runner.
+func (t *PluginTool) isAvailableWith(runner CommandRunner) bool {
+	ctx, cancel := context.WithTimeout(context.Background(), pluginCallTimeout)
+	defer cancel()
+
+	var availability pluginAvailability
+	if err := callPlugin(ctx, runner, t.path, PluginRequest{Method: PluginMethodIsAvailable}, &availability); err != nil {
+		return false
+	}
+	return availability.Available
+}
+
+// GetVersion asks the plugin for the version of the tool it wraps.
+func (t *PluginTool) GetVersion() (string, error) {
+	return t.GetVersionWith(LocalRunner{})
+}
+
+// GetVersionWith is GetVersion calling the plugin through runner.
+func (t *PluginTool) GetVersionWith(runner CommandRunner) (string, error) {
+	ctx, cancel := context.WithTimeout(context.Background(), pluginCallTimeout)
+	defer cancel()
+
+	var version pluginVersion
+	if err := callPlugin(ctx, runner, t.path, PluginRequest{Method: PluginMethodVersion}, &version); err != nil {
+		return "", err
+	}
+	if version.Version == "" {
+		return "unknown", nil
+	}
+	return version.Version, nil
+}
+
+// Fingerprint is not supported: the wrapped tool can change while the plugin
+// executable stays the same, so plugin versions are probed on every run.
+func (t *PluginTool) Fingerprint() (string, bool) {
+	return "", false
+}
+
+// Install is not supported; plugins manage their own tools.
+func (t *PluginTool) Install() error {
+	return fmt.Errorf("plugin %s cannot be installed automatically; install the tool it wraps manually", t.name)
+}
+
+// Upgrade is not supported; plugins manage their own tools.
+func (t *PluginTool) Upgrade() error {
+	return fmt.Errorf("plugin %s cannot be upgraded automatically; upgrade the tool it wraps manually", t.name)
+}
+
+// Execute sends an execute request and converts the reported issues.
+func (t *PluginTool) Execute(ctx context.Context, files []string, options ExecuteOptions) (*Result, error) {
+	if !t.isAvailableWith(runnerOf(options)) {
+		return &Result{
+			Tool:     t.name,
+			Language: t.language,
+			Success:  false,
+			Status:   StatusSkipped,
+			ExitCode: -1,
+			Error:    fmt.Sprintf("tool %s is not available", t.name),
+		}, nil
+	}
+
+	request := PluginRequest{
+		Protocol: PluginProtocolVersion,
+		Method:   PluginMethodExecute,
+		Files:    files,
+		Options: &PluginOptions{
+			ProjectRoot: options.ProjectRoot,
+			ConfigFile:  options.ConfigFile,
+			Fix:         options.Fix,
+			FormatOnly:  options.FormatOnly,
+			LintOnly:    options.LintOnly,
+			Check:       options.Check,
+			ExtraArgs:   options.ExtraArgs,
+			Env:         options.Env,
+		},
+	}
+
+	cmd, err := pluginCommand(t.path, request)
+	if err != nil {
+		return nil, err
+	}
+	if options.ProjectRoot != "" {
+		cmd.Dir = options.ProjectRoot
+	}
+
+	result, err := t.ExecuteCommandWith(ctx, runnerOf(options), cmd, files)
+	if err != nil || result.Status != "" {
+		return result, err
+	}
+
+	var execution pluginExecution
+	if err := decodePluginResponse(t.path, []byte(result.Stdout), result.ExitCode, &execution); err != nil {
+		result.Success = false
+		result.Status = StatusToolError
+		result.Error = withStderr(err, result.Stderr).Error()
+		return result, nil
+	}
+
+	for _, issue := range execution.Issues {
+		result.Issues = append(result.Issues, issue.toIssue())
+	}
+	if len(result.Issues) > 0 {
+		result.Status = StatusIssues
+	} else {
+		result.Status = StatusClean
+	}
+	result.Success = true
+	result.Error = ""
+
+	return result, nil
+}
+
+// toIssue converts a plugin issue to an Issue.
+func (i PluginIssue) toIssue() Issue {
+	issue := Issue{
+		File:       i.File,
+		Line:       i.Line,
+		Column:     i.Column,
+		Severity:   i.Severity,
+		Rule:       i.Rule,
+		Message:    i.Message,
+		Suggestion: i.Suggestion,
+	}
+	if issue.Severity == "" {
+		issue.Severity = severityWarning
+	}
+
+	for _, edit := range i.Edits {
+		applicability := Applicability(edit.Applicability)
+		if applicability == "" {
+			applicability = ApplicabilityManual
+		}
+		issue.Edits = append(issue.Edits, TextEdit{
+			File:          edit.File,
+			StartLine:     edit.StartLine,
+			StartColumn:   edit.StartColumn,
+			EndLine:       edit.EndLine,
+			EndColumn:     edit.EndColumn,
+			StartOffset:   edit.StartOffset,
+			EndOffset:     edit.EndOffset,
+			Replacement:   edit.Replacement,
+			Applicability: applicability,
+		})
+	}
+
+	return issue
+}
+
+// callPlugin runs a single request against the plugin through runner and
+// decodes its result.
+func callPlugin(ctx context.Context, runner CommandRunner, path string, request PluginRequest, result interface{}) error {
+	request.Protocol = PluginProtocolVersion
+
+	cmd, err := pluginCommand(path, request)
+	if err != nil {
+		return err
+	}
+
+	output, err := runner.Run(ctx, InvocationOf(cmd))
+	if err != nil && (IsInterrupted(err) || errors.Is(err, ErrNotStarted)) {
+		return fmt.Errorf("plugin %s %s failed: %w", path, request.Method, err)
+	}
+
+	// A non-zero exit is fine as long as the plugin wrote a response
+	if err := decodePluginResponse(path, output.Stdout, output.ExitCode, result); err != nil {
+		return withStderr(err, string(output.Stderr))
+	}
+	return nil
+}
+
+// withStderr appends the plugin's stderr, if any, to err.
+func withStderr(err error, stderr string) error {
+	if message := strings.TrimSpace(stderr); message != "" {
+		return fmt.Errorf("%w: %s", err, message)
+	}
+	return err
+}
+
+// pluginCommand builds the plugin invocation with request on stdin.
+func pluginCommand(path string, request PluginRequest) (*exec.Cmd, error) {
+	payload, err := json.Marshal(request)
+	if err != nil {
+		return nil, fmt.Errorf("failed to encode plugin request: %w", err)
+	}
+
+	cmd := exec.Command(path)
+	cmd.Stdin = bytes.NewReader(append(payload, '\n'))
+	return cmd, nil
+}
+
+// decodePluginResponse checks the response envelope and decodes its result.
+func decodePluginResponse(path string, stdout []byte, exitCode int, result interface{}) error {
+	var response PluginResponse
+	if err := json.Unmarshal(bytes.TrimSpace(stdout), &response); err != nil {
+		return fmt.Errorf("plugin %s returned an invalid response (exit status %d): %w", path, exitCode, err)
+	}
+	if response.Protocol != PluginProtocolVersion {
+		return fmt.Errorf("plugin %s speaks protocol %d, expected %d", path, response.Protocol, PluginProtocolVersion)
+	}
+	if response.Error != "" {
+		return fmt.Errorf("plugin %s: %s", path, response.Error)
+	}
+	if len(response.Result) == 0 {
+		return nil
+	}
+	if err := json.Unmarshal(response.Result, result); err != nil {
+		return fmt.Errorf("plugin %s returned an invalid result: %w", path, err)
+	}
+	return nil
+}
+
+// Ensure PluginTool implements QualityTool interface.
+var (
+	_ QualityTool   = (*PluginTool)(nil)
+	_ VersionRunner = (*PluginTool)(nil)
+)
@@ -0,0 +1,124 @@
+// Copyright (c) 2025 Archmagece
+// SPDX-License-Identifier: MIT
+
+package tools
+
+import (
+	"context"
+	"encoding/json"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/Gizzahub/gzh-cli-quality/internal/testutil"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+// writePlugin writes a fake plugin answering each method with the given response.
+func writePlugin(t *testing.T, dir, name, execute string) string {
+	t.Helper()
+	return testutil.WriteExecutable(t, dir, name, `request=$(cat)
+case "$request" in
+  *'"method":"describe"'*) echo '{"protocol":1,"result":{"name":"sqlcheck","language":"SQL","type":"lint","config_patterns":[".sqlcheck"]}}' ;;
+  *'"method":"is_available"'*) echo '{"protocol":1,"result":{"available":true}}' ;;
+  *'"method":"version"'*) echo '{"protocol":1,"result":{"version":"1.2.3"}}' ;;
+  *'"method":"execute"'*)
+    [ -n "$GZQ_REQUEST_FILE" ] && printf '%s' "$request" > "$GZQ_REQUEST_FILE"
+    echo '`+execute+`' ;;
+esac
+`)
+}
+
+func TestPluginTool_Protocol(t *testing.T) {
+	projectDir := t.TempDir()
+	require.NoError(t, os.WriteFile(filepath.Join(projectDir, ".sqlcheck"), []byte{}, 0o644))
+	requestFile := filepath.Join(t.TempDir(), "request.json")
+	t.Setenv("GZQ_REQUEST_FILE", requestFile)
+
+	path := writePlugin(t, t.TempDir(), "sqlcheck",
+		`{"protocol":1,"result":{"issues":[{"file":"q.sql","line":3,"column":1,"rule":"SC1","message":"select star","edits":[{"file":"q.sql","start_offset":7,"end_offset":8,"replacement":"id","applicability":"unsafe"}]},{"file":"r.sql","message":"m"}]}}`)
+
+	tool, err := NewPluginTool(path)
+	require.NoError(t, err)
+
+	assert.Equal(t, "sqlcheck", tool.Name())
+	assert.Equal(t, "SQL", tool.Language())
+	assert.Equal(t, LINT, tool.Type())
+	assert.Equal(t, path, tool.Path())
+	assert.True(t, tool.IsAvailable())
+	assert.Equal(t, []string{filepath.Join(projectDir, ".sqlcheck")}, tool.FindConfigFiles(projectDir))
+
+	version, err := tool.GetVersion()
+	require.NoError(t, err)
+	assert.Equal(t, "1.2.3", version)
+
+	result, err := tool.Execute(context.Background(), []string{"q.sql", "r.sql"},
+		ExecuteOptions{ProjectRoot: projectDir, Fix: true, ExtraArgs: []string{"--strict"}})
+	require.NoError(t, err)
+
+	assert.Equal(t, StatusIssues, result.Status)
+	assert.True(t, result.Success)
+	assert.Equal(t, 2, result.FilesProcessed)
+	require.Len(t, result.Issues, 2)
+	assert.Equal(t, "SC1", result.Issues[0].Rule)
+	assert.Equal(t, "warning", result.Issues[1].Severity)
+	require.Len(t, result.Issues[0].Edits, 1)
+	assert.Equal(t, TextEdit{File: "q.sql", StartOffset: 7, EndOffset: 8, Replacement: "id", Applicability: ApplicabilityUnsafe}, result.Issues[0].Edits[0])
+
+	// The request carries the files and options
+	data, err := os.ReadFile(requestFile)
+	require.NoError(t, err)
+	var request PluginRequest
+	require.NoError(t, json.Unmarshal(data, &request))
+	assert.Equal(t, PluginProtocolVersion, request.Protocol)
+	assert.Equal(t, PluginMethodExecute, request.Method)
+	assert.Equal(t, []string{"q.sql", "r.sql"}, request.Files)
+	require.NotNil(t, request.Options)
+	assert.Equal(t, PluginOptions{ProjectRoot: projectDir, Fix: true, ExtraArgs: []string{"--strict"}}, *request.Options)
+}
+
+func TestPluginTool_Execute_Responses(t *testing.T) {
+	tests := []struct {
+		name     string
+		response string
+		status   Status
+		errText  string
+	}{
+		{name: "clean", response: `{"protocol":1,"result":{"issues":[]}}`, status: StatusClean},
+		{name: "plugin error", response: `{"protocol":1,"error":"config is broken"}`, status: StatusToolError, errText: "config is broken"},
+		{name: "wrong protocol", response: `{"protocol":2,"result":{}}`, status: StatusToolError, errText: "protocol 2"},
+		{name: "invalid json", response: `not json`, status: StatusToolError, errText: "invalid response"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			tool, err := NewPluginTool(writePlugin(t, t.TempDir(), "plugin", tt.response))
+			require.NoError(t, err)
+
+			result, err := tool.Execute(context.Background(), []string{"a.sql"}, ExecuteOptions{})
+			require.NoError(t, err)
+
+			assert.Equal(t, tt.status, result.Status)
+			assert.Equal(t, !tt.status.IsFailure(), result.Success)
+			if tt.errText != "" {
+				assert.Contains(t, result.Error, tt.errText)
+			}
+		})
+	}
+}
+
+func TestDiscoverPlugins(t *testing.T) {
+	dir := t.TempDir()
+	writePlugin(t, dir, "sqlcheck", `{"protocol":1,"result":{}}`)
+	testutil.WriteExecutable(t, dir, "broken", "echo 'oops' >&2\nexit 1\n")
+	require.NoError(t, os.WriteFile(filepath.Join(dir, "README.md"), []byte("not a plugin"), 0o644))
+
+	plugins, errs := DiscoverPlugins([]string{dir, filepath.Join(dir, "missing")})
+
+	require.Len(t, plugins, 1)
+	assert.Equal(t, "sqlcheck", plugins[0].Name())
+	require.Len(t, errs, 1)
+	assert.Contains(t, errs[0].Error(), "broken")
+	assert.Contains(t, errs[0].Error(), "oops")
+}
@@ -0,0 +1,229 @@
+// Copyright (c) 2025 Archmagece
+// SPDX-License-Identifier: MIT
+
+package tools
+
+import (
+	"encoding/json"
+	"fmt"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"sync"
+)
+
+// probeStoreVersion is the format version of the persistent probe store.
+// Bump it when the way versions are extracted changes.
+const probeStoreVersion = 1
+
+// Fingerprinter is implemented by tools that can identify their installed
+// binary without running it, so a probed version can be reused across runs.
+type Fingerprinter interface {
+	// Fingerprint returns an identifier that changes whenever the installed
+	// tool may have changed, or false if the tool cannot be fingerprinted
+	Fingerprint() (string, bool)
+}
+
+// VersionRunner is implemented by tools whose version probe can run through a
+// CommandRunner.
+type VersionRunner interface {
+	// GetVersionWith returns the installed version like GetVersion
+	GetVersionWith(runner CommandRunner) (string, error)
+}
+
+// Prober answers "is this tool available" and "which version is installed"
+// for one run. Each tool is probed at most once per Prober; with a store
+// file, versions are also reused across runs while the tool's fingerprint
+// is unchanged, so later runs do not start the tool at all.
+type Prober struct {
+	storePath string
+	runner    CommandRunner
+
+	mu     sync.Mutex
+	probes map[string]*probe
+	stored map[string]storedProbe
+}
+
+// probe holds the memoized answers for one tool.
+type probe struct {
+	availableOnce sync.Once
+	available     bool
+
+	versionOnce sync.Once
+	version     string
+	err         error
+}
+
+// storedProbe is a version persisted together with the fingerprint it was probed at.
+type storedProbe struct {
+	Fingerprint string `json:"fingerprint"`
+	Version     string `json:"version"`
+}
+
+// probeStore is the format of the persistent probe store.
+type probeStore struct {
+	Version int                    `json:"version"`
+	Tools   map[string]storedProbe `json:"tools"`
+}
+
+// NewProber creates a prober. storePath is the file versions are persisted
+// in; empty means probes are only memoized for the lifetime of the Prober.
+func NewProber(storePath string) *Prober {
+	return &Prober{
+		storePath: storePath,
+		probes:    make(map[string]*probe),
+	}
+}
+
+// IsAvailable reports whether the tool is installed.
+func (p *Prober) IsAvailable(tool QualityTool) bool {
+	entry := p.entry(tool.Name())
+	entry.availableOnce.Do(func() {
+		entry.available = tool.IsAvailable()
+	})
+	return entry.available
+}
+
+// Version returns the installed version of the tool.
+func (p *Prober) Version(tool QualityTool) (string, error) {
+	entry := p.entry(tool.Name())
+	entry.versionOnce.Do(func() {
+		if !p.IsAvailable(tool) {
+			entry.err = fmt.Errorf("tool %s is not installed", tool.Name())
+			return
+		}
+		entry.version, entry.err = p.probeVersion(tool)
+	})
+	return entry.version, entry.err
+}
+
+// SetRunner sets the runner version probes of VersionRunner tools run
+// through. Versions probed with a runner are not persisted.
+func (p *Prober) SetRunner(runner CommandRunner) {
+	p.runner = runner
+}
+
+// Forget drops what is known about a tool, e.g. after installing or upgrading it.
+func (p *Prober) Forget(name string) {
+	p.mu.Lock()
+	defer p.mu.Unlock()
+	delete(p.probes, name)
+}
+
+// entry returns the memoized probe of a tool, creating it on first use.
+func (p *Prober) entry(name string) *probe {
+	p.mu.Lock()
+	defer p.mu.Unlock()
+
+	entry, ok := p.probes[name]
+	if !ok {
+		entry = &probe{}
+		p.probes[name] = entry
+	}
+	return entry
+}
+
+// probeVersion reuses the stored version while the fingerprint matches and
+// otherwise runs the tool and stores the result.
+func (p *Prober) probeVersion(tool QualityTool) (string, error) {
+	if versioned, ok := tool.(VersionRunner); ok && p.runner != nil {
+		return versioned.GetVersionWith(p.runner)
+	}
+
+	fingerprinter, ok := tool.(Fingerprinter)
+	if !ok || p.storePath == "" {
+		return tool.GetVersion()
+	}
+	fingerprint, ok := fingerprinter.Fingerprint()
+	if !ok {
+		return tool.GetVersion()
+	}
+
+	p.mu.Lock()
+	p.loadStore()
+	stored, found := p.stored[tool.Name()]
+	p.mu.Unlock()
+	if found && stored.Fingerprint == fingerprint {
+		return stored.Version, nil
+	}
+
+	version, err := tool.GetVersion()
+	if err != nil {
+		return "", err
+	}
+
+	p.mu.Lock()
+	p.stored[tool.Name()] = storedProbe{Fingerprint: fingerprint, Version: version}
+	p.saveStore()
+	p.mu.Unlock()
+
+	return version, nil
+}
+
+// loadStore reads the store file once. An unreadable or outdated store is
+// treated as empty. The caller holds p.mu.
+func (p *Prober) loadStore() {
+	if p.stored != nil {
+		return
+	}
+	p.stored = make(map[string]storedProbe)
+
+	data, err := os.ReadFile(p.storePath)
+	if err != nil {
+		return
+	}
+	var store probeStore
+	if json.Unmarshal(data, &store) != nil || store.Version != probeStoreVersion {
+		return
+	}
+	for name, stored := range store.Tools {
+		p.stored[name] = stored
+	}
+}
+
+// saveStore writes the store file; failures only cost a later re-probe.
+// The caller holds p.mu.
+func (p *Prober) saveStore() {
+	data, err := json.MarshalIndent(probeStore{Version: probeStoreVersion, Tools: p.stored}, "", "  ")
+	if err != nil {
+		return
+	}
+	if err := os.MkdirAll(filepath.Dir(p.storePath), 0o755); err != nil {
+		return
+	}
+
+	// Write then rename so concurrent runs never read a partial file
+	tmp := fmt.Sprintf("%s.%d.tmp", p.storePath, os.Getpid())
+	if err := os.WriteFile(tmp, data, 0o644); err != nil {
+		return
+	}
+	if err := os.Rename(tmp, p.storePath); err != nil {
+		_ = os.Remove(tmp)
+	}
+}
+
+// Fingerprint identifies the installed executable by its resolved path,
+// modification time and size.
+func (t *BaseTool) Fingerprint() (string, bool) {
+	return fileFingerprint(t.executable)
+}
+
+// fileFingerprint returns "<path>|<mtime>|<size>" of an executable on PATH.
+func fileFingerprint(executable string) (string, bool) {
+	path, err := exec.LookPath(executable)
+	if err != nil {
+		return "", false
+	}
+	if resolved, err := filepath.EvalSymlinks(path); err == nil {
+		path = resolved
+	}
+	if abs, err := filepath.Abs(path); err == nil {
+		path = abs
+	}
+
+	info, err := os.Stat(path)
+	if err != nil {
+		return "", false
+	}
+	return fmt.Sprintf("%s|%d|%d", path, info.ModTime().UnixNano(), info.Size()), true
+}
@@ -0,0 +1,149 @@
+// Copyright (c) 2025 Archmagece
+// SPDX-License-Identifier: MIT
+
+package tools
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"sync"
+	"testing"
+
+	"github.com/Gizzahub/gzh-cli-quality/internal/testutil"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+// writeCountingTool installs a fake tool that records every invocation in calls.
+func writeCountingTool(t *testing.T, dir, calls, version string) {
+	t.Helper()
+	testutil.WriteExecutable(t, dir, "probed", "echo run >> "+calls+"\necho 'probed "+version+"'\n")
+}
+
+func countCalls(t *testing.T, calls string) int {
+	t.Helper()
+	data, err := os.ReadFile(calls)
+	if os.IsNotExist(err) {
+		return 0
+	}
+	require.NoError(t, err)
+	return strings.Count(string(data), "run")
+}
+
+func TestProber_Version(t *testing.T) {
+	binDir := t.TempDir()
+	calls := filepath.Join(t.TempDir(), "calls")
+	store := filepath.Join(t.TempDir(), "cache", "tool-versions.json")
+	writeCountingTool(t, binDir, calls, "1.2.3")
+	t.Setenv("PATH", binDir)
+
+	tool := NewBaseTool("probed", "Go", "probed", LINT)
+
+	// Concurrent callers share one probe
+	prober := NewProber(store)
+	var wg sync.WaitGroup
+	for i := 0; i < 8; i++ {
+		wg.Add(1)
+		go func() {
+			defer wg.Done()
+			version, err := prober.Version(tool)
+			assert.NoError(t, err)
+			assert.Equal(t, "1.2.3", version)
+		}()
+	}
+	wg.Wait()
+	assert.True(t, prober.IsAvailable(tool))
+	assert.Equal(t, 1, countCalls(t, calls))
+
+	// A later run reuses the stored version without starting the tool
+	version, err := NewProber(store).Version(tool)
+	require.NoError(t, err)
+	assert.Equal(t, "1.2.3", version)
+	assert.Equal(t, 1, countCalls(t, calls))
+
+	// Replacing the binary changes its fingerprint
+	writeCountingTool(t, binDir, calls, "1.10.0")
+	version, err = NewProber(store).Version(tool)
+	require.NoError(t, err)
+	assert.Equal(t, "1.10.0", version)
+	assert.Equal(t, 2, countCalls(t, calls))
+
+	// Forget drops the memoized answer
+	prober.Forget("probed")
+	version, err = prober.Version(tool)
+	require.NoError(t, err)
+	assert.Equal(t, "1.10.0", version)
+}
+
+func TestProber_NotInstalled(t *testing.T) {
+	t.Setenv("PATH", t.TempDir())
+	tool := NewBaseTool("probed", "Go", "probed", LINT)
+
+	prober := NewProber("")
+	assert.False(t, prober.IsAvailable(tool))
+	_, err := prober.Version(tool)
+	assert.ErrorContains(t, err, "not installed")
+}
+
+func TestProber_WithoutStore(t *testing.T) {
+	binDir := t.TempDir()
+	calls := filepath.Join(t.TempDir(), "calls")
+	writeCountingTool(t, binDir, calls, "0.4.1")
+	t.Setenv("PATH", binDir)
+	tool := NewBaseTool("probed", "Go", "probed", LINT)
+
+	for i := 0; i < 2; i++ {
+		version, err := NewProber("").Version(tool)
+		require.NoError(t, err)
+		assert.Equal(t, "0.4.1", version)
+	}
+
+	// Without a store every prober probes once
+	assert.Equal(t, 2, countCalls(t, calls))
+}
+
+func TestProber_WithRunner(t *testing.T) {
+	binDir := t.TempDir()
+	calls := filepath.Join(t.TempDir(), "calls")
+	store := filepath.Join(t.TempDir(), "tool-versions.json")
+	writeCountingTool(t, binDir, calls, "0.4.1")
+	t.Setenv("PATH", binDir)
+	tool := NewBaseTool("probed", "Go", "probed", LINT)
+
+	runner := NewDryRunRunner(nil)
+	prober := NewProber(store)
+	prober.SetRunner(runner)
+	version, err := prober.Version(tool)
+	require.NoError(t, err)
+
+	// The probe goes through the runner and is not persisted
+	assert.Equal(t, "unknown", version)
+	assert.NotEmpty(t, runner.Invocations())
+	assert.Equal(t, 0, countCalls(t, calls))
+	assert.NoFileExists(t, store)
+}
+
+func TestBaseTool_Fingerprint(t *testing.T) {
+	binDir := t.TempDir()
+	path := testutil.WriteExecutable(t, binDir, "probed", "exit 0\n")
+	t.Setenv("PATH", binDir)
+
+	fingerprint, ok := NewBaseTool("probed", "Go", "probed", LINT).Fingerprint()
+	require.True(t, ok)
+	assert.True(t, strings.HasPrefix(fingerprint, path+"|"), fingerprint)
+
+	_, ok = NewBaseTool("missing", "Go", "missing", LINT).Fingerprint()
+	assert.False(t, ok)
+
+	// Rust tools also depend on the selected rustup toolchain
+	testutil.WriteExecutable(t, binDir, "cargo", "exit 0\n")
+	t.Setenv("RUSTUP_HOME", t.TempDir())
+	t.Setenv("RUSTUP_TOOLCHAIN", "stable")
+	stable, ok := NewClippyTool().Fingerprint()
+	require.True(t, ok)
+	t.Setenv("RUSTUP_TOOLCHAIN", "nightly")
+	nightly, ok := NewClippyTool().Fingerprint()
+	require.True(t, ok)
+	assert.NotEqual(t, stable, nightly)
+}
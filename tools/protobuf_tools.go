@@ -20,7 +20,10 @@ func NewBufTool() *BufTool {
 		BaseTool: NewBaseTool("buf", "Protobuf", "buf", BOTH),
 	}
 
-	tool.SetInstallCommand([]string{"go", "install", "github.com/bufbuild/buf/cmd/buf@latest"})
+	tool.Bind(tool)
+	// buf exits 100 on lint findings or format diffs
+	tool.SetFindingExitCodes(100)
+	tool.AddPackage(PackageGo, "github.com/bufbuild/buf/cmd/buf")
 	tool.SetConfigPatterns([]string{"buf.yaml", "buf.gen.yaml"})
 
 	return tool
@@ -22,7 +22,10 @@ func NewBlackTool() *BlackTool {
 		BaseTool: NewBaseTool("black", "Python", "black", FORMAT),
 	}
 
-	tool.SetInstallCommand([]string{"uv", "tool", "install", "black"})
+	tool.Bind(tool)
+	// black --check exits 1 when files would be reformatted (123 is an internal error)
+	tool.SetFindingExitCodes(1)
+	tool.AddPackage(PackagePip, "black")
 	tool.SetConfigPatterns([]string{"pyproject.toml", ".black", "black.toml"})
 
 	return tool
@@ -40,6 +43,11 @@ func (t *BlackTool) BuildCommand(files []string, options ExecuteOptions) *exec.C
 	// Add line length if not in config
 	args = append(args, "--line-length", "88") // black default
 
+	// Report a diff instead of rewriting files
+	if options.Check {
+		args = append(args, "--check", "--diff")
+	}
+
 	// Add extra flags if provided
 	args = append(args, options.ExtraArgs...)
 
@@ -61,6 +69,16 @@ func (t *BlackTool) BuildCommand(files []string, options ExecuteOptions) *exec.C
 	return cmd
 }
 
+// ParseOutput parses black --diff output into one issue per unformatted file.
+func (t *BlackTool) ParseOutput(output string) []Issue {
+	return ParseUnifiedDiff(output, "file would be reformatted by black")
+}
+
+// SupportsCheck reports that black can verify formatting without writing.
+func (t *BlackTool) SupportsCheck() bool {
+	return true
+}
+
 // RuffTool implements Python linting and formatting using ruff.
 type RuffTool struct {
 	*BaseTool
@@ -72,7 +90,10 @@ func NewRuffTool() *RuffTool {
 		BaseTool: NewBaseTool("ruff", "Python", "ruff", BOTH),
 	}
 
-	tool.SetInstallCommand([]string{"uv", "tool", "install", "ruff"})
+	tool.Bind(tool)
+	// ruff exits 1 on violations (2 is an error)
+	tool.SetFindingExitCodes(1)
+	tool.AddPackage(PackagePip, "ruff")
 	tool.SetConfigPatterns([]string{"ruff.toml", ".ruff.toml", "pyproject.toml"})
 
 	return tool
@@ -99,6 +120,11 @@ func (t *RuffTool) BuildCommand(files []string, options ExecuteOptions) *exec.Cm
 		args = append(args, "--fix")
 	}
 
+	// ruff format --diff reports changes without writing them
+	if options.Check && options.FormatOnly {
+		args = append(args, "--diff")
+	}
+
 	// Output format for parsing
 	if !options.FormatOnly {
 		args = append(args, "--output-format", "json")
@@ -132,6 +158,8 @@ func (t *RuffTool) Execute(ctx context.Context, files []string, options ExecuteO
 			Tool:     t.name,
 			Language: t.language,
 			Success:  false,
+			Status:   StatusSkipped,
+			ExitCode: -1,
 			Error:    fmt.Sprintf("tool %s is not available", t.name),
 		}, nil
 	}
@@ -159,10 +187,17 @@ func (t *RuffTool) Execute(ctx context.Context, files []string, options ExecuteO
 			Tool:           t.name,
 			Language:       t.language,
 			Success:        formatResult.Success && lintResult.Success,
+			Status:         WorseStatus(formatResult.Status, lintResult.Status),
+			ExitCode:       lintResult.ExitCode,
 			FilesProcessed: formatResult.FilesProcessed,
 			Duration:       formatResult.Duration + lintResult.Duration,
-			Issues:         lintResult.Issues,
+			Issues:         append(formatResult.Issues, lintResult.Issues...),
 			Output:         formatResult.Output + "\n" + lintResult.Output,
+			Stdout:         formatResult.Stdout + lintResult.Stdout,
+			Stderr:         formatResult.Stderr + lintResult.Stderr,
+		}
+		if formatResult.ExitCode != 0 {
+			combinedResult.ExitCode = formatResult.ExitCode
 		}
 
 		if !combinedResult.Success {
@@ -182,15 +217,19 @@ func (t *RuffTool) Execute(ctx context.Context, files []string, options ExecuteO
 // executeMode executes ruff in a specific mode.
 func (t *RuffTool) executeMode(ctx context.Context, files []string, options ExecuteOptions) (*Result, error) {
 	cmd := t.BuildCommand(files, options)
-	result, err := t.ExecuteCommand(ctx, cmd, files)
+	result, err := t.ExecuteCommandWith(ctx, runnerOf(options), cmd, files)
 	if err != nil {
 		return result, err
 	}
 
-	// Parse output for issues if linting and the tool found issues
-	if !options.FormatOnly && result.Output != "" {
-		result.Issues = t.ParseOutput(result.Output)
+	// Parse format diffs in check mode and lint findings otherwise
+	switch {
+	case options.FormatOnly && options.Check:
+		result.Issues = ParseUnifiedDiff(result.Stdout, "file would be reformatted by ruff")
+	case !options.FormatOnly && result.Output != "":
+		t.parseIssues(t, result)
 	}
+	t.ClassifyResult(result)
 
 	return result, nil
 }
@@ -214,7 +253,20 @@ func (t *RuffTool) ParseOutput(output string) []Issue {
 			Column int `json:"column"`
 		} `json:"end_location"`
 		Fix *struct {
-			Content string `json:"content"`
+			Content       string `json:"content"`
+			Message       string `json:"message"`
+			Applicability string `json:"applicability"`
+			Edits         []struct {
+				Content  string `json:"content"`
+				Location struct {
+					Row    int `json:"row"`
+					Column int `json:"column"`
+				} `json:"location"`
+				EndLocation struct {
+					Row    int `json:"row"`
+					Column int `json:"column"`
+				} `json:"end_location"`
+			} `json:"edits"`
 		} `json:"fix,omitempty"`
 	}
 
@@ -236,6 +288,22 @@ func (t *RuffTool) ParseOutput(output string) []Issue {
 
 		if item.Fix != nil {
 			issue.Suggestion = item.Fix.Content
+			if item.Fix.Message != "" {
+				issue.Suggestion = item.Fix.Message
+			}
+
+			applicability := ruffApplicability(item.Fix.Applicability)
+			for _, e := range item.Fix.Edits {
+				issue.Edits = append(issue.Edits, TextEdit{
+					File:          item.Filename,
+					StartLine:     e.Location.Row,
+					StartColumn:   e.Location.Column,
+					EndLine:       e.EndLocation.Row,
+					EndColumn:     e.EndLocation.Column,
+					Replacement:   e.Content,
+					Applicability: applicability,
+				})
+			}
 		}
 
 		issues = append(issues, issue)
@@ -244,11 +312,29 @@ func (t *RuffTool) ParseOutput(output string) []Issue {
 	return issues
 }
 
+// ruffApplicability maps ruff's fix applicability, including the names used
+// before ruff 0.1 (Automatic, Suggested, Manual).
+func ruffApplicability(value string) Applicability {
+	switch strings.ToLower(value) {
+	case "safe", "automatic":
+		return ApplicabilitySafe
+	case "unsafe", "suggested":
+		return ApplicabilityUnsafe
+	default:
+		return ApplicabilityManual
+	}
+}
+
 // parseTextOutput parses plain text output as fallback.
 func (t *RuffTool) parseTextOutput(output string) []Issue {
 	return ParseTextLines(output, RuffParseConfig)
 }
 
+// SupportsCheck reports that ruff can verify formatting without writing.
+func (t *RuffTool) SupportsCheck() bool {
+	return true
+}
+
 // PylintTool implements Python linting using pylint.
 type PylintTool struct {
 	*BaseTool
@@ -260,12 +346,26 @@ func NewPylintTool() *PylintTool {
 		BaseTool: NewBaseTool("pylint", "Python", "pylint", LINT),
 	}
 
-	tool.SetInstallCommand([]string{"uv", "tool", "install", "pylint"})
+	tool.Bind(tool)
+	// pylint exit code is a bitmask of message categories
+	tool.SetFindingExitCodes(pylintFindingExitCodes()...)
+	tool.AddPackage(PackagePip, "pylint")
 	tool.SetConfigPatterns([]string{".pylintrc", "pylint.cfg", "pyproject.toml"})
 
 	return tool
 }
 
+// pylintFindingExitCodes returns every pylint exit code made only of message
+// bits (2 error, 4 warning, 8 refactor, 16 convention). Bit 1 (fatal) and
+// bit 32 (usage error) mean pylint itself failed.
+func pylintFindingExitCodes() []int {
+	var codes []int
+	for code := 2; code <= 30; code += 2 {
+		codes = append(codes, code)
+	}
+	return codes
+}
+
 // BuildCommand builds the pylint command.
 func (t *PylintTool) BuildCommand(files []string, options ExecuteOptions) *exec.Cmd {
 	args := []string{}
@@ -355,7 +455,10 @@ func NewMypyTool() *MypyTool {
 		BaseTool: NewBaseTool("mypy", "Python", "mypy", LINT),
 	}
 
-	tool.SetInstallCommand([]string{"uv", "tool", "install", "mypy"})
+	tool.Bind(tool)
+	// mypy exits 1 on type errors (2 is a crash or usage error)
+	tool.SetFindingExitCodes(1)
+	tool.AddPackage(PackagePip, "mypy")
 	tool.SetConfigPatterns([]string{"mypy.ini", ".mypy.ini", "pyproject.toml", "setup.cfg"})
 
 	return tool
@@ -444,7 +547,10 @@ func NewBanditTool() *BanditTool {
 		BaseTool: NewBaseTool("bandit", "Python", "bandit", LINT),
 	}
 
-	tool.SetInstallCommand([]string{"uv", "tool", "install", "bandit"})
+	tool.Bind(tool)
+	// bandit exits 1 when issues are found
+	tool.SetFindingExitCodes(1)
+	tool.AddPackage(PackagePip, "bandit")
 	tool.SetConfigPatterns([]string{".bandit", "bandit.yaml", "pyproject.toml"})
 
 	return tool
@@ -523,4 +629,7 @@ var (
 	_ QualityTool = (*PylintTool)(nil)
 	_ QualityTool = (*MypyTool)(nil)
 	_ QualityTool = (*BanditTool)(nil)
+
+	_ FormatChecker = (*BlackTool)(nil)
+	_ FormatChecker = (*RuffTool)(nil)
 )
@@ -65,6 +65,14 @@ func TestBlackTool_BuildCommand(t *testing.T) {
 			options: ExecuteOptions{},
 			expectedArgs: []string{"."},
 		},
+		{
+			name:  "check mode",
+			files: []string{"main.py"},
+			options: ExecuteOptions{
+				Check: true,
+			},
+			expectedArgs: []string{"--check", "--diff", "main.py"},
+		},
 	}
 
 	for _, tt := range tests {
@@ -163,6 +171,17 @@ func TestRuffTool_BuildCommand(t *testing.T) {
 	}
 }
 
+func TestRuffTool_BuildCommand_Check(t *testing.T) {
+	tool := NewRuffTool()
+
+	cmd := tool.BuildCommand([]string{"main.py"}, ExecuteOptions{FormatOnly: true, Check: true})
+	assert.Equal(t, []string{"format", "--diff", "main.py"}, cmd.Args[1:])
+
+	// Linting is already read-only
+	cmd = tool.BuildCommand([]string{"main.py"}, ExecuteOptions{LintOnly: true, Check: true})
+	assert.NotContains(t, cmd.Args, "--diff")
+}
+
 func TestRuffTool_ParseOutput(t *testing.T) {
 	tool := NewRuffTool()
 
@@ -228,6 +247,37 @@ func TestRuffTool_ParseOutput(t *testing.T) {
 				assert.Equal(t, "", issue.Suggestion)
 			},
 		},
+		{
+			name: "with fix edits",
+			output: `[
+				{
+					"code": "F401",
+					"message": "os imported but unused",
+					"filename": "main.py",
+					"location": {"row": 1, "column": 8},
+					"end_location": {"row": 1, "column": 10},
+					"fix": {
+						"applicability": "safe",
+						"message": "Remove unused import: os",
+						"edits": [
+							{"content": "", "location": {"row": 1, "column": 1}, "end_location": {"row": 2, "column": 1}}
+						]
+					}
+				}
+			]`,
+			expected: 1,
+			checkIssue: func(t *testing.T, issue Issue) {
+				assert.Equal(t, "Remove unused import: os", issue.Suggestion)
+				assert.Equal(t, []TextEdit{{
+					File:          "main.py",
+					StartLine:     1,
+					StartColumn:   1,
+					EndLine:       2,
+					EndColumn:     1,
+					Applicability: ApplicabilitySafe,
+				}}, issue.Edits)
+			},
+		},
 	}
 
 	for _, tt := range tests {
@@ -0,0 +1,328 @@
+// Copyright (c) 2025 Archmagece
+// SPDX-License-Identifier: MIT
+
+package tools
+
+import (
+	"bytes"
+	"context"
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"strings"
+	"sync"
+	"time"
+)
+
+// ErrNotStarted is matched (errors.Is) by run errors of processes that never
+// started, such as a missing executable or an invocation without a recording.
+var ErrNotStarted = errors.New("command not started")
+
+// Invocation is a single tool process to run.
+type Invocation struct {
+	// Args is the argv, starting with the executable
+	Args []string
+
+	// Env is the complete environment; nil inherits the current process's
+	Env []string
+
+	// Dir is the working directory; empty means the current directory
+	Dir string
+
+	// Stdin is written to the process's standard input
+	Stdin []byte
+}
+
+// RunOutput is what a run of an Invocation produced.
+type RunOutput struct {
+	Stdout   []byte
+	Stderr   []byte
+	ExitCode int
+	Duration time.Duration
+}
+
+// CommandRunner runs tool invocations. Like exec.Cmd.Run, Run returns an
+// error for a non-zero exit, the context error when ctx ends the run, and an
+// error matching ErrNotStarted when there was no process; the output is
+// non-nil in every case.
+type CommandRunner interface {
+	Run(ctx context.Context, invocation Invocation) (*RunOutput, error)
+}
+
+// InvocationOf describes the process cmd would start. A reader set as
+// cmd.Stdin is consumed.
+func InvocationOf(cmd *exec.Cmd) Invocation {
+	invocation := Invocation{
+		Args: append([]string{}, cmd.Args...),
+		Env:  cmd.Env,
+		Dir:  cmd.Dir,
+	}
+	if cmd.Stdin != nil {
+		invocation.Stdin, _ = io.ReadAll(cmd.Stdin)
+	}
+	return invocation
+}
+
+// String renders the invocation as a shell command line.
+func (i Invocation) String() string {
+	args := make([]string, len(i.Args))
+	for n, arg := range i.Args {
+		args[n] = shellQuote(arg)
+	}
+	line := strings.Join(args, " ")
+	if i.Dir != "" {
+		line = "cd " + shellQuote(i.Dir) + " && " + line
+	}
+	return line
+}
+
+// shellQuote quotes s for a POSIX shell when it contains special characters.
+func shellQuote(s string) string {
+	if s != "" && strings.Trim(s, "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789-_=+./:,@%") == "" {
+		return s
+	}
+	return "'" + strings.ReplaceAll(s, "'", `'\''`) + "'"
+}
+
+// runnerOf returns the runner set in options, or a LocalRunner.
+func runnerOf(options ExecuteOptions) CommandRunner {
+	if options.Runner != nil {
+		return options.Runner
+	}
+	return LocalRunner{}
+}
+
+// exitError is the error of a non-zero exit reported by a runner that did not
+// start a process itself.
+func exitError(code int) error {
+	return fmt.Errorf("exit status %d", code)
+}
+
+// notStartedError wraps the reason a process never started.
+type notStartedError struct {
+	err error
+}
+
+func (e *notStartedError) Error() string   { return e.err.Error() }
+func (e *notStartedError) Unwrap() []error { return []error{ErrNotStarted, e.err} }
+
+// LocalRunner runs invocations as local processes. A cancelled process
+// receives SIGTERM and, after a grace period, its whole process group is killed.
+type LocalRunner struct{}
+
+// Run starts the invocation and waits for it.
+func (LocalRunner) Run(ctx context.Context, invocation Invocation) (*RunOutput, error) {
+	if len(invocation.Args) == 0 {
+		return &RunOutput{ExitCode: -1}, &notStartedError{errors.New("empty command")}
+	}
+
+	startTime := time.Now()
+	cmd := exec.Command(invocation.Args[0], invocation.Args[1:]...)
+	cmd.Env = invocation.Env
+	cmd.Dir = invocation.Dir
+	if invocation.Stdin != nil {
+		cmd.Stdin = bytes.NewReader(invocation.Stdin)
+	}
+
+	stdout, stderr, err := runCommand(ctx, cmd)
+	output := &RunOutput{
+		Stdout:   stdout,
+		Stderr:   stderr,
+		ExitCode: exitCodeOf(cmd),
+		Duration: time.Since(startTime),
+	}
+	if err != nil && !IsInterrupted(err) && cmd.ProcessState == nil {
+		err = &notStartedError{err}
+	}
+	return output, err
+}
+
+// DryRunRunner prints each invocation's exact command line instead of
+// running it, and remembers the invocations. Every run succeeds without output.
+type DryRunRunner struct {
+	out io.Writer
+
+	mu          sync.Mutex
+	invocations []Invocation
+}
+
+// NewDryRunRunner creates a dry-run runner printing to out; nil prints nothing.
+func NewDryRunRunner(out io.Writer) *DryRunRunner {
+	return &DryRunRunner{out: out}
+}
+
+// Run records and prints the invocation.
+func (r *DryRunRunner) Run(ctx context.Context, invocation Invocation) (*RunOutput, error) {
+	if err := ctx.Err(); err != nil {
+		return &RunOutput{ExitCode: -1}, err
+	}
+
+	r.mu.Lock()
+	defer r.mu.Unlock()
+
+	r.invocations = append(r.invocations, invocation)
+	if r.out != nil {
+		fmt.Fprintln(r.out, invocation.String())
+	}
+	return &RunOutput{}, nil
+}
+
+// Invocations returns the invocations run so far, in order.
+func (r *DryRunRunner) Invocations() []Invocation {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+
+	return append([]Invocation{}, r.invocations...)
+}
+
+// recordingRootPlaceholder stands for the recording root in fixtures.
+const recordingRootPlaceholder = "@ROOT@"
+
+// recordedInvocation is the fixture format of one recorded run.
+type recordedInvocation struct {
+	Args     []string `json:"args"`
+	Dir      string   `json:"dir,omitempty"`
+	Stdin    string   `json:"stdin,omitempty"`
+	Stdout   string   `json:"stdout"`
+	Stderr   string   `json:"stderr"`
+	ExitCode int      `json:"exit_code"`
+}
+
+// RecordReplayRunner stores tool invocations and their output as JSON
+// fixtures, and replays them for deterministic tests. Fixtures are keyed by
+// argv, working directory and stdin; the environment is not part of the key.
+// Occurrences of the root directory are stored as "@ROOT@", so fixtures
+// recorded in one temporary project replay in another.
+type RecordReplayRunner struct {
+	dir    string
+	root   string
+	record CommandRunner
+}
+
+// NewRecordingRunner creates a runner that runs invocations with runner (nil
+// means a LocalRunner) and writes each one to a fixture in dir.
+func NewRecordingRunner(dir, root string, runner CommandRunner) *RecordReplayRunner {
+	if runner == nil {
+		runner = LocalRunner{}
+	}
+	return &RecordReplayRunner{dir: dir, root: root, record: runner}
+}
+
+// NewReplayRunner creates a runner that answers invocations from the fixtures
+// in dir without starting any process.
+func NewReplayRunner(dir, root string) *RecordReplayRunner {
+	return &RecordReplayRunner{dir: dir, root: root}
+}
+
+// Run records or replays the invocation.
+func (r *RecordReplayRunner) Run(ctx context.Context, invocation Invocation) (*RunOutput, error) {
+	recording := recordedInvocation{
+		Args:  make([]string, len(invocation.Args)),
+		Dir:   r.abstract(invocation.Dir),
+		Stdin: r.abstract(string(invocation.Stdin)),
+	}
+	for n, arg := range invocation.Args {
+		recording.Args[n] = r.abstract(arg)
+	}
+	path := filepath.Join(r.dir, recording.fixtureName())
+
+	if r.record == nil {
+		return r.replay(ctx, path, invocation)
+	}
+
+	output, err := r.record.Run(ctx, invocation)
+	if err != nil && (IsInterrupted(err) || errors.Is(err, ErrNotStarted)) {
+		return output, err
+	}
+
+	recording.Stdout = r.abstract(string(output.Stdout))
+	recording.Stderr = r.abstract(string(output.Stderr))
+	recording.ExitCode = output.ExitCode
+	if writeErr := writeRecording(path, recording); writeErr != nil {
+		return output, fmt.Errorf("failed to record %s: %w", invocation, writeErr)
+	}
+	return output, err
+}
+
+// replay answers the invocation from its fixture.
+func (r *RecordReplayRunner) replay(ctx context.Context, path string, invocation Invocation) (*RunOutput, error) {
+	if err := ctx.Err(); err != nil {
+		return &RunOutput{ExitCode: -1}, err
+	}
+
+	data, err := os.ReadFile(path)
+	if err != nil {
+		return &RunOutput{ExitCode: -1}, &notStartedError{fmt.Errorf("no recording of %s: %w", invocation, err)}
+	}
+
+	var recording recordedInvocation
+	if err := json.Unmarshal(data, &recording); err != nil {
+		return &RunOutput{ExitCode: -1}, &notStartedError{fmt.Errorf("invalid recording %s: %w", path, err)}
+	}
+
+	output := &RunOutput{
+		Stdout:   []byte(r.concrete(recording.Stdout)),
+		Stderr:   []byte(r.concrete(recording.Stderr)),
+		ExitCode: recording.ExitCode,
+	}
+	if recording.ExitCode != 0 {
+		return output, exitError(recording.ExitCode)
+	}
+	return output, nil
+}
+
+// abstract replaces the root directory with its placeholder.
+func (r *RecordReplayRunner) abstract(s string) string {
+	if r.root == "" {
+		return s
+	}
+	return strings.ReplaceAll(s, r.root, recordingRootPlaceholder)
+}
+
+// concrete replaces the placeholder with the root directory.
+func (r *RecordReplayRunner) concrete(s string) string {
+	if r.root == "" {
+		return s
+	}
+	return strings.ReplaceAll(s, recordingRootPlaceholder, r.root)
+}
+
+// fixtureName names the fixture after the executable and a digest of the
+// invocation key, e.g. "cargo-3f2a9c1d4e5b6a70.json".
+func (r recordedInvocation) fixtureName() string {
+	hasher := sha256.New()
+	for _, arg := range r.Args {
+		fmt.Fprintf(hasher, "arg:%s\x00", arg)
+	}
+	fmt.Fprintf(hasher, "dir:%s\x00stdin:%s", r.Dir, r.Stdin)
+
+	name := "command"
+	if len(r.Args) > 0 {
+		name = filepath.Base(r.Args[0])
+	}
+	return name + "-" + hex.EncodeToString(hasher.Sum(nil))[:16] + ".json"
+}
+
+// writeRecording writes a fixture, creating its directory as needed.
+func writeRecording(path string, recording recordedInvocation) error {
+	data, err := json.MarshalIndent(recording, "", "  ")
+	if err != nil {
+		return err
+	}
+	if err := os.MkdirAll(filepath.Dir(path), 0o755); err != nil {
+		return err
+	}
+	return os.WriteFile(path, append(data, '\n'), 0o644)
+}
+
+var (
+	_ CommandRunner = LocalRunner{}
+	_ CommandRunner = (*DryRunRunner)(nil)
+	_ CommandRunner = (*RecordReplayRunner)(nil)
+)
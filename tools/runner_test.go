@@ -0,0 +1,206 @@
+// Copyright (c) 2025 Archmagece
+// SPDX-License-Identifier: MIT
+
+package tools
+
+import (
+	"bytes"
+	"context"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/Gizzahub/gzh-cli-quality/internal/testutil"
+)
+
+func TestLocalRunner_Run(t *testing.T) {
+	dir := t.TempDir()
+
+	output, err := LocalRunner{}.Run(context.Background(), Invocation{
+		Args:  []string{"sh", "-c", `read line; echo "$line $GREETING $(pwd)"; echo err >&2; exit 2`},
+		Env:   []string{"GREETING=hi"},
+		Dir:   dir,
+		Stdin: []byte("hello\n"),
+	})
+
+	require.Error(t, err)
+	assert.NotErrorIs(t, err, ErrNotStarted)
+	resolved, _ := filepath.EvalSymlinks(dir)
+	assert.Contains(t, []string{"hello hi " + dir + "\n", "hello hi " + resolved + "\n"}, string(output.Stdout))
+	assert.Equal(t, "err\n", string(output.Stderr))
+	assert.Equal(t, 2, output.ExitCode)
+	assert.Positive(t, output.Duration)
+}
+
+func TestLocalRunner_Run_NotStarted(t *testing.T) {
+	output, err := LocalRunner{}.Run(context.Background(), Invocation{Args: []string{"nonexistent-tool-xyz"}})
+
+	require.ErrorIs(t, err, ErrNotStarted)
+	assert.Equal(t, -1, output.ExitCode)
+
+	tool := NewBaseTool("missing", "Go", "nonexistent-tool-xyz", LINT)
+	result, err := tool.ExecuteCommandWith(context.Background(), LocalRunner{}, exec.Command("nonexistent-tool-xyz"), nil)
+	require.NoError(t, err)
+	assert.Equal(t, StatusToolError, result.Status)
+	assert.False(t, result.Success)
+}
+
+func TestInvocation_String(t *testing.T) {
+	invocation := Invocation{
+		Args: []string{"cargo", "clippy", "--message-format", "json", "--", "-D", "warnings", "it's here", ""},
+		Dir:  "/work/my project",
+	}
+
+	assert.Equal(t, `cd '/work/my project' && cargo clippy --message-format json -- -D warnings 'it'\''s here' ''`, invocation.String())
+}
+
+func TestDryRunRunner_PrintsArgvWithoutRunning(t *testing.T) {
+	dir := t.TempDir()
+	marker := filepath.Join(dir, "ran")
+	tool := NewBaseTool("touch", "Go", "touch", LINT)
+
+	var out bytes.Buffer
+	runner := NewDryRunRunner(&out)
+	result, err := tool.Execute(context.Background(), []string{marker}, ExecuteOptions{
+		ProjectRoot: dir,
+		ExtraArgs:   []string{"-c"},
+		Runner:      runner,
+	})
+
+	require.NoError(t, err)
+	assert.Equal(t, StatusClean, result.Status)
+	assert.Equal(t, "cd "+dir+" && touch "+marker+" -c\n", out.String())
+	require.Len(t, runner.Invocations(), 1)
+	assert.Equal(t, []string{"touch", marker, "-c"}, runner.Invocations()[0].Args)
+	assert.NoFileExists(t, marker)
+}
+
+func TestDryRunRunner_ToolQueries(t *testing.T) {
+	t.Setenv("RUSTUP_TOOLCHAIN", "")
+	root := resolvePath(t.TempDir())
+	testutil.WriteFile(t, root, "Cargo.toml", "[package]\nname = \"sample\"\n")
+	lib := testutil.WriteFile(t, filepath.Join(root, "src"), "lib.rs", "\n")
+	argv := func(runner *DryRunRunner) [][]string {
+		var args [][]string
+		for _, invocation := range runner.Invocations() {
+			args = append(args, invocation.Args)
+		}
+		return args
+	}
+
+	// Version probes
+	runner := NewDryRunRunner(nil)
+	tool := NewBaseTool("sh", "Go", "sh", LINT)
+	tool.SetVersionCommand("--version")
+	version, err := tool.GetVersionWith(runner)
+	require.NoError(t, err)
+	assert.Equal(t, "unknown", version)
+	assert.Equal(t, [][]string{{"sh", "--version"}}, argv(runner))
+
+	// cargo metadata while building a command
+	runner = NewDryRunRunner(nil)
+	NewCargoCheckTool().BuildCommand([]string{lib}, ExecuteOptions{ProjectRoot: root, Runner: runner})
+	assert.Contains(t, argv(runner), []string{"cargo", "metadata", "--format-version", "1", "--offline", "--no-deps"})
+
+	// The Go module lookup of goimports and gci
+	runner = NewDryRunRunner(nil)
+	cmd := NewGoimportsTool().BuildCommand([]string{"main.go"}, ExecuteOptions{ProjectRoot: root, Runner: runner})
+	assert.NotContains(t, cmd.Args, "-local")
+	require.Len(t, runner.Invocations(), 1)
+	assert.Equal(t, []string{"go", "list", "-m"}, runner.Invocations()[0].Args)
+	assert.Equal(t, root, runner.Invocations()[0].Dir)
+
+	// The nightly toolchain lookup of cargo-udeps
+	runner = NewDryRunRunner(nil)
+	toolchain, ok := udepsToolchain(context.Background(), ExecuteOptions{ProjectRoot: root, Runner: runner})
+	assert.Empty(t, toolchain)
+	assert.False(t, ok)
+	assert.Equal(t, [][]string{{"rustup", "toolchain", "list"}}, argv(runner))
+
+	// Plugin requests
+	runner = NewDryRunRunner(nil)
+	plugin := &PluginTool{BaseTool: NewBaseTool("sqlcheck", "SQL", "sqlcheck", LINT), path: "/plugins/sqlcheck"}
+	_, err = plugin.GetVersionWith(runner)
+	require.ErrorContains(t, err, "invalid response")
+	require.Len(t, runner.Invocations(), 1)
+	assert.Equal(t, []string{"/plugins/sqlcheck"}, runner.Invocations()[0].Args)
+	assert.Contains(t, string(runner.Invocations()[0].Stdin), `"method":"version"`)
+}
+
+// clippyProject creates a Cargo project with a stub cargo on PATH whose clippy
+// reports a denied lint at an absolute path inside the project.
+func clippyProject(t *testing.T, clippyExitCode string) string {
+	t.Helper()
+
+	root, err := filepath.EvalSymlinks(t.TempDir())
+	require.NoError(t, err)
+	testutil.WriteFile(t, root, "Cargo.toml", "[package]\nname = \"sample\"\nversion = \"0.1.0\"\n")
+	testutil.WriteFile(t, filepath.Join(root, "src"), "lib.rs", "pub fn f() { let x = 1; }\n")
+
+	binDir := t.TempDir()
+	testutil.WriteExecutable(t, binDir, "cargo", `
+if [ "$1" = "clippy" ]; then
+  echo "{\"reason\":\"compiler-message\",\"message\":{\"message\":\"unused variable: x\",\"code\":{\"code\":\"unused_variables\"},\"level\":\"error\",\"spans\":[{\"file_name\":\"$(pwd)/src/lib.rs\",\"line_start\":1,\"line_end\":1,\"column_start\":18,\"column_end\":19,\"is_primary\":true}],\"children\":[]}}"
+  exit `+clippyExitCode+`
+fi
+exit 1
+`)
+	t.Setenv("PATH", binDir)
+	t.Setenv("RUSTUP_TOOLCHAIN", "")
+
+	return root
+}
+
+func TestRecordReplayRunner(t *testing.T) {
+	fixtures := t.TempDir()
+	files := func(root string) []string { return []string{filepath.Join(root, "src", "lib.rs")} }
+
+	// Record against a real (stub) cargo
+	recordRoot := clippyProject(t, "101")
+	recorded, err := NewClippyTool().Execute(context.Background(), files(recordRoot), ExecuteOptions{
+		ProjectRoot: recordRoot,
+		Runner:      NewRecordingRunner(fixtures, recordRoot, nil),
+	})
+	require.NoError(t, err)
+	assert.Equal(t, StatusIssues, recorded.Status)
+
+	entries, err := os.ReadDir(fixtures)
+	require.NoError(t, err)
+	require.Len(t, entries, 1)
+	assert.Regexp(t, `^cargo-[0-9a-f]{16}\.json$`, entries[0].Name())
+	data, err := os.ReadFile(filepath.Join(fixtures, entries[0].Name()))
+	require.NoError(t, err)
+	assert.Contains(t, string(data), `@ROOT@/src/lib.rs`)
+	assert.NotContains(t, string(data), recordRoot)
+
+	// Replay in another project; the stub would now fail if it were run
+	replayRoot := clippyProject(t, "3")
+	replayed, err := NewClippyTool().Execute(context.Background(), files(replayRoot), ExecuteOptions{
+		ProjectRoot: replayRoot,
+		Runner:      NewReplayRunner(fixtures, replayRoot),
+	})
+	require.NoError(t, err)
+
+	assert.Equal(t, StatusIssues, replayed.Status)
+	assert.Equal(t, 101, replayed.ExitCode)
+	require.Len(t, replayed.Issues, 1)
+	assert.Equal(t, "src/lib.rs", replayed.Issues[0].File)
+	assert.Equal(t, "unused_variables", replayed.Issues[0].Rule)
+	assert.Equal(t, recorded.Issues, replayed.Issues)
+}
+
+func TestRecordReplayRunner_MissingRecording(t *testing.T) {
+	tool := NewBaseTool("echo", "Go", "echo", LINT)
+
+	result, err := tool.Execute(context.Background(), []string{"main.go"}, ExecuteOptions{
+		Runner: NewReplayRunner(t.TempDir(), ""),
+	})
+
+	require.NoError(t, err)
+	assert.Equal(t, StatusToolError, result.Status)
+	assert.Contains(t, result.Error, "no recording of echo main.go")
+}
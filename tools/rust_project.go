@@ -0,0 +1,196 @@
+// Copyright (c) 2025 Archmagece
+// SPDX-License-Identifier: MIT
+
+package tools
+
+import (
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+)
+
+// defaultRustEdition is the edition cargo assumes when Cargo.toml sets none.
+const defaultRustEdition = "2015"
+
+// RustCrate describes the crate owning a set of Rust source files.
+type RustCrate struct {
+	// Name is the package name from Cargo.toml
+	Name string
+
+	// Dir is the directory containing the crate's Cargo.toml
+	Dir string
+
+	// Edition is the crate's edition, inherited from [workspace.package] if
+	// the crate says so, and "2015" when none is set
+	Edition string
+
+	// Toolchain is the channel pinned by the nearest rust-toolchain(.toml)
+	// file, or empty when rustup's default applies
+	Toolchain string
+}
+
+// RustCrates returns the crates owning the given files, sorted by directory.
+// Relative files are resolved against projectRoot; files outside any crate
+// are skipped.
+func RustCrates(files []string, projectRoot string) []RustCrate {
+	resolver := newRustResolver()
+
+	seen := make(map[string]bool)
+	var crates []RustCrate
+	for _, file := range files {
+		crate, ok := resolver.crateOf(absPath(file, projectRoot))
+		if !ok || seen[crate.Dir] {
+			continue
+		}
+		seen[crate.Dir] = true
+		crates = append(crates, crate)
+	}
+
+	sort.Slice(crates, func(i, j int) bool { return crates[i].Dir < crates[j].Dir })
+	return crates
+}
+
+// rustResolver resolves owning crates and toolchains, memoizing per directory
+// since many files share the same manifest.
+type rustResolver struct {
+	crates     map[string]*RustCrate
+	toolchains map[string]string
+}
+
+// newRustResolver creates an empty resolver.
+func newRustResolver() *rustResolver {
+	return &rustResolver{
+		crates:     make(map[string]*RustCrate),
+		toolchains: make(map[string]string),
+	}
+}
+
+// crateOf returns the crate whose Cargo.toml is the nearest one with a
+// [package] table above file.
+func (r *rustResolver) crateOf(file string) (RustCrate, bool) {
+	dir := filepath.Dir(file)
+	crate, ok := r.crates[dir]
+	if !ok {
+		crate = r.findCrate(dir)
+		r.crates[dir] = crate
+	}
+	if crate == nil {
+		return RustCrate{}, false
+	}
+	return *crate, true
+}
+
+// findCrate walks up from dir to the nearest package manifest.
+func (r *rustResolver) findCrate(dir string) *RustCrate {
+	for current := dir; ; current = filepath.Dir(current) {
+		if manifest, err := readTOML(filepath.Join(current, "Cargo.toml")); err == nil && manifest.HasTable("package") {
+			name, _ := manifest.String("package", "name")
+			return &RustCrate{
+				Name:      name,
+				Dir:       current,
+				Edition:   crateEdition(manifest, current),
+				Toolchain: r.toolchain(current),
+			}
+		}
+		if filepath.Dir(current) == current {
+			return nil
+		}
+	}
+}
+
+// toolchain returns the channel pinned for dir, see rustToolchain.
+func (r *rustResolver) toolchain(dir string) string {
+	toolchain, ok := r.toolchains[dir]
+	if !ok {
+		toolchain = rustToolchain(dir)
+		r.toolchains[dir] = toolchain
+	}
+	return toolchain
+}
+
+// crateEdition returns the edition of a package manifest in dir, following
+// `edition.workspace = true` to the workspace's [workspace.package].
+func crateEdition(manifest *tomlDocument, dir string) string {
+	if edition, ok := manifest.String("package", "edition"); ok {
+		return edition
+	}
+	if !inheritsFromWorkspace(manifest, "edition") {
+		return defaultRustEdition
+	}
+
+	for current := dir; ; current = filepath.Dir(current) {
+		if workspace, err := readTOML(filepath.Join(current, "Cargo.toml")); err == nil && workspace.HasTable("workspace") {
+			if edition, ok := workspace.String("workspace.package", "edition"); ok {
+				return edition
+			}
+			return defaultRustEdition
+		}
+		if filepath.Dir(current) == current {
+			return defaultRustEdition
+		}
+	}
+}
+
+// inheritsFromWorkspace reports whether a [package] key is inherited with
+// `key.workspace = true`, `key = { workspace = true }` or [package.key].
+func inheritsFromWorkspace(manifest *tomlDocument, key string) bool {
+	if entry, ok := manifest.Get("package", key+".workspace"); ok {
+		return entry.Value == "true"
+	}
+	if entry, ok := manifest.Get("package", key); ok {
+		fields, ok := tomlInlineTable(entry.Value)
+		return ok && fields["workspace"] == "true"
+	}
+	if entry, ok := manifest.Get("package."+key, "workspace"); ok {
+		return entry.Value == "true"
+	}
+	return false
+}
+
+// rustToolchain returns the channel of the nearest rust-toolchain or
+// rust-toolchain.toml file at or above dir, or "" if there is none. Like
+// rustup, the legacy rust-toolchain file wins when both exist.
+func rustToolchain(dir string) string {
+	for current := dir; ; current = filepath.Dir(current) {
+		if data, err := os.ReadFile(filepath.Join(current, "rust-toolchain")); err == nil {
+			// Either TOML or just the channel name
+			if file, err := parseTOML(string(data)); err == nil {
+				if channel, ok := file.String("toolchain", "channel"); ok {
+					return channel
+				}
+			}
+			return strings.TrimSpace(strings.SplitN(string(data), "\n", 2)[0])
+		}
+		if file, err := readTOML(filepath.Join(current, "rust-toolchain.toml")); err == nil {
+			channel, _ := file.String("toolchain", "channel")
+			return channel
+		}
+		if filepath.Dir(current) == current {
+			return ""
+		}
+	}
+}
+
+// toolchainEnv returns the RUSTUP_TOOLCHAIN variable selecting a pinned
+// toolchain. Unlike "+<toolchain>", which only rustup proxies accept, other
+// cargo installs ignore it. An explicit RUSTUP_TOOLCHAIN, from options.Env or
+// the environment, takes precedence over toolchain files, so nothing is added
+// then.
+func toolchainEnv(options ExecuteOptions, toolchain string) []string {
+	if toolchain == "" || lookupEnv(options, "RUSTUP_TOOLCHAIN") != "" {
+		return nil
+	}
+	return []string{"RUSTUP_TOOLCHAIN=" + toolchain}
+}
+
+// absPath resolves file against root when it is relative.
+func absPath(file, root string) string {
+	if !filepath.IsAbs(file) && root != "" {
+		file = filepath.Join(root, file)
+	}
+	if abs, err := filepath.Abs(file); err == nil {
+		return abs
+	}
+	return file
+}
@@ -4,9 +4,19 @@
 package tools
 
 import (
+	"context"
 	"encoding/json"
+	"fmt"
+	"os"
 	"os/exec"
+	"path/filepath"
+	"regexp"
+	"slices"
+	"sort"
+	"strconv"
 	"strings"
+
+	"github.com/Gizzahub/gzh-cli-quality/git"
 )
 
 const (
@@ -26,26 +36,45 @@ func NewRustfmtTool() *RustfmtTool {
 		BaseTool: NewBaseTool("rustfmt", "Rust", "rustfmt", FORMAT),
 	}
 
-	tool.SetInstallCommand([]string{"rustup", "component", "add", "rustfmt"})
+	tool.Bind(tool)
+	// rustfmt --check exits 1 when files need formatting
+	tool.SetFindingExitCodes(1)
+	tool.AddPackage(PackageRustup, "rustfmt")
 	tool.SetConfigPatterns([]string{"rustfmt.toml", ".rustfmt.toml"})
 
 	return tool
 }
 
-// BuildCommand builds the rustfmt command.
+// BuildCommand builds the rustfmt command. When all files belong to crates
+// with the same edition and toolchain, those are passed explicitly since bare
+// rustfmt assumes the 2015 edition; Execute splits mixed file lists.
 func (t *RustfmtTool) BuildCommand(files []string, options ExecuteOptions) *exec.Cmd {
-	args := []string{}
+	// Filter only Rust files
+	rustFiles := FilterFilesByExtensions(files, []string{".rs"})
+
+	var args, vars []string
+	if groups := rustfmtGroups(rustFiles, options.ProjectRoot); len(groups) == 1 {
+		vars = toolchainEnv(options, groups[0].toolchain)
+
+		// An edition in the rustfmt config wins over the crate's
+		if groups[0].edition != "" && !configSetsEdition(options.ConfigFile) {
+			args = append(args, "--edition", groups[0].edition)
+		}
+	}
 
 	// Add config file if specified
 	if options.ConfigFile != "" {
 		args = append(args, "--config-path", options.ConfigFile)
 	}
 
+	// Print diffs instead of rewriting files
+	if options.Check {
+		args = append(args, "--check")
+	}
+
 	// Add extra flags if provided
 	args = append(args, options.ExtraArgs...)
 
-	// Filter only Rust files
-	rustFiles := FilterFilesByExtensions(files, []string{".rs"})
 	args = append(args, rustFiles...)
 
 	cmd := exec.Command(t.executable, args...)
@@ -53,13 +82,143 @@ func (t *RustfmtTool) BuildCommand(files []string, options ExecuteOptions) *exec
 	if options.ProjectRoot != "" {
 		cmd.Dir = options.ProjectRoot
 	}
+	cmd.Env = commandEnv(options, vars...)
 
 	return cmd
 }
 
+// Execute runs rustfmt once per group of files sharing an edition and
+// toolchain and merges the results.
+func (t *RustfmtTool) Execute(ctx context.Context, files []string, options ExecuteOptions) (*Result, error) {
+	groups := rustfmtGroups(FilterFilesByExtensions(files, []string{".rs"}), options.ProjectRoot)
+	if len(groups) <= 1 {
+		return t.BaseTool.Execute(ctx, files, options)
+	}
+
+	var merged *Result
+	filesProcessed := 0
+	for _, group := range groups {
+		result, err := t.BaseTool.Execute(ctx, group.files, options)
+		if err != nil {
+			return result, err
+		}
+		if result.Status == StatusSkipped {
+			return result, nil
+		}
+
+		filesProcessed += result.FilesProcessed
+		merged = mergeRuns(merged, result)
+	}
+	merged.FilesProcessed = filesProcessed
+
+	return merged, nil
+}
+
+// rustfmtGroup is a set of files formatted with the same edition and toolchain.
+type rustfmtGroup struct {
+	edition   string
+	toolchain string
+	files     []string
+}
+
+// rustfmtGroups groups files by the edition and toolchain of their crates,
+// in order of first appearance. Files outside any crate get neither.
+func rustfmtGroups(files []string, projectRoot string) []rustfmtGroup {
+	resolver := newRustResolver()
+
+	var groups []rustfmtGroup
+	index := make(map[string]int)
+	for _, file := range files {
+		crate, _ := resolver.crateOf(absPath(file, projectRoot))
+
+		key := crate.Edition + "\x00" + crate.Toolchain
+		i, ok := index[key]
+		if !ok {
+			i = len(groups)
+			index[key] = i
+			groups = append(groups, rustfmtGroup{edition: crate.Edition, toolchain: crate.Toolchain})
+		}
+		groups[i].files = append(groups[i].files, file)
+	}
+
+	return groups
+}
+
+// configSetsEdition reports whether a rustfmt config file sets the edition.
+func configSetsEdition(configFile string) bool {
+	if configFile == "" {
+		return false
+	}
+	config, err := readTOML(configFile)
+	if err != nil {
+		return false
+	}
+	_, ok := config.Get("", "edition")
+	return ok
+}
+
+// ParseOutput parses rustfmt --check diffs into one issue per unformatted file.
+func (t *RustfmtTool) ParseOutput(output string) []Issue {
+	return parseRustfmtCheck(output, "file is not rustfmt-formatted")
+}
+
+// SupportsCheck reports that rustfmt can verify formatting without writing.
+func (t *RustfmtTool) SupportsCheck() bool {
+	return true
+}
+
+// FeatureSet selects the Cargo features a command is built with.
+type FeatureSet struct {
+	// AllFeatures enables every feature of the selected packages
+	AllFeatures bool
+
+	// NoDefaultFeatures disables the default feature
+	NoDefaultFeatures bool
+
+	// Features lists features to enable
+	Features []string
+}
+
+// Args returns the cargo flags selecting the feature set.
+func (f FeatureSet) Args() []string {
+	var args []string
+	if f.AllFeatures {
+		args = append(args, "--all-features")
+	}
+	if f.NoDefaultFeatures {
+		args = append(args, "--no-default-features")
+	}
+	if len(f.Features) > 0 {
+		args = append(args, "--features", strings.Join(f.Features, ","))
+	}
+	return args
+}
+
+// RustSettings configures lint levels, targets and features of clippy runs.
+type RustSettings struct {
+	// Deny, Warn and Allow list lints passed as -D, -W and -A, in that order
+	// so specific allows override denied groups. Lints the Cargo.toml [lints]
+	// table configures are left to it.
+	Deny  []string
+	Warn  []string
+	Allow []string
+
+	// AllTargets also lints tests, benches and examples
+	AllTargets bool
+
+	// Features is the feature set of a single run
+	Features FeatureSet
+
+	// FeatureMatrix, if set, runs clippy once per feature set instead and
+	// merges the findings
+	FeatureMatrix []FeatureSet
+}
+
 // ClippyTool implements Rust linting using clippy.
 type ClippyTool struct {
 	*BaseTool
+
+	settings RustSettings
 }
 
 // NewClippyTool creates a new clippy tool.
@@ -68,14 +227,34 @@ func NewClippyTool() *ClippyTool {
 		BaseTool: NewBaseTool("clippy", "Rust", "cargo", LINT),
 	}
 
-	tool.SetInstallCommand([]string{"rustup", "component", "add", "clippy"})
+	tool.Bind(tool)
+	// clippy cargo exits 101 when lints are denied
+	tool.SetFindingExitCodes(101)
+	tool.AddPackage(PackageRustup, "clippy")
+	// "cargo --version" would report cargo, not clippy
+	tool.SetVersionCommand("clippy", "--version")
 	tool.SetConfigPatterns([]string{"clippy.toml", ".clippy.toml", "Cargo.toml"})
 
 	return tool
 }
 
+// SetSettings sets the lint levels, targets and features used by later runs.
+func (t *ClippyTool) SetSettings(settings RustSettings) {
+	t.settings = settings
+}
+
+// SettingsDigest identifies the settings for cache keys.
+func (t *ClippyTool) SettingsDigest() string {
+	return fmt.Sprintf("%+v", t.settings)
+}
+
 // BuildCommand builds the clippy command.
 func (t *ClippyTool) BuildCommand(files []string, options ExecuteOptions) *exec.Cmd {
+	return t.buildCommand(files, options, t.settings.Features)
+}
+
+// buildCommand builds the clippy command for one feature set.
+func (t *ClippyTool) buildCommand(files []string, options ExecuteOptions, features FeatureSet) *exec.Cmd {
 	args := []string{"clippy"}
 
 	// Add fix flag if requested
@@ -83,14 +262,27 @@ func (t *ClippyTool) BuildCommand(files []string, options ExecuteOptions) *exec.
 		args = append(args, "--fix")
 	}
 
+	// Only lint the workspace members owning the changed files
+	args = append(args, cargoPackageArgs(runnerOf(options), t.executable, files, options.ProjectRoot)...)
+
+	if t.settings.AllTargets {
+		args = append(args, "--all-targets")
+	}
+	args = append(args, features.Args()...)
+
 	// Output format for parsing
 	args = append(args, "--message-format", "json")
 
 	// Add extra flags
 	args = append(args, options.ExtraArgs...)
 
-	// Clippy works on the entire project, not individual files
-	args = append(args, "--", "-D", "warnings")
+	// Lint levels are rustc flags, passed after the "--" separator
+	if lintArgs := t.lintArgs(options); len(lintArgs) > 0 {
+		if !slices.Contains(options.ExtraArgs, "--") {
+			args = append(args, "--")
+		}
+		args = append(args, lintArgs...)
+	}
 
 	cmd := exec.Command(t.executable, args...)
 
@@ -98,76 +290,306 @@ func (t *ClippyTool) BuildCommand(files []string, options ExecuteOptions) *exec.
 		cmd.Dir = options.ProjectRoot
 	}
 
+	// A toolchain pinned by rust-toolchain(.toml) is selected explicitly
+	cmd.Env = commandEnv(options, toolchainEnv(options, rustToolchain(absPath(".", options.ProjectRoot)))...)
+
 	return cmd
 }
 
+// lintArgs returns the -D/-W/-A flags. Without configured levels warnings
+// are denied, unless the Cargo.toml has a [lints] table to defer to.
+func (t *ClippyTool) lintArgs(options ExecuteOptions) []string {
+	manifest, hasLints := manifestLints(runnerOf(options), t.executable, options.ProjectRoot)
+
+	levels := []struct {
+		flag  string
+		lints []string
+	}{
+		{"-D", t.settings.Deny},
+		{"-W", t.settings.Warn},
+		{"-A", t.settings.Allow},
+	}
+	if len(t.settings.Deny)+len(t.settings.Warn)+len(t.settings.Allow) == 0 && !hasLints {
+		levels[0].lints = []string{"warnings"}
+	}
+
+	var args []string
+	for _, level := range levels {
+		for _, lint := range level.lints {
+			if !manifest[lint] {
+				args = append(args, level.flag, lint)
+			}
+		}
+	}
+	return args
+}
+
 // ParseOutput parses clippy JSON output.
 func (t *ClippyTool) ParseOutput(output string) []Issue {
-	if strings.TrimSpace(output) == "" {
-		return []Issue{}
-	}
+	return parseCargoDiagnostics(output)
+}
 
-	lines := strings.Split(output, "\n")
-	issues := make([]Issue, 0, len(lines))
+// Execute runs clippy, once per feature set when a feature matrix is
+// configured, and rewrites span paths, which cargo reports relative to the
+// workspace root, relative to the project root.
+func (t *ClippyTool) Execute(ctx context.Context, files []string, options ExecuteOptions) (*Result, error) {
+	if result := t.preloadCargoMetadata(ctx, t.executable, options); result != nil {
+		return result, nil
+	}
 
-	for _, line := range lines {
-		line = strings.TrimSpace(line)
-		if line == "" {
-			continue
+	if len(t.settings.FeatureMatrix) == 0 {
+		result, err := t.BaseTool.Execute(ctx, files, options)
+		if err != nil {
+			return result, err
 		}
 
-		var clippyMessage struct {
-			Message struct {
-				Message string `json:"message"`
-				Code    *struct {
-					Code string `json:"code"`
-				} `json:"code"`
-				Level string `json:"level"`
-				Spans []struct {
-					FileName    string `json:"file_name"`
-					LineStart   int    `json:"line_start"`
-					ColumnStart int    `json:"column_start"`
-				} `json:"spans"`
-			} `json:"message"`
-			Target struct {
-				Name string `json:"name"`
-			} `json:"target"`
-		}
-
-		if err := json.Unmarshal([]byte(line), &clippyMessage); err != nil {
-			continue
-		}
+		normalizeCargoIssues(ctx, result, runnerOf(options), t.executable, options.ProjectRoot)
+		return result, nil
+	}
 
-		msg := clippyMessage.Message
-		if len(msg.Spans) == 0 {
-			continue
+	var merged *Result
+	for _, features := range t.settings.FeatureMatrix {
+		result, err := t.executeWith(ctx, clippyFeatureRun{tool: t, features: features}, t, files, options)
+		if err != nil {
+			return result, err
+		}
+		if result.Status == StatusSkipped {
+			return result, nil
 		}
 
-		severity := severityInfo
-		switch msg.Level {
-		case severityError:
-			severity = severityError
-		case severityWarning:
-			severity = severityWarning
+		normalizeCargoIssues(ctx, result, runnerOf(options), t.executable, options.ProjectRoot)
+		merged = mergeRuns(merged, result)
+	}
+
+	return merged, nil
+}
+
+// clippyFeatureRun builds the clippy command for one set of a feature matrix.
+type clippyFeatureRun struct {
+	tool     *ClippyTool
+	features FeatureSet
+}
+
+// BuildCommand builds the clippy command with the run's feature set.
+func (r clippyFeatureRun) BuildCommand(files []string, options ExecuteOptions) *exec.Cmd {
+	return r.tool.buildCommand(files, options, r.features)
+}
+
+// mergeRuns adds the result of one run of a tool to the results of the
+// previous runs; findings reported by several runs are kept once.
+func mergeRuns(merged, result *Result) *Result {
+	if merged == nil {
+		return result
+	}
+
+	seen := make(map[string]bool, len(merged.Issues))
+	key := func(issue Issue) string {
+		return fmt.Sprintf("%s:%d:%d:%s:%s", issue.File, issue.Line, issue.Column, issue.Rule, issue.Message)
+	}
+	for _, issue := range merged.Issues {
+		seen[key(issue)] = true
+	}
+	for _, issue := range result.Issues {
+		if !seen[key(issue)] {
+			seen[key(issue)] = true
+			merged.Issues = append(merged.Issues, issue)
 		}
+	}
+
+	merged.Success = merged.Success && result.Success
+	merged.Status = WorseStatus(merged.Status, result.Status)
+	if merged.ExitCode == 0 {
+		merged.ExitCode = result.ExitCode
+	}
+	if merged.Error == "" {
+		merged.Error = result.Error
+	}
+	merged.Duration += result.Duration
+	merged.Output += "\n" + result.Output
+	merged.Stdout += result.Stdout
+	merged.Stderr += result.Stderr
+
+	return merged
+}
+
+// CargoCheckTool implements fast Rust type checking using cargo check.
+type CargoCheckTool struct {
+	*BaseTool
+}
+
+// NewCargoCheckTool creates a new cargo check tool.
+func NewCargoCheckTool() *CargoCheckTool {
+	tool := &CargoCheckTool{
+		BaseTool: NewBaseTool("cargo-check", "Rust", "cargo", LINT),
+	}
+
+	tool.Bind(tool)
+	// cargo check exits 101 when compilation fails
+	tool.SetFindingExitCodes(101)
+	tool.AddPackage(PackageRustup, "cargo")
+	tool.SetConfigPatterns([]string{"Cargo.toml"})
+
+	return tool
+}
+
+// BuildCommand builds the cargo check command.
+func (t *CargoCheckTool) BuildCommand(files []string, options ExecuteOptions) *exec.Cmd {
+	args := []string{"check"}
+
+	// Only check the workspace members owning the changed files
+	args = append(args, cargoPackageArgs(runnerOf(options), t.executable, files, options.ProjectRoot)...)
+
+	// Output format for parsing
+	args = append(args, "--message-format", "json", "--all-targets")
+
+	// Add extra flags if provided
+	args = append(args, options.ExtraArgs...)
+
+	cmd := exec.Command(t.executable, args...)
+
+	if options.ProjectRoot != "" {
+		cmd.Dir = options.ProjectRoot
+	}
+
+	// A toolchain pinned by rust-toolchain(.toml) is selected explicitly
+	cmd.Env = commandEnv(options, toolchainEnv(options, rustToolchain(absPath(".", options.ProjectRoot)))...)
+
+	return cmd
+}
+
+// ParseOutput parses cargo check JSON output with the clippy parser.
+func (t *CargoCheckTool) ParseOutput(output string) []Issue {
+	return parseCargoDiagnostics(output)
+}
 
-		rule := ""
-		if msg.Code != nil {
-			rule = msg.Code.Code
+// Execute runs cargo check and rewrites span paths relative to the project
+// root, as for clippy.
+func (t *CargoCheckTool) Execute(ctx context.Context, files []string, options ExecuteOptions) (*Result, error) {
+	if result := t.preloadCargoMetadata(ctx, t.executable, options); result != nil {
+		return result, nil
+	}
+
+	result, err := t.BaseTool.Execute(ctx, files, options)
+	if err != nil {
+		return result, err
+	}
+
+	normalizeCargoIssues(ctx, result, runnerOf(options), t.executable, options.ProjectRoot)
+	return result, nil
+}
+
+// SupersededBy reports that clippy, which runs the same type checks and
+// more, covers cargo check.
+func (t *CargoCheckTool) SupersededBy() []string {
+	return []string{"clippy"}
+}
+
+// rustdocLints are the lints the rustdoc tool denies by default.
+var rustdocLints = []string{"rustdoc::broken_intra_doc_links"}
+
+// RustdocTool implements Rust documentation linting using cargo doc.
+type RustdocTool struct {
+	*BaseTool
+
+	missingDocs bool
+}
+
+// NewRustdocTool creates a new rustdoc tool.
+func NewRustdocTool() *RustdocTool {
+	tool := &RustdocTool{
+		BaseTool: NewBaseTool("rustdoc", "Rust", "cargo", LINT),
+	}
+
+	tool.Bind(tool)
+	// cargo doc exits 101 when lints are denied
+	tool.SetFindingExitCodes(101)
+	// rustdoc ships with the compiler
+	tool.AddPackage(PackageRustup, "rustc")
+	tool.SetConfigPatterns([]string{"Cargo.toml"})
+
+	return tool
+}
+
+// SetMissingDocs makes later runs also deny undocumented public items.
+func (t *RustdocTool) SetMissingDocs(enabled bool) {
+	t.missingDocs = enabled
+}
+
+// SettingsDigest identifies the settings for cache keys.
+func (t *RustdocTool) SettingsDigest() string {
+	return fmt.Sprintf("missing-docs=%t", t.missingDocs)
+}
+
+// BuildCommand builds the cargo doc command. cargo doc takes no rustdoc
+// flags on the command line, so the lints are passed in RUSTDOCFLAGS.
+func (t *RustdocTool) BuildCommand(files []string, options ExecuteOptions) *exec.Cmd {
+	args := []string{"doc", "--no-deps"}
+
+	// Only document the workspace members owning the changed files
+	args = append(args, cargoPackageArgs(runnerOf(options), t.executable, files, options.ProjectRoot)...)
+
+	// Output format for parsing
+	args = append(args, "--message-format", "json")
+
+	// Add extra flags if provided
+	args = append(args, options.ExtraArgs...)
+
+	cmd := exec.Command(t.executable, args...)
+
+	if options.ProjectRoot != "" {
+		cmd.Dir = options.ProjectRoot
+	}
+
+	// A toolchain pinned by rust-toolchain(.toml) is selected explicitly, and
+	// the lint flags extend any RUSTDOCFLAGS already set
+	vars := toolchainEnv(options, rustToolchain(absPath(".", options.ProjectRoot)))
+	if lintArgs := t.lintArgs(options); len(lintArgs) > 0 {
+		flags := strings.TrimSpace(lookupEnv(options, "RUSTDOCFLAGS") + " " + strings.Join(lintArgs, " "))
+		vars = append(vars, "RUSTDOCFLAGS="+flags)
+	}
+	cmd.Env = commandEnv(options, vars...)
+
+	return cmd
+}
+
+// lintArgs returns the -D flags of the rustdoc lints, leaving out those the
+// Cargo.toml [lints] table configures.
+func (t *RustdocTool) lintArgs(options ExecuteOptions) []string {
+	manifest, _ := manifestLints(runnerOf(options), t.executable, options.ProjectRoot)
+
+	lints := rustdocLints
+	if t.missingDocs {
+		lints = append(slices.Clone(lints), "missing_docs")
+	}
+
+	var args []string
+	for _, lint := range lints {
+		if !manifest[lint] {
+			args = append(args, "-D", lint)
 		}
+	}
+	return args
+}
 
-		span := msg.Spans[0]
-		issues = append(issues, Issue{
-			File:     span.FileName,
-			Line:     span.LineStart,
-			Column:   span.ColumnStart,
-			Severity: severity,
-			Rule:     rule,
-			Message:  msg.Message,
-		})
+// ParseOutput parses cargo doc JSON output with the clippy parser.
+func (t *RustdocTool) ParseOutput(output string) []Issue {
+	return parseCargoDiagnostics(output)
+}
+
+// Execute runs cargo doc and rewrites span paths relative to the project
+// root, as for clippy.
+func (t *RustdocTool) Execute(ctx context.Context, files []string, options ExecuteOptions) (*Result, error) {
+	if result := t.preloadCargoMetadata(ctx, t.executable, options); result != nil {
+		return result, nil
 	}
 
-	return issues
+	result, err := t.BaseTool.Execute(ctx, files, options)
+	if err != nil {
+		return result, err
+	}
+
+	normalizeCargoIssues(ctx, result, runnerOf(options), t.executable, options.ProjectRoot)
+	return result, nil
 }
 
 // CargoFmtTool implements Rust formatting using cargo fmt.
@@ -181,7 +603,11 @@ func NewCargoFmtTool() *CargoFmtTool {
 		BaseTool: NewBaseTool("cargo-fmt", "Rust", "cargo", FORMAT),
 	}
 
-	tool.SetInstallCommand([]string{"rustup", "component", "add", "rustfmt"})
+	tool.Bind(tool)
+	// cargo-fmt --check exits 1 when files need formatting
+	tool.SetFindingExitCodes(1)
+	tool.AddPackage(PackageRustup, "rustfmt")
+	tool.SetVersionCommand("fmt", "--version")
 	tool.SetConfigPatterns([]string{"rustfmt.toml", ".rustfmt.toml"})
 
 	return tool
@@ -191,22 +617,1144 @@ func NewCargoFmtTool() *CargoFmtTool {
 func (t *CargoFmtTool) BuildCommand(files []string, options ExecuteOptions) *exec.Cmd {
 	args := []string{"fmt"}
 
+	// Only format the workspace members owning the changed files
+	args = append(args, cargoPackageArgs(runnerOf(options), t.executable, files, options.ProjectRoot)...)
+
 	// Add extra flags if provided
 	args = append(args, options.ExtraArgs...)
 
-	// cargo fmt works on the entire project
+	// --check is a rustfmt flag, passed after the "--" separator
+	if options.Check {
+		if !slices.Contains(options.ExtraArgs, "--") {
+			args = append(args, "--")
+		}
+		args = append(args, "--check")
+	}
+
 	cmd := exec.Command(t.executable, args...)
 
 	if options.ProjectRoot != "" {
 		cmd.Dir = options.ProjectRoot
 	}
 
+	// A toolchain pinned by rust-toolchain(.toml) is selected explicitly
+	cmd.Env = commandEnv(options, toolchainEnv(options, rustToolchain(absPath(".", options.ProjectRoot)))...)
+
 	return cmd
 }
 
-// Ensure Rust tools implement QualityTool interface.
-var (
-	_ QualityTool = (*RustfmtTool)(nil)
-	_ QualityTool = (*ClippyTool)(nil)
-	_ QualityTool = (*CargoFmtTool)(nil)
+// ParseOutput parses cargo fmt -- --check diffs into one issue per unformatted file.
+func (t *CargoFmtTool) ParseOutput(output string) []Issue {
+	return parseRustfmtCheck(output, "file is not rustfmt-formatted")
+}
+
+// Execute runs cargo fmt with the workspace metadata preloaded.
+func (t *CargoFmtTool) Execute(ctx context.Context, files []string, options ExecuteOptions) (*Result, error) {
+	if result := t.preloadCargoMetadata(ctx, t.executable, options); result != nil {
+		return result, nil
+	}
+
+	return t.BaseTool.Execute(ctx, files, options)
+}
+
+// SupportsCheck reports that cargo fmt can verify formatting without writing.
+func (t *CargoFmtTool) SupportsCheck() bool {
+	return true
+}
+
+// CargoAuditTool implements Rust dependency vulnerability scanning using cargo-audit.
+type CargoAuditTool struct {
+	*BaseTool
+
+	advisoryDB string
+}
+
+// NewCargoAuditTool creates a new cargo-audit tool.
+func NewCargoAuditTool() *CargoAuditTool {
+	tool := &CargoAuditTool{
+		BaseTool: NewBaseTool("cargo-audit", "Rust", "cargo-audit", LINT),
+	}
+
+	tool.Bind(tool)
+	// cargo-audit exits 1 when vulnerabilities are found
+	tool.SetFindingExitCodes(1)
+	tool.AddPackage(PackageCargo, "cargo-audit")
+	// cargo runs the subcommand binary as "cargo-audit audit ..."
+	tool.SetVersionCommand("audit", "--version")
+	// The lockfile is listed so cached results follow dependency changes
+	tool.SetConfigPatterns([]string{".cargo/audit.toml", "Cargo.lock"})
+
+	return tool
+}
+
+// SetAdvisoryDB makes later runs use the advisory database stored at path
+// instead of fetching it; empty fetches the default database.
+func (t *CargoAuditTool) SetAdvisoryDB(path string) {
+	t.advisoryDB = path
+}
+
+// SettingsDigest identifies the settings for cache keys.
+func (t *CargoAuditTool) SettingsDigest() string {
+	return "advisory-db=" + t.advisoryDB
+}
+
+// BuildCommand builds the cargo-audit command. The binary is called the way
+// cargo calls it, so the tool is only available when cargo-audit is installed.
+func (t *CargoAuditTool) BuildCommand(files []string, options ExecuteOptions) *exec.Cmd {
+	args := []string{"audit", "--json"}
+
+	// Offline runs against a local database
+	if t.advisoryDB != "" {
+		args = append(args, "--db", t.advisoryDB, "--no-fetch")
+	}
+
+	// Workspace members share the lockfile of the workspace root
+	root := resolvePath(absPath(".", options.ProjectRoot))
+	if lockfile := findCargoLock(root); lockfile != "" && filepath.Dir(lockfile) != root {
+		args = append(args, "--file", lockfile)
+	}
+
+	// Add extra flags if provided
+	args = append(args, options.ExtraArgs...)
+
+	cmd := exec.Command(t.executable, args...)
+
+	if options.ProjectRoot != "" {
+		cmd.Dir = options.ProjectRoot
+	}
+
+	return cmd
+}
+
+// ProjectInputs returns the manifests and lockfiles the advisories are checked against.
+func (t *CargoAuditTool) ProjectInputs(files []string, projectRoot string) []string {
+	return cargoProjectInputs(files, projectRoot)
+}
+
+// ParseOutput parses cargo-audit JSON output; findings point at Cargo.lock.
+func (t *CargoAuditTool) ParseOutput(output string) []Issue {
+	return parseCargoAudit(output, nil)
+}
+
+// Execute runs cargo-audit and locates the affected crates in the project's
+// Cargo.toml or Cargo.lock.
+func (t *CargoAuditTool) Execute(ctx context.Context, files []string, options ExecuteOptions) (*Result, error) {
+	lockfile := loadCargoLockfile(options.ProjectRoot)
+	parser := outputParserFunc(func(output string) []Issue {
+		return parseCargoAudit(output, lockfile)
+	})
+	return t.executeWith(ctx, t, parser, files, options)
+}
+
+// CargoDenyTool implements Rust dependency policy checks (advisories,
+// licenses, bans and sources) using cargo-deny.
+type CargoDenyTool struct {
+	*BaseTool
+
+	advisoryDB string
+}
+
+// NewCargoDenyTool creates a new cargo-deny tool.
+func NewCargoDenyTool() *CargoDenyTool {
+	tool := &CargoDenyTool{
+		BaseTool: NewBaseTool("cargo-deny", "Rust", "cargo-deny", LINT),
+	}
+
+	tool.Bind(tool)
+	// cargo-deny sets one exit code bit per failed check
+	tool.SetFindingExitCodes(exitCodeRange(1, 15)...)
+	tool.AddPackage(PackageCargo, "cargo-deny")
+	// The lockfile is listed so cached results follow dependency changes
+	tool.SetConfigPatterns([]string{"deny.toml", ".deny.toml", ".cargo/deny.toml", "Cargo.lock"})
+
+	return tool
+}
+
+// SetAdvisoryDB makes later runs use the locally stored advisory database
+// instead of fetching it. cargo-deny reads its location from `db-path` in
+// the [advisories] table of deny.toml, so this only disables fetching.
+func (t *CargoDenyTool) SetAdvisoryDB(path string) {
+	t.advisoryDB = path
+}
+
+// SettingsDigest identifies the settings for cache keys.
+func (t *CargoDenyTool) SettingsDigest() string {
+	return "advisory-db=" + t.advisoryDB
+}
+
+// BuildCommand builds the cargo-deny command running all checks.
+func (t *CargoDenyTool) BuildCommand(files []string, options ExecuteOptions) *exec.Cmd {
+	args := []string{"--format", "json", "check"}
+
+	if config := denyConfig(options.ConfigFile); config != "" {
+		args = append(args, "--config", config)
+	}
+
+	// Offline runs against a local database
+	if t.advisoryDB != "" {
+		args = append(args, "--disable-fetch")
+	}
+
+	// Add extra flags if provided
+	args = append(args, options.ExtraArgs...)
+
+	cmd := exec.Command(t.executable, args...)
+
+	if options.ProjectRoot != "" {
+		cmd.Dir = options.ProjectRoot
+	}
+
+	return cmd
+}
+
+// ProjectInputs returns the manifests and lockfiles the dependency graph is read from.
+func (t *CargoDenyTool) ProjectInputs(files []string, projectRoot string) []string {
+	return cargoProjectInputs(files, projectRoot)
+}
+
+// ParseOutput parses cargo-deny JSON diagnostics; findings point at Cargo.lock.
+func (t *CargoDenyTool) ParseOutput(output string) []Issue {
+	return parseCargoDeny(output, nil, "deny.toml")
+}
+
+// Execute runs cargo-deny and locates the affected crates in the project's
+// Cargo.toml or Cargo.lock, and configuration findings in deny.toml.
+func (t *CargoDenyTool) Execute(ctx context.Context, files []string, options ExecuteOptions) (*Result, error) {
+	lockfile := loadCargoLockfile(options.ProjectRoot)
+
+	configFile := "deny.toml"
+	if config := denyConfig(options.ConfigFile); config != "" {
+		configFile = config
+		if rel, err := filepath.Rel(lockfile.root, resolvePath(absPath(config, options.ProjectRoot))); err == nil {
+			configFile = rel
+		}
+	}
+
+	parser := outputParserFunc(func(output string) []Issue {
+		return parseCargoDeny(output, lockfile, configFile)
+	})
+	return t.executeWith(ctx, t, parser, files, options)
+}
+
+// denyConfig returns the config file if it is a cargo-deny config rather
+// than the lockfile listed among the config patterns.
+func denyConfig(configFile string) string {
+	if configFile == "" || filepath.Base(configFile) == "Cargo.lock" {
+		return ""
+	}
+	return configFile
+}
+
+// outputParserFunc adapts a function to the OutputParser interface.
+type outputParserFunc func(output string) []Issue
+
+// ParseOutput calls f.
+func (f outputParserFunc) ParseOutput(output string) []Issue {
+	return f(output)
+}
+
+// rustAdvisory is a RustSec advisory as reported by cargo-audit and cargo-deny.
+type rustAdvisory struct {
+	ID      string   `json:"id"`
+	Title   string   `json:"title"`
+	Aliases []string `json:"aliases"`
+}
+
+// notes returns the aliases and the advisory page as issue notes.
+func (a *rustAdvisory) notes() []string {
+	var notes []string
+	if len(a.Aliases) > 0 {
+		notes = append(notes, "aliases: "+strings.Join(a.Aliases, ", "))
+	}
+	if strings.HasPrefix(a.ID, "RUSTSEC-") {
+		notes = append(notes, "advisory: https://rustsec.org/advisories/"+a.ID)
+	}
+	return notes
+}
+
+// rustCrate identifies a crate in cargo-audit and cargo-deny output.
+type rustCrate struct {
+	Name    string `json:"name"`
+	Version string `json:"version"`
+}
+
+// cargoAuditFinding is a vulnerability or warning (unmaintained, unsound,
+// yanked) reported by cargo-audit.
+type cargoAuditFinding struct {
+	Kind     string        `json:"kind"`
+	Advisory *rustAdvisory `json:"advisory"`
+	Package  rustCrate     `json:"package"`
+	Versions *struct {
+		Patched []string `json:"patched"`
+	} `json:"versions"`
+}
+
+// parseCargoAudit parses `cargo audit --json` output into one issue per
+// vulnerability (errors) and warning, with advisory IDs as rules.
+func parseCargoAudit(output string, lockfile *cargoLockfile) []Issue {
+	var report struct {
+		Vulnerabilities struct {
+			List []cargoAuditFinding `json:"list"`
+		} `json:"vulnerabilities"`
+		Warnings map[string][]cargoAuditFinding `json:"warnings"`
+	}
+
+	start := strings.Index(output, "{")
+	if start < 0 || json.Unmarshal([]byte(output[start:]), &report) != nil {
+		return []Issue{}
+	}
+
+	issues := make([]Issue, 0, len(report.Vulnerabilities.List))
+	add := func(finding cargoAuditFinding, severity string) {
+		issue := Issue{Severity: severity, Rule: finding.Kind}
+		issue.File, issue.Line = lockfile.locate(finding.Package.Name, finding.Package.Version)
+
+		crate := finding.Package.Name + " " + finding.Package.Version
+		if finding.Advisory != nil {
+			issue.Rule = finding.Advisory.ID
+			issue.Message = crate + ": " + finding.Advisory.Title
+			issue.Notes = finding.Advisory.notes()
+		} else {
+			issue.Message = fmt.Sprintf("%s is %s", crate, finding.Kind)
+		}
+		if finding.Versions != nil && len(finding.Versions.Patched) > 0 {
+			issue.Suggestion = "upgrade to " + strings.Join(finding.Versions.Patched, " or ")
+		}
+
+		issues = append(issues, issue)
+	}
+
+	for _, finding := range report.Vulnerabilities.List {
+		add(finding, severityError)
+	}
+
+	kinds := make([]string, 0, len(report.Warnings))
+	for kind := range report.Warnings {
+		kinds = append(kinds, kind)
+	}
+	sort.Strings(kinds)
+	for _, kind := range kinds {
+		for _, finding := range report.Warnings[kind] {
+			if finding.Kind == "" {
+				finding.Kind = kind
+			}
+			add(finding, severityWarning)
+		}
+	}
+
+	return issues
+}
+
+// cargoDenyMessage is a line of `cargo deny --format json` output.
+type cargoDenyMessage struct {
+	Type   string `json:"type"`
+	Fields struct {
+		Severity string        `json:"severity"`
+		Code     string        `json:"code"`
+		Message  string        `json:"message"`
+		Notes    []string      `json:"notes"`
+		Advisory *rustAdvisory `json:"advisory"`
+		Labels   []struct {
+			Line   int `json:"line"`
+			Column int `json:"column"`
+		} `json:"labels"`
+		Graphs []struct {
+			Krate *rustCrate `json:"Krate"`
+		} `json:"graphs"`
+	} `json:"fields"`
+}
+
+// parseCargoDeny parses the JSON lines of cargo-deny diagnostics. Findings
+// about a crate point at the crate, the others at the configuration file.
+func parseCargoDeny(output string, lockfile *cargoLockfile, configFile string) []Issue {
+	var issues []Issue
+	for _, line := range strings.Split(output, "\n") {
+		var msg cargoDenyMessage
+		if json.Unmarshal([]byte(strings.TrimSpace(line)), &msg) != nil || msg.Type != "diagnostic" {
+			continue
+		}
+		fields := msg.Fields
+
+		issue := Issue{Rule: fields.Code, Message: fields.Message}
+		switch fields.Severity {
+		case "error", "warning":
+			issue.Severity = fields.Severity
+		case "warn":
+			issue.Severity = severityWarning
+		default:
+			issue.Severity = severityInfo
+		}
+
+		if len(fields.Graphs) > 0 && fields.Graphs[0].Krate != nil {
+			crate := fields.Graphs[0].Krate
+			issue.File, issue.Line = lockfile.locate(crate.Name, crate.Version)
+			issue.Message = crate.Name + " " + crate.Version + ": " + fields.Message
+		} else {
+			issue.File = configFile
+			if len(fields.Labels) > 0 {
+				issue.Line, issue.Column = fields.Labels[0].Line, fields.Labels[0].Column
+			}
+		}
+
+		if fields.Advisory != nil {
+			issue.Rule = fields.Advisory.ID
+		}
+		for _, note := range fields.Notes {
+			if solution, ok := strings.CutPrefix(note, "Solution: "); ok {
+				issue.Suggestion = solution
+			} else {
+				issue.Notes = append(issue.Notes, note)
+			}
+		}
+
+		issues = append(issues, issue)
+	}
+
+	if issues == nil {
+		return []Issue{}
+	}
+	return issues
+}
+
+// CargoMacheteTool implements unused dependency detection using cargo-machete.
+type CargoMacheteTool struct {
+	*BaseTool
+}
+
+// NewCargoMacheteTool creates a new cargo-machete tool.
+func NewCargoMacheteTool() *CargoMacheteTool {
+	tool := &CargoMacheteTool{
+		BaseTool: NewBaseTool("cargo-machete", "Rust", "cargo-machete", LINT),
+	}
+
+	tool.Bind(tool)
+	// cargo-machete exits 1 when unused dependencies are found
+	tool.SetFindingExitCodes(1)
+	tool.AddPackage(PackageCargo, "cargo-machete")
+	tool.SetConfigPatterns([]string{".cargo-machete.toml", "Cargo.toml"})
+
+	return tool
+}
+
+// BuildCommand builds the cargo-machete command. The binary is called the
+// way cargo calls it, so the tool is only available when it is installed.
+func (t *CargoMacheteTool) BuildCommand(files []string, options ExecuteOptions) *exec.Cmd {
+	args := []string{"machete"}
+
+	// Remove the unused entries from the manifests
+	if options.Fix {
+		args = append(args, "--fix")
+	}
+
+	// Add extra flags if provided
+	args = append(args, options.ExtraArgs...)
+
+	cmd := exec.Command(t.executable, args...)
+
+	if options.ProjectRoot != "" {
+		cmd.Dir = options.ProjectRoot
+	}
+
+	return cmd
+}
+
+// ProjectInputs returns the manifests whose dependencies are checked.
+func (t *CargoMacheteTool) ProjectInputs(files []string, projectRoot string) []string {
+	return cargoProjectInputs(files, projectRoot)
+}
+
+// ParseOutput parses the cargo-machete report, resolving manifests against
+// the working directory.
+func (t *CargoMacheteTool) ParseOutput(output string) []Issue {
+	return parseCargoMachete(output, "")
+}
+
+// Execute runs cargo-machete and locates the unused dependencies in the
+// manifests below the project root.
+func (t *CargoMacheteTool) Execute(ctx context.Context, files []string, options ExecuteOptions) (*Result, error) {
+	parser := outputParserFunc(func(output string) []Issue {
+		return parseCargoMachete(output, options.ProjectRoot)
+	})
+	return t.executeWith(ctx, t, parser, files, options)
+}
+
+// CargoUdepsTool implements unused dependency detection using cargo-udeps,
+// which builds the crates and so needs a nightly toolchain.
+type CargoUdepsTool struct {
+	*BaseTool
+}
+
+// NewCargoUdepsTool creates a new cargo-udeps tool.
+func NewCargoUdepsTool() *CargoUdepsTool {
+	tool := &CargoUdepsTool{
+		BaseTool: NewBaseTool("cargo-udeps", "Rust", "cargo-udeps", LINT),
+	}
+
+	tool.Bind(tool)
+	// cargo-udeps exits 1 when unused dependencies are found
+	tool.SetFindingExitCodes(1)
+	tool.AddPackage(PackageCargo, "cargo-udeps")
+	// cargo runs the subcommand binary as "cargo-udeps udeps ..."
+	tool.SetVersionCommand("udeps", "--version")
+	tool.SetConfigPatterns([]string{"Cargo.toml"})
+
+	return tool
+}
+
+// BuildCommand builds the cargo-udeps command, selecting an installed
+// nightly toolchain unless the project already uses one.
+func (t *CargoUdepsTool) BuildCommand(files []string, options ExecuteOptions) *exec.Cmd {
+	toolchain, _ := udepsToolchain(context.Background(), options)
+	return t.buildCommand(files, options, toolchain)
+}
+
+// buildCommand builds the cargo-udeps command run with toolchain, if any.
+func (t *CargoUdepsTool) buildCommand(files []string, options ExecuteOptions, toolchain string) *exec.Cmd {
+	args := []string{"udeps", "--output", "json", "--all-targets"}
+
+	// Only check the workspace members owning the changed files
+	args = append(args, cargoPackageArgs(runnerOf(options), "cargo", files, options.ProjectRoot)...)
+
+	// Add extra flags if provided
+	args = append(args, options.ExtraArgs...)
+
+	cmd := exec.Command(t.executable, args...)
+
+	if options.ProjectRoot != "" {
+		cmd.Dir = options.ProjectRoot
+	}
+
+	// The nightly selected for cargo-udeps overrides a non-nightly RUSTUP_TOOLCHAIN
+	var vars []string
+	if toolchain != "" {
+		vars = append(vars, "RUSTUP_TOOLCHAIN="+toolchain)
+	}
+	cmd.Env = commandEnv(options, vars...)
+
+	return cmd
+}
+
+// ProjectInputs returns the manifests and lockfile the dependencies are resolved from.
+func (t *CargoUdepsTool) ProjectInputs(files []string, projectRoot string) []string {
+	return cargoProjectInputs(files, projectRoot)
+}
+
+// ParseOutput parses the cargo-udeps JSON report, resolving manifests
+// against the working directory.
+func (t *CargoUdepsTool) ParseOutput(output string) []Issue {
+	return parseCargoUdeps(output, "")
+}
+
+// Execute runs cargo-udeps, or skips it when rustup has no nightly toolchain.
+// rustup is asked once; the command is built with its answer.
+func (t *CargoUdepsTool) Execute(ctx context.Context, files []string, options ExecuteOptions) (*Result, error) {
+	toolchain, ok := udepsToolchain(ctx, options)
+	if !ok {
+		return &Result{
+			Tool:     t.name,
+			Language: t.language,
+			Success:  false,
+			Status:   StatusSkipped,
+			ExitCode: -1,
+			Error:    "cargo-udeps requires a nightly toolchain",
+		}, nil
+	}
+
+	if result := t.preloadCargoMetadata(ctx, "cargo", options); result != nil {
+		return result, nil
+	}
+
+	parser := outputParserFunc(func(output string) []Issue {
+		return parseCargoUdeps(output, options.ProjectRoot)
+	})
+	return t.executeWith(ctx, udepsRun{tool: t, toolchain: toolchain}, parser, files, options)
+}
+
+// udepsRun builds the cargo-udeps command with an already selected toolchain.
+type udepsRun struct {
+	tool      *CargoUdepsTool
+	toolchain string
+}
+
+// BuildCommand builds the cargo-udeps command with the run's toolchain.
+func (r udepsRun) BuildCommand(files []string, options ExecuteOptions) *exec.Cmd {
+	return r.tool.buildCommand(files, options, r.toolchain)
+}
+
+// udepsToolchain returns the nightly toolchain cargo-udeps should be run
+// with, or "" when the selected toolchain (RUSTUP_TOOLCHAIN, from options.Env
+// or the environment, or a pinned one) is already a nightly or rustup is not
+// installed. It returns false when rustup lists no nightly toolchain.
+func udepsToolchain(ctx context.Context, options ExecuteOptions) (string, bool) {
+	selected := lookupEnv(options, "RUSTUP_TOOLCHAIN")
+	if selected == "" {
+		selected = rustToolchain(absPath(".", options.ProjectRoot))
+	}
+	if strings.HasPrefix(selected, "nightly") {
+		return "", true
+	}
+
+	output, err := runnerOf(options).Run(ctx, Invocation{Args: []string{"rustup", "toolchain", "list"}})
+	if err != nil {
+		return "", true
+	}
+	for _, line := range strings.Split(string(output.Stdout), "\n") {
+		if fields := strings.Fields(line); len(fields) > 0 && strings.HasPrefix(fields[0], "nightly") {
+			return fields[0], true
+		}
+	}
+	return "", false
+}
+
+// parseCargoMachete parses the cargo-machete report, which lists unused
+// dependencies below a line naming each crate and its manifest:
+//
+//	cargo-machete found the following unused dependencies in .:
+//	app -- ./crates/app/Cargo.toml:
+//		serde
+func parseCargoMachete(output, projectRoot string) []Issue {
+	issues := []Issue{}
+	manifest := ""
+	for _, line := range strings.Split(output, "\n") {
+		trimmed := strings.TrimSpace(line)
+		switch {
+		case trimmed == "":
+		case strings.HasPrefix(line, "\t") || strings.HasPrefix(line, " "):
+			if manifest != "" {
+				issues = append(issues, unusedDependencyIssue(projectRoot, manifest, trimmed, ""))
+			}
+		case strings.Contains(trimmed, " -- ") && strings.HasSuffix(trimmed, ":"):
+			_, path, _ := strings.Cut(strings.TrimSuffix(trimmed, ":"), " -- ")
+			manifest = path
+		default:
+			manifest = ""
+		}
+	}
+	return issues
+}
+
+// parseCargoUdeps parses `cargo udeps --output json`, which reports unused
+// dependencies per crate and dependency kind.
+func parseCargoUdeps(output, projectRoot string) []Issue {
+	var report struct {
+		UnusedDeps map[string]struct {
+			ManifestPath string   `json:"manifest_path"`
+			Normal       []string `json:"normal"`
+			Development  []string `json:"development"`
+			Build        []string `json:"build"`
+		} `json:"unused_deps"`
+	}
+
+	start := strings.Index(output, "{")
+	if start < 0 || json.Unmarshal([]byte(output[start:]), &report) != nil {
+		return []Issue{}
+	}
+
+	crates := make([]string, 0, len(report.UnusedDeps))
+	for crate := range report.UnusedDeps {
+		crates = append(crates, crate)
+	}
+	sort.Strings(crates)
+
+	issues := []Issue{}
+	for _, crate := range crates {
+		unused := report.UnusedDeps[crate]
+		kinds := []struct {
+			table string
+			names []string
+		}{
+			{"dependencies", unused.Normal},
+			{"dev-dependencies", unused.Development},
+			{"build-dependencies", unused.Build},
+		}
+		for _, kind := range kinds {
+			for _, name := range kind.names {
+				issues = append(issues, unusedDependencyIssue(projectRoot, unused.ManifestPath, name, kind.table))
+			}
+		}
+	}
+	return issues
+}
+
+// CargoSemverChecksTool implements public API break detection for library
+// crates using cargo-semver-checks, comparing against the --since commit.
+type CargoSemverChecksTool struct {
+	*BaseTool
+}
+
+// NewCargoSemverChecksTool creates a new cargo-semver-checks tool.
+func NewCargoSemverChecksTool() *CargoSemverChecksTool {
+	tool := &CargoSemverChecksTool{
+		BaseTool: NewBaseTool("cargo-semver-checks", "Rust", "cargo-semver-checks", LINT),
+	}
+
+	tool.Bind(tool)
+	// cargo-semver-checks exits 1 when the version bump misses a breaking change
+	tool.SetFindingExitCodes(1)
+	tool.AddPackage(PackageCargo, "cargo-semver-checks")
+	// cargo runs the subcommand binary as "cargo-semver-checks semver-checks ..."
+	tool.SetVersionCommand("semver-checks", "--version")
+	tool.SetConfigPatterns([]string{"Cargo.toml"})
+
+	return tool
+}
+
+// BuildCommand builds the cargo-semver-checks command, letting it check out
+// the base commit itself; Execute provides the baseline as a git worktree.
+func (t *CargoSemverChecksTool) BuildCommand(files []string, options ExecuteOptions) *exec.Cmd {
+	return t.buildCommand(files, options, "")
+}
+
+// buildCommand builds the cargo-semver-checks command comparing against the
+// crates in baselineRoot, or against options.BaseRef when it is empty.
+func (t *CargoSemverChecksTool) buildCommand(files []string, options ExecuteOptions, baselineRoot string) *exec.Cmd {
+	args := []string{"semver-checks"}
+
+	if baselineRoot != "" {
+		args = append(args, "--baseline-root", baselineRoot)
+	} else if options.BaseRef != "" {
+		args = append(args, "--baseline-rev", options.BaseRef)
+	}
+
+	// Only check the library crates owning the changed files
+	for _, crate := range libraryCrates(files, options.ProjectRoot) {
+		args = append(args, "--package", crate.Name)
+	}
+
+	// Add extra flags if provided
+	args = append(args, options.ExtraArgs...)
+
+	cmd := exec.Command(t.executable, args...)
+
+	if options.ProjectRoot != "" {
+		cmd.Dir = options.ProjectRoot
+	}
+
+	return cmd
+}
+
+// ParseOutput parses the cargo-semver-checks report, resolving paths against
+// the working directory.
+func (t *CargoSemverChecksTool) ParseOutput(output string) []Issue {
+	return parseCargoSemverChecks(output, "", "", nil)
+}
+
+// Execute checks out the base commit into a temporary git worktree and runs
+// cargo-semver-checks against it. It skips when there is no base commit or
+// no library crate changed.
+func (t *CargoSemverChecksTool) Execute(ctx context.Context, files []string, options ExecuteOptions) (*Result, error) {
+	if options.BaseRef == "" {
+		return t.notRun(StatusSkipped, "cargo-semver-checks needs a base commit (--since)"), nil
+	}
+	crates := libraryCrates(files, options.ProjectRoot)
+	if len(crates) == 0 {
+		return t.notRun(StatusSkipped, "no library crate changed"), nil
+	}
+	if !t.IsAvailable() {
+		// Reported as skipped without checking out the baseline
+		return t.executeWith(ctx, t, t, files, options)
+	}
+	if _, dryRun := options.Runner.(*DryRunRunner); dryRun {
+		// A dry run shows the command checking out the base commit itself
+		return t.executeWith(ctx, t, t, files, options)
+	}
+
+	baselineRoot, cleanup, err := semverBaseline(options.ProjectRoot, options.BaseRef)
+	if err != nil {
+		return t.notRun(StatusToolError, err.Error()), nil //nolint:nilerr // the failure is reported in the result
+	}
+	defer cleanup()
+
+	parser := outputParserFunc(func(output string) []Issue {
+		return parseCargoSemverChecks(output, options.ProjectRoot, baselineRoot, crates)
+	})
+	return t.executeWith(ctx, semverBaselineRun{tool: t, baselineRoot: baselineRoot}, parser, files, options)
+}
+
+// ProjectInputs returns the Cargo manifests and lockfiles along with every
+// source file of the changed library crates, since a public API break may
+// surface in a file that did not change. The base ref keys the cache
+// through options.BaseRef.
+func (t *CargoSemverChecksTool) ProjectInputs(files []string, projectRoot string) []string {
+	inputs := cargoProjectInputs(files, projectRoot)
+	for _, crate := range libraryCrates(files, projectRoot) {
+		_ = filepath.Walk(filepath.Join(crate.Dir, "src"), func(path string, info os.FileInfo, err error) error {
+			if err == nil && !info.IsDir() && filepath.Ext(path) == ".rs" {
+				inputs = append(inputs, path)
+			}
+			return nil
+		})
+	}
+
+	sort.Strings(inputs)
+	return inputs
+}
+
+// notRun returns the result of a run that could not start.
+func (t *CargoSemverChecksTool) notRun(status Status, reason string) *Result {
+	return &Result{
+		Tool:     t.name,
+		Language: t.language,
+		Success:  false,
+		Status:   status,
+		ExitCode: -1,
+		Error:    reason,
+	}
+}
+
+// semverBaselineRun builds the cargo-semver-checks command for a checked out
+// baseline.
+type semverBaselineRun struct {
+	tool         *CargoSemverChecksTool
+	baselineRoot string
+}
+
+// BuildCommand builds the cargo-semver-checks command with the run's baseline.
+func (r semverBaselineRun) BuildCommand(files []string, options ExecuteOptions) *exec.Cmd {
+	return r.tool.buildCommand(files, options, r.baselineRoot)
+}
+
+// libraryCrates returns the crates owning the given .rs files that have a
+// library target, the only kind with a public API to break.
+func libraryCrates(files []string, projectRoot string) []RustCrate {
+	var crates []RustCrate
+	for _, crate := range RustCrates(FilterFilesByExtensions(files, []string{".rs"}), projectRoot) {
+		if crate.Name == "" {
+			continue
+		}
+		manifest, err := readTOML(filepath.Join(crate.Dir, "Cargo.toml"))
+		if err != nil {
+			continue
+		}
+		if _, err := os.Stat(filepath.Join(crate.Dir, "src", "lib.rs")); err == nil || manifest.HasTable("lib") {
+			crates = append(crates, crate)
+		}
+	}
+	return crates
+}
+
+// semverBaseline checks out baseRef into a temporary git worktree and returns
+// the directory corresponding to projectRoot in it, along with a function
+// removing the worktree again.
+func semverBaseline(projectRoot, baseRef string) (string, func(), error) {
+	root := resolvePath(absPath(".", projectRoot))
+	gitUtils := git.NewGitUtils(root)
+
+	repoRoot, err := gitUtils.GetRepositoryRoot()
+	if err != nil {
+		return "", nil, err
+	}
+	rel, err := filepath.Rel(resolvePath(repoRoot), root)
+	if err != nil {
+		return "", nil, fmt.Errorf("failed to locate %s in the repository: %w", root, err)
+	}
+
+	tempDir, err := os.MkdirTemp("", "gz-quality-semver-")
+	if err != nil {
+		return "", nil, fmt.Errorf("failed to create baseline directory: %w", err)
+	}
+	worktree := filepath.Join(resolvePath(tempDir), "baseline")
+	if err := gitUtils.AddWorktree(baseRef, worktree); err != nil {
+		_ = os.RemoveAll(tempDir)
+		return "", nil, err
+	}
+
+	cleanup := func() {
+		_ = gitUtils.RemoveWorktree(worktree)
+		_ = os.RemoveAll(tempDir)
+	}
+	return filepath.Join(worktree, rel), cleanup, nil
+}
+
+var (
+	// semverLintPattern matches the header of a failed lint, e.g.
+	// "--- failure function_missing: pub fn removed or renamed ---"
+	semverLintPattern = regexp.MustCompile(`^--- (failure|warning) ([\w-]+): (.+) ---$`)
+
+	// semverItemPattern matches an affected item and its location, e.g.
+	// "function demo::foo, previously in file /path/src/lib.rs:3"
+	semverItemPattern = regexp.MustCompile(`^(.+?),? (?:previously )?in (?:file )?(\S+):(\d+)$`)
+)
+
+// parseCargoSemverChecks parses the cargo-semver-checks report, which lists
+// the items affected by each failed lint:
+//
+//	--- failure function_missing: pub fn removed or renamed ---
+//
+//	Description:
+//	A publicly-visible function cannot be imported by its prior path.
+//	        ref: https://doc.rust-lang.org/cargo/reference/semver.html#item-remove
+//
+//	Failed in:
+//	  function demo::foo, previously in file /path/src/lib.rs:3
+//
+// Items of the baseline are located at the same path in the project; items
+// without a location point at the manifest of the crate being checked.
+func parseCargoSemverChecks(output, projectRoot, baselineRoot string, crates []RustCrate) []Issue {
+	root := resolvePath(absPath(".", projectRoot))
+	if baselineRoot != "" {
+		baselineRoot = resolvePath(baselineRoot)
+	}
+
+	relative := func(file string) string {
+		if !filepath.IsAbs(file) {
+			return file
+		}
+		file = resolvePath(file)
+		if baselineRoot != "" {
+			if rel, err := filepath.Rel(baselineRoot, file); err == nil && rel != ".." && !strings.HasPrefix(rel, ".."+string(filepath.Separator)) {
+				return rel
+			}
+		}
+		if rel, err := filepath.Rel(root, file); err == nil {
+			return rel
+		}
+		return file
+	}
+
+	manifest := "Cargo.toml"
+	var severity, rule, title, description, reference, section string
+	items := 0
+
+	issues := []Issue{}
+	for _, line := range strings.Split(output, "\n") {
+		trimmed := strings.TrimSpace(line)
+
+		if name, ok := strings.CutPrefix(trimmed, "Checking "); ok {
+			// "Checking demo v0.1.0 -> v0.2.0 (minor change)"
+			name, _, _ = strings.Cut(name, " ")
+			for _, crate := range crates {
+				if crate.Name == name {
+					manifest = relative(filepath.Join(crate.Dir, "Cargo.toml"))
+				}
+			}
+			continue
+		}
+		if match := semverLintPattern.FindStringSubmatch(trimmed); match != nil {
+			severity, rule, title = severityError, match[2], match[3]
+			if match[1] == "warning" {
+				severity = severityWarning
+			}
+			description, reference, section, items = "", "", "", 0
+			continue
+		}
+
+		switch {
+		case rule == "":
+		case trimmed == "Description:" || trimmed == "Failed in:":
+			section = trimmed
+		case trimmed == "":
+			// The list of affected items ends the lint's report
+			if section == "Failed in:" && items > 0 {
+				rule = ""
+			}
+		case section == "Description:":
+			if url, ok := strings.CutPrefix(trimmed, "ref: "); ok {
+				reference = url
+			} else if !strings.HasPrefix(trimmed, "impl: ") {
+				description = strings.TrimSpace(description + " " + trimmed)
+			}
+		case section == "Failed in:":
+			issue := Issue{
+				File:     manifest,
+				Severity: severity,
+				Rule:     rule,
+				Message:  fmt.Sprintf("%s: %s", title, trimmed),
+			}
+			if match := semverItemPattern.FindStringSubmatch(trimmed); match != nil {
+				issue.Message = fmt.Sprintf("%s: %s", title, match[1])
+				issue.File = relative(match[2])
+				issue.Line, _ = strconv.Atoi(match[3])
+			}
+			if description != "" {
+				issue.Notes = append(issue.Notes, description)
+			}
+			if reference != "" {
+				issue.Notes = append(issue.Notes, "see "+reference)
+			}
+			issues = append(issues, issue)
+			items++
+		}
+	}
+	return issues
+}
+
+// Fingerprint includes the active rustup toolchain; see rustupFingerprint.
+func (t *RustfmtTool) Fingerprint() (string, bool) {
+	return rustupFingerprint(t.executable)
+}
+
+// Fingerprint includes the active rustup toolchain; see rustupFingerprint.
+func (t *ClippyTool) Fingerprint() (string, bool) {
+	return rustupFingerprint(t.executable)
+}
+
+// Fingerprint includes the active rustup toolchain; see rustupFingerprint.
+func (t *CargoFmtTool) Fingerprint() (string, bool) {
+	return rustupFingerprint(t.executable)
+}
+
+// Fingerprint includes the active rustup toolchain; see rustupFingerprint.
+func (t *CargoCheckTool) Fingerprint() (string, bool) {
+	return rustupFingerprint(t.executable)
+}
+
+// Fingerprint includes the active rustup toolchain; see rustupFingerprint.
+func (t *RustdocTool) Fingerprint() (string, bool) {
+	return rustupFingerprint(t.executable)
+}
+
+// rustupFingerprint fingerprints a Rust executable together with the rustup
+// state selecting its toolchain. cargo and rustfmt are usually rustup proxies
+// whose binary stays the same when the toolchain is updated or overridden.
+func rustupFingerprint(executable string) (string, bool) {
+	fingerprint, ok := fileFingerprint(executable)
+	if !ok {
+		return "", false
+	}
+
+	rustupHome := os.Getenv("RUSTUP_HOME")
+	if rustupHome == "" {
+		home, err := os.UserHomeDir()
+		if err != nil {
+			return "", false
+		}
+		rustupHome = filepath.Join(home, ".rustup")
+	}
+
+	// rustup rewrites update-hash/<toolchain> on every toolchain update
+	paths := []string{filepath.Join(rustupHome, "settings.toml"), "rust-toolchain.toml", "rust-toolchain"}
+	updateHashDir := filepath.Join(rustupHome, "update-hash")
+	if entries, err := os.ReadDir(updateHashDir); err == nil {
+		for _, entry := range entries {
+			paths = append(paths, filepath.Join(updateHashDir, entry.Name()))
+		}
+	}
+
+	state := []string{fingerprint, "RUSTUP_TOOLCHAIN=" + os.Getenv("RUSTUP_TOOLCHAIN")}
+	for _, path := range paths {
+		if info, err := os.Stat(path); err == nil {
+			state = append(state, fmt.Sprintf("%s|%d|%d", path, info.ModTime().UnixNano(), info.Size()))
+		}
+	}
+	return strings.Join(state, ";"), true
+}
+
+// OmitsFiles reports that clippy checks workspace packages, not files.
+func (t *ClippyTool) OmitsFiles() bool {
+	return true
+}
+
+// ProjectInputs returns the workspace sources and manifests, since clippy
+// reports on whole crates rather than the given files.
+func (t *ClippyTool) ProjectInputs(files []string, projectRoot string) []string {
+	return cargoSourceInputs(files, projectRoot)
+}
+
+// OmitsFiles reports that cargo check checks workspace packages, not files.
+func (t *CargoCheckTool) OmitsFiles() bool {
+	return true
+}
+
+// ProjectInputs returns the workspace sources and manifests, since cargo check
+// reports on whole crates rather than the given files.
+func (t *CargoCheckTool) ProjectInputs(files []string, projectRoot string) []string {
+	return cargoSourceInputs(files, projectRoot)
+}
+
+// OmitsFiles reports that cargo doc documents workspace packages, not files.
+func (t *RustdocTool) OmitsFiles() bool {
+	return true
+}
+
+// ProjectInputs returns the workspace sources and manifests, since cargo doc
+// reports on whole crates rather than the given files.
+func (t *RustdocTool) ProjectInputs(files []string, projectRoot string) []string {
+	return cargoSourceInputs(files, projectRoot)
+}
+
+// OmitsFiles reports that cargo fmt formats workspace packages, not files.
+func (t *CargoFmtTool) OmitsFiles() bool {
+	return true
+}
+
+// ProjectInputs returns the workspace sources and manifests, since cargo fmt
+// reports on whole crates rather than the given files.
+func (t *CargoFmtTool) ProjectInputs(files []string, projectRoot string) []string {
+	return cargoSourceInputs(files, projectRoot)
+}
+
+// OmitsFiles reports that cargo audit checks Cargo.lock, not files.
+func (t *CargoAuditTool) OmitsFiles() bool {
+	return true
+}
+
+// OmitsFiles reports that cargo deny checks the dependency graph, not files.
+func (t *CargoDenyTool) OmitsFiles() bool {
+	return true
+}
+
+// OmitsFiles reports that cargo machete checks the manifests, not files.
+func (t *CargoMacheteTool) OmitsFiles() bool {
+	return true
+}
+
+// OmitsFiles reports that cargo udeps checks workspace packages, not files.
+func (t *CargoUdepsTool) OmitsFiles() bool {
+	return true
+}
+
+// OmitsFiles reports that cargo semver-checks checks library packages, not files.
+func (t *CargoSemverChecksTool) OmitsFiles() bool {
+	return true
+}
+
+// Ensure Rust tools implement QualityTool interface.
+var (
+	_ QualityTool = (*RustfmtTool)(nil)
+	_ QualityTool = (*ClippyTool)(nil)
+	_ QualityTool = (*CargoCheckTool)(nil)
+	_ QualityTool = (*RustdocTool)(nil)
+	_ QualityTool = (*CargoFmtTool)(nil)
+	_ QualityTool = (*CargoAuditTool)(nil)
+	_ QualityTool = (*CargoDenyTool)(nil)
+	_ QualityTool = (*CargoMacheteTool)(nil)
+	_ QualityTool = (*CargoUdepsTool)(nil)
+	_ QualityTool = (*CargoSemverChecksTool)(nil)
+
+	_ FormatChecker = (*RustfmtTool)(nil)
+	_ FormatChecker = (*CargoFmtTool)(nil)
+
+	_ Fingerprinter = (*RustfmtTool)(nil)
+	_ Fingerprinter = (*ClippyTool)(nil)
+	_ Fingerprinter = (*CargoCheckTool)(nil)
+	_ Fingerprinter = (*RustdocTool)(nil)
+	_ Fingerprinter = (*CargoFmtTool)(nil)
+
+	_ Configurable = (*ClippyTool)(nil)
+	_ Configurable = (*RustdocTool)(nil)
+	_ Configurable = (*CargoAuditTool)(nil)
+	_ Configurable = (*CargoDenyTool)(nil)
+
+	_ Superseded = (*CargoCheckTool)(nil)
+
+	_ ProjectScoped = (*ClippyTool)(nil)
+	_ ProjectScoped = (*CargoCheckTool)(nil)
+	_ ProjectScoped = (*RustdocTool)(nil)
+	_ ProjectScoped = (*CargoFmtTool)(nil)
+	_ ProjectScoped = (*CargoAuditTool)(nil)
+	_ ProjectScoped = (*CargoDenyTool)(nil)
+	_ ProjectScoped = (*CargoMacheteTool)(nil)
+	_ ProjectScoped = (*CargoUdepsTool)(nil)
+	_ ProjectScoped = (*CargoSemverChecksTool)(nil)
+
+	_ FilelessCommand = (*ClippyTool)(nil)
+	_ FilelessCommand = (*CargoCheckTool)(nil)
+	_ FilelessCommand = (*RustdocTool)(nil)
+	_ FilelessCommand = (*CargoFmtTool)(nil)
+	_ FilelessCommand = (*CargoAuditTool)(nil)
+	_ FilelessCommand = (*CargoDenyTool)(nil)
+	_ FilelessCommand = (*CargoMacheteTool)(nil)
+	_ FilelessCommand = (*CargoUdepsTool)(nil)
+	_ FilelessCommand = (*CargoSemverChecksTool)(nil)
 )
@@ -6,10 +6,14 @@ package tools
 import (
 	"context"
 	"os"
+	"os/exec"
 	"path/filepath"
+	"strings"
 	"testing"
 
+	"github.com/Gizzahub/gzh-cli-quality/internal/testutil"
 	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
 )
 
 func TestNewRustfmtTool(t *testing.T) {
@@ -67,6 +71,14 @@ func TestRustfmtTool_BuildCommand(t *testing.T) {
 			},
 			expectedArgs: []string{"--check", "--verbose", "main.rs"},
 		},
+		{
+			name:  "check mode",
+			files: []string{"main.rs"},
+			options: ExecuteOptions{
+				Check: true,
+			},
+			expectedArgs: []string{"--check", "main.rs"},
+		},
 	}
 
 	for _, tt := range tests {
@@ -207,6 +219,65 @@ func TestClippyTool_ParseOutput(t *testing.T) {
 {"reason":"compiler-message","message":{"message":"unused variable: 'y'","code":{"code":"unused_variables"},"level":"warning","spans":[{"file_name":"src/main.rs","line_start":11,"column_start":9}]}}`,
 			expected: 2,
 		},
+		{
+			name: "suggested replacement in help child",
+			output: `{"reason":"compiler-message","message":{"message":"redundant clone","code":{"code":"clippy::redundant_clone"},"level":"warning","spans":[{"file_name":"src/main.rs","byte_start":120,"byte_end":128,"line_start":7,"column_start":14,"suggested_replacement":null}],"children":[{"message":"remove this","spans":[{"file_name":"src/main.rs","byte_start":120,"byte_end":128,"line_start":7,"column_start":14,"suggested_replacement":"","suggestion_applicability":"MachineApplicable"}]}]}}`,
+			expected: 1,
+			checkIssue: func(t *testing.T, issue Issue) {
+				assert.Equal(t, []TextEdit{{
+					File:          "src/main.rs",
+					StartOffset:   120,
+					EndOffset:     128,
+					Applicability: ApplicabilitySafe,
+				}}, issue.Edits)
+			},
+		},
+		{
+			name: "primary span, range, children and rendered text",
+			output: `{"reason":"compiler-message","package_id":"path+file:///ws#app@0.1.0","message":{"message":"this looks like you are swapping","code":{"code":"clippy::almost_swapped"},"level":"error","spans":[{"file_name":"src/lib.rs","byte_start":10,"byte_end":12,"line_start":2,"line_end":2,"column_start":5,"column_end":7,"is_primary":false},{"file_name":"src/lib.rs","byte_start":20,"byte_end":40,"line_start":3,"line_end":4,"column_start":5,"column_end":11,"is_primary":true}],"children":[{"message":"` + "`#[deny(clippy::almost_swapped)]`" + ` on by default","level":"note","spans":[],"children":[]},{"message":"try","level":"help","spans":[{"file_name":"src/lib.rs","byte_start":20,"byte_end":40,"line_start":3,"line_end":4,"column_start":5,"column_end":11,"is_primary":true,"suggested_replacement":"std::mem::swap(&mut a, &mut b)","suggestion_applicability":"MaybeIncorrect"}],"children":[]}],"rendered":"error: this looks like you are swapping\n"}}`,
+			expected: 1,
+			checkIssue: func(t *testing.T, issue Issue) {
+				assert.Equal(t, 3, issue.Line)
+				assert.Equal(t, 5, issue.Column)
+				assert.Equal(t, 4, issue.EndLine)
+				assert.Equal(t, 11, issue.EndColumn)
+				assert.Equal(t, "error", issue.Severity)
+				assert.Equal(t, []string{"note: `#[deny(clippy::almost_swapped)]` on by default"}, issue.Notes)
+				assert.Equal(t, "try: `std::mem::swap(&mut a, &mut b)`", issue.Suggestion)
+				assert.Equal(t, "error: this looks like you are swapping\n", issue.Rendered)
+				assert.Equal(t, []TextEdit{{
+					File:          "src/lib.rs",
+					StartOffset:   20,
+					EndOffset:     40,
+					Replacement:   "std::mem::swap(&mut a, &mut b)",
+					Applicability: ApplicabilityUnsafe,
+				}}, issue.Edits)
+			},
+		},
+		{
+			name: "alternative suggestions in separate help children",
+			output: `{"reason":"compiler-message","message":{"message":"cannot find value","code":{"code":"E0425"},"level":"error","spans":[{"file_name":"src/lib.rs","byte_start":10,"byte_end":12,"line_start":2,"column_start":5,"is_primary":true}],"children":[{"message":"a local variable with a similar name exists","level":"help","spans":[{"file_name":"src/lib.rs","byte_start":10,"byte_end":12,"line_start":2,"column_start":5,"suggested_replacement":"ab","suggestion_applicability":"MaybeIncorrect"}],"children":[]},{"message":"consider importing one of these","level":"help","spans":[{"file_name":"src/lib.rs","byte_start":0,"byte_end":0,"line_start":1,"column_start":1,"suggested_replacement":"use a::x;\n","suggestion_applicability":"MachineApplicable"},{"file_name":"src/lib.rs","byte_start":0,"byte_end":0,"line_start":1,"column_start":1,"suggested_replacement":"use b::x;\n","suggestion_applicability":"MachineApplicable"}],"children":[]}]}}`,
+			expected: 1,
+			checkIssue: func(t *testing.T, issue Issue) {
+				// Only the first machine-applicable alternative becomes an edit
+				assert.Equal(t, []TextEdit{{
+					File:          "src/lib.rs",
+					Replacement:   "use a::x;\n",
+					Applicability: ApplicabilitySafe,
+				}}, issue.Edits)
+				assert.Equal(t, "a local variable with a similar name exists: `ab`\nconsider importing one of these: `use a::x;\n`: `use b::x;\n`", issue.Suggestion)
+			},
+		},
+		{
+			name: "artifacts, build results and duplicate targets",
+			output: `{"reason":"compiler-artifact","package_id":"registry+https://github.com/rust-lang/crates.io-index#serde@1.0.0","target":{"name":"serde"},"filenames":[]}
+{"reason":"build-script-executed","package_id":"path+file:///ws#app@0.1.0","linked_libs":[]}
+{"reason":"compiler-message","message":{"message":"unused import","code":{"code":"unused_imports"},"level":"warning","spans":[{"file_name":"src/lib.rs","line_start":1,"column_start":5,"is_primary":true}]},"target":{"name":"app","kind":["lib"]}}
+{"reason":"compiler-message","message":{"message":"unused import","code":{"code":"unused_imports"},"level":"warning","spans":[{"file_name":"src/lib.rs","line_start":1,"column_start":5,"is_primary":true}]},"target":{"name":"app","kind":["test"]}}
+{"reason":"compiler-message","message":{"message":"1 warning emitted","level":"warning","spans":[]}}
+{"reason":"build-finished","success":true}`,
+			expected: 1,
+		},
 		{
 			name: "invalid JSON - should be skipped",
 			output: `not valid json
@@ -297,6 +368,17 @@ func TestCargoFmtTool_BuildCommand(t *testing.T) {
 	}
 }
 
+func TestCargoFmtTool_BuildCommand_Check(t *testing.T) {
+	tool := NewCargoFmtTool()
+
+	cmd := tool.BuildCommand(nil, ExecuteOptions{Check: true})
+	assert.Equal(t, []string{"fmt", "--", "--check"}, cmd.Args[1:])
+
+	// An existing "--" separator is reused
+	cmd = tool.BuildCommand(nil, ExecuteOptions{Check: true, ExtraArgs: []string{"--all", "--", "--verbose"}})
+	assert.Equal(t, []string{"fmt", "--all", "--", "--verbose", "--check"}, cmd.Args[1:])
+}
+
 func TestRustTools_InterfaceCompliance(t *testing.T) {
 	// Ensure all Rust tools implement QualityTool interface
 	var _ QualityTool = (*RustfmtTool)(nil)
@@ -307,6 +389,9 @@ func TestRustTools_InterfaceCompliance(t *testing.T) {
 		NewRustfmtTool(),
 		NewClippyTool(),
 		NewCargoFmtTool(),
+		NewCargoAuditTool(),
+		NewCargoDenyTool(),
+		NewCargoSemverChecksTool(),
 	}
 
 	for _, tool := range tools {
@@ -352,3 +437,842 @@ func TestClippyTool_BuildCommand_ProjectRoot(t *testing.T) {
 
 	assert.Equal(t, "/test/project", cmd.Dir)
 }
+
+func TestClippyTool_LintLevels(t *testing.T) {
+	lintFlags := func(tool *ClippyTool, root string) []string {
+		args := tool.BuildCommand(nil, ExecuteOptions{ProjectRoot: root}).Args
+		for i, arg := range args {
+			if arg == "--" {
+				return args[i+1:]
+			}
+		}
+		return nil
+	}
+
+	root := t.TempDir()
+	writeCargoWorkspace(t, root, map[string]string{"app": "app"})
+	member := filepath.Join(root, "app")
+
+	// Without settings or a [lints] table warnings are denied
+	tool := NewClippyTool()
+	assert.Equal(t, []string{"-D", "warnings"}, lintFlags(tool, member))
+
+	// The workspace [lints] table is inherited and left alone
+	require.NoError(t, os.WriteFile(filepath.Join(root, "Cargo.toml"), []byte(`[workspace]
+members = [
+    "app", # the only member
+]
+
+[workspace.lints.rust]
+unsafe_code = "forbid"
+
+[workspace.lints.clippy]
+pedantic = { level = "warn", priority = -1 }
+`), 0o644))
+	require.NoError(t, os.WriteFile(filepath.Join(member, "Cargo.toml"), []byte("[package]\nname = \"app\"\n\n[lints]\nworkspace = true\n"), 0o644))
+	assert.Nil(t, lintFlags(tool, member))
+
+	tool.SetSettings(RustSettings{
+		Deny:  []string{"warnings", "clippy::pedantic"},
+		Warn:  []string{"unsafe_code"},
+		Allow: []string{"clippy::too_many_lines"},
+	})
+	assert.Equal(t, []string{"-D", "warnings", "-A", "clippy::too_many_lines"}, lintFlags(tool, member))
+}
+
+func TestClippyTool_BuildCommand_TargetsAndFeatures(t *testing.T) {
+	tool := NewClippyTool()
+	tool.SetSettings(RustSettings{
+		AllTargets: true,
+		Features:   FeatureSet{NoDefaultFeatures: true, Features: []string{"serde", "std"}},
+	})
+
+	cmd := tool.BuildCommand(nil, ExecuteOptions{ProjectRoot: t.TempDir(), ExtraArgs: []string{"--locked"}})
+	assert.Equal(t, []string{
+		"clippy", "--all-targets", "--no-default-features", "--features", "serde,std",
+		"--message-format", "json", "--locked", "--", "-D", "warnings",
+	}, cmd.Args[1:])
+}
+
+func TestClippyTool_Execute_FeatureMatrix(t *testing.T) {
+	binDir := t.TempDir()
+	calls := filepath.Join(t.TempDir(), "calls")
+	common := `{"reason":"compiler-message","message":{"message":"unused import","code":{"code":"unused_imports"},"level":"warning","spans":[{"file_name":"src/lib.rs","line_start":1,"column_start":5,"is_primary":true}]}}`
+	gated := `{"reason":"compiler-message","message":{"message":"needless return","code":{"code":"clippy::needless_return"},"level":"warning","spans":[{"file_name":"src/net.rs","line_start":7,"column_start":9,"is_primary":true}]}}`
+	testutil.WriteExecutable(t, binDir, "cargo", `[ "$1" = metadata ] && exit 1
+echo "$*" >> `+calls+`
+echo '`+common+`'
+case "$*" in *--all-features*) echo '`+gated+`'; exit 101 ;; esac
+`)
+	t.Setenv("PATH", binDir)
+
+	tool := NewClippyTool()
+	tool.SetSettings(RustSettings{FeatureMatrix: []FeatureSet{{NoDefaultFeatures: true}, {AllFeatures: true}}})
+
+	result, err := tool.Execute(context.Background(), nil, ExecuteOptions{ProjectRoot: t.TempDir()})
+	require.NoError(t, err)
+
+	runs, err := os.ReadFile(calls)
+	require.NoError(t, err)
+	assert.Equal(t, "clippy --no-default-features --message-format json -- -D warnings\n"+
+		"clippy --all-features --message-format json -- -D warnings\n", string(runs))
+
+	require.Len(t, result.Issues, 2)
+	assert.Equal(t, "unused_imports", result.Issues[0].Rule)
+	assert.Equal(t, filepath.Join("src", "net.rs"), result.Issues[1].File)
+	assert.Equal(t, StatusIssues, result.Status)
+	assert.Equal(t, 101, result.ExitCode)
+}
+
+func TestClippyTool_ProjectInputs(t *testing.T) {
+	root := resolvePath(t.TempDir())
+	writeRustProject(t, root, map[string]string{
+		"Cargo.toml":                "[workspace]\nmembers = [\"crates/*\"]\n",
+		"Cargo.lock":                "version = 3\n",
+		"rust-toolchain.toml":       "[toolchain]\nchannel = \"1.75\"\n",
+		"crates/core/Cargo.toml":    "[package]\nname = \"app-core\"\n",
+		"crates/core/src/lib.rs":    "pub fn parse() {}\n",
+		"crates/core/src/util.rs":   "pub fn trim() {}\n",
+		"crates/cli/Cargo.toml":     "[package]\nname = \"app-cli\"\n",
+		"crates/cli/build.rs":       "fn main() {}\n",
+		"crates/cli/src/main.rs":    "fn main() {}\n",
+		"target/debug/build/out.rs": "\n",
+		".git/hooks/hook.rs":        "\n",
+	})
+
+	// Findings in main.rs may change when only the core crate it uses changed
+	inputs := NewClippyTool().ProjectInputs([]string{filepath.Join(root, "crates", "cli", "src", "main.rs")}, root)
+	assert.Equal(t, []string{
+		filepath.Join(root, "Cargo.lock"),
+		filepath.Join(root, "Cargo.toml"),
+		filepath.Join(root, "crates", "cli", "Cargo.toml"),
+		filepath.Join(root, "crates", "cli", "build.rs"),
+		filepath.Join(root, "crates", "cli", "src", "main.rs"),
+		filepath.Join(root, "crates", "core", "Cargo.toml"),
+		filepath.Join(root, "crates", "core", "src", "lib.rs"),
+		filepath.Join(root, "crates", "core", "src", "util.rs"),
+		filepath.Join(root, "rust-toolchain.toml"),
+	}, inputs)
+}
+
+// writeRustProject creates files (relative path -> content) below root.
+func writeRustProject(t *testing.T, root string, files map[string]string) {
+	t.Helper()
+	for name, content := range files {
+		path := filepath.Join(root, filepath.FromSlash(name))
+		require.NoError(t, os.MkdirAll(filepath.Dir(path), 0o755))
+		require.NoError(t, os.WriteFile(path, []byte(content), 0o644))
+	}
+}
+
+func TestRustfmtTool_BuildCommand_EditionAndToolchain(t *testing.T) {
+	t.Setenv("RUSTUP_TOOLCHAIN", "")
+	root := t.TempDir()
+	writeRustProject(t, root, map[string]string{
+		"Cargo.toml":          "[workspace]\nmembers = [\"app\"]\n\n[workspace.package]\nedition = \"2021\"\n",
+		"rust-toolchain.toml": "[toolchain]\nchannel = \"1.79.0\"\ncomponents = [\"rustfmt\"]\n",
+		"app/Cargo.toml":      "[package]\nname = \"app\"\nedition = { workspace = true }\n",
+		"rustfmt.toml":        "edition = \"2018\"\n",
+	})
+	tool := NewRustfmtTool()
+
+	cmd := tool.BuildCommand([]string{"app/src/main.rs"}, ExecuteOptions{ProjectRoot: root, Check: true})
+	assert.Equal(t, []string{"--edition", "2021", "--check", "app/src/main.rs"}, cmd.Args[1:])
+	assert.Equal(t, "1.79.0", cmdEnv(cmd, "RUSTUP_TOOLCHAIN"))
+
+	// An edition in the rustfmt config is left to rustfmt
+	cmd = tool.BuildCommand([]string{"app/src/main.rs"}, ExecuteOptions{ProjectRoot: root, ConfigFile: filepath.Join(root, "rustfmt.toml")})
+	assert.Equal(t, []string{"--config-path", filepath.Join(root, "rustfmt.toml"), "app/src/main.rs"}, cmd.Args[1:])
+
+	// RUSTUP_TOOLCHAIN, from the environment or options.Env, overrides
+	// toolchain files
+	t.Setenv("RUSTUP_TOOLCHAIN", "stable")
+	cmd = tool.BuildCommand([]string{"app/src/main.rs"}, ExecuteOptions{ProjectRoot: root})
+	assert.Equal(t, []string{"--edition", "2021", "app/src/main.rs"}, cmd.Args[1:])
+	assert.Nil(t, cmd.Env)
+	t.Setenv("RUSTUP_TOOLCHAIN", "")
+	cmd = tool.BuildCommand([]string{"app/src/main.rs"}, ExecuteOptions{ProjectRoot: root, Env: map[string]string{"RUSTUP_TOOLCHAIN": "beta"}})
+	assert.Equal(t, "beta", cmdEnv(cmd, "RUSTUP_TOOLCHAIN"))
+
+	// Cargo-based tools select the toolchain too, without "+<toolchain>",
+	// which cargo installs other than rustup's reject
+	cmd = NewCargoFmtTool().BuildCommand(nil, ExecuteOptions{ProjectRoot: root})
+	assert.Equal(t, []string{"fmt"}, cmd.Args[1:])
+	assert.Equal(t, "1.79.0", cmdEnv(cmd, "RUSTUP_TOOLCHAIN"))
+	cmd = NewClippyTool().BuildCommand(nil, ExecuteOptions{ProjectRoot: root})
+	assert.Equal(t, "clippy", cmd.Args[1])
+	assert.Equal(t, "1.79.0", cmdEnv(cmd, "RUSTUP_TOOLCHAIN"))
+}
+
+func TestRustfmtTool_Execute_MixedEditions(t *testing.T) {
+	t.Setenv("RUSTUP_TOOLCHAIN", "")
+	binDir := t.TempDir()
+	calls := filepath.Join(t.TempDir(), "calls")
+	testutil.WriteExecutable(t, binDir, "rustfmt", "echo \"$*\" >> "+calls+"\n")
+	t.Setenv("PATH", binDir)
+
+	root := t.TempDir()
+	writeRustProject(t, root, map[string]string{
+		"new/Cargo.toml": "[package]\nname = \"new\"\nedition = \"2021\"\n",
+		"old/Cargo.toml": "[package]\nname = \"old\"\n",
+	})
+
+	files := []string{"new/src/main.rs", "old/src/lib.rs", "new/src/util.rs"}
+	result, err := NewRustfmtTool().Execute(context.Background(), files, ExecuteOptions{ProjectRoot: root})
+	require.NoError(t, err)
+	assert.Equal(t, StatusClean, result.Status)
+	assert.Equal(t, 3, result.FilesProcessed)
+
+	runs, err := os.ReadFile(calls)
+	require.NoError(t, err)
+	assert.Equal(t, "--edition 2021 new/src/main.rs new/src/util.rs\n--edition 2015 old/src/lib.rs\n", string(runs))
+}
+
+// cargoLockFixture is a project depending on time directly and on smallvec
+// through another crate.
+var cargoLockFixture = map[string]string{
+	"Cargo.toml": `[package]
+name = "app"
+version = "0.1.0"
+
+[dependencies]
+time = "0.1"
+`,
+	"Cargo.lock": `version = 3
+
+[[package]]
+name = "app"
+version = "0.1.0"
+
+[[package]]
+name = "smallvec"
+version = "0.6.9"
+
+[[package]]
+name = "smallvec"
+version = "1.6.0"
+
+[[package]]
+name = "time"
+version = "0.1.43"
+`,
+}
+
+func TestCargoAuditTool_BuildCommand(t *testing.T) {
+	root := t.TempDir()
+	writeRustProject(t, root, map[string]string{
+		"Cargo.toml":            "[workspace]\nmembers = [\"crates/app\"]\n",
+		"Cargo.lock":            "version = 3\n",
+		"crates/app/Cargo.toml": "[package]\nname = \"app\"\n",
+	})
+	root = resolvePath(root)
+
+	tool := NewCargoAuditTool()
+	cmd := tool.BuildCommand(nil, ExecuteOptions{ProjectRoot: root})
+	assert.Equal(t, []string{"audit", "--json"}, cmd.Args[1:])
+
+	// Members audit the workspace lockfile, offline with a local database
+	tool.SetAdvisoryDB("/srv/advisory-db")
+	cmd = tool.BuildCommand(nil, ExecuteOptions{ProjectRoot: filepath.Join(root, "crates", "app")})
+	assert.Equal(t, []string{
+		"audit", "--json", "--db", "/srv/advisory-db", "--no-fetch", "--file", filepath.Join(root, "Cargo.lock"),
+	}, cmd.Args[1:])
+}
+
+func TestCargoAuditTool_Execute(t *testing.T) {
+	root := t.TempDir()
+	writeRustProject(t, root, cargoLockFixture)
+
+	report := `{
+  "database": {"advisory-count": 600},
+  "vulnerabilities": {"found": true, "count": 2, "list": [
+    {"advisory": {"id": "RUSTSEC-2021-0003", "title": "Buffer overflow in SmallVec::insert_many", "aliases": ["CVE-2021-25900"]},
+     "versions": {"patched": [">=0.6.14, <1.0.0", ">=1.6.1"]},
+     "package": {"name": "smallvec", "version": "1.6.0"}},
+    {"advisory": {"id": "RUSTSEC-2020-0071", "title": "Potential segfault in the time crate"},
+     "versions": {"patched": []},
+     "package": {"name": "time", "version": "0.1.43"}}
+  ]},
+  "warnings": {
+    "yanked": [{"kind": "yanked", "advisory": null, "package": {"name": "smallvec", "version": "0.6.9"}}],
+    "unmaintained": [{"kind": "unmaintained", "advisory": {"id": "RUSTSEC-2020-0016", "title": "net2 crate has been deprecated"}, "package": {"name": "net2", "version": "0.2.37"}}]
+  }
+}`
+	binDir := t.TempDir()
+	testutil.WriteExecutable(t, binDir, "cargo-audit", "cat <<'EOF'\n"+report+"\nEOF\nexit 1\n")
+	t.Setenv("PATH", binDir)
+
+	result, err := NewCargoAuditTool().Execute(context.Background(), []string{"src/main.rs"}, ExecuteOptions{ProjectRoot: root})
+	require.NoError(t, err)
+	assert.Equal(t, StatusIssues, result.Status)
+	require.Len(t, result.Issues, 4)
+
+	vuln := result.Issues[0]
+	assert.Equal(t, "RUSTSEC-2021-0003", vuln.Rule)
+	assert.Equal(t, "error", vuln.Severity)
+	assert.Equal(t, "smallvec 1.6.0: Buffer overflow in SmallVec::insert_many", vuln.Message)
+	assert.Equal(t, "upgrade to >=0.6.14, <1.0.0 or >=1.6.1", vuln.Suggestion)
+	assert.Equal(t, []string{"aliases: CVE-2021-25900", "advisory: https://rustsec.org/advisories/RUSTSEC-2021-0003"}, vuln.Notes)
+	// Transitive dependencies point at their Cargo.lock entry
+	assert.Equal(t, "Cargo.lock", vuln.File)
+	assert.Equal(t, 12, vuln.Line)
+
+	// Direct dependencies point at their Cargo.toml declaration
+	assert.Equal(t, "RUSTSEC-2020-0071", result.Issues[1].Rule)
+	assert.Equal(t, "Cargo.toml", result.Issues[1].File)
+	assert.Equal(t, 6, result.Issues[1].Line)
+	assert.Empty(t, result.Issues[1].Suggestion)
+
+	// Warnings, sorted by kind
+	assert.Equal(t, "RUSTSEC-2020-0016", result.Issues[2].Rule)
+	assert.Equal(t, "warning", result.Issues[2].Severity)
+	assert.Equal(t, 0, result.Issues[2].Line)
+	assert.Equal(t, "yanked", result.Issues[3].Rule)
+	assert.Equal(t, "smallvec 0.6.9 is yanked", result.Issues[3].Message)
+	assert.Equal(t, 8, result.Issues[3].Line)
+}
+
+func TestCargoAuditTool_ParseOutput(t *testing.T) {
+	tool := NewCargoAuditTool()
+
+	assert.Empty(t, tool.ParseOutput(""))
+	assert.Empty(t, tool.ParseOutput("error: couldn't fetch advisory database"))
+	assert.Empty(t, tool.ParseOutput(`{"vulnerabilities": {"found": false, "count": 0, "list": []}, "warnings": {}}`))
+
+	issues := tool.ParseOutput(`{"vulnerabilities": {"list": [{"advisory": {"id": "RUSTSEC-2021-0003", "title": "t"}, "package": {"name": "smallvec", "version": "1.6.0"}}]}}`)
+	require.Len(t, issues, 1)
+	assert.Equal(t, "Cargo.lock", issues[0].File)
+	assert.Equal(t, 0, issues[0].Line)
+}
+
+func TestCargoDenyTool_BuildCommand(t *testing.T) {
+	tool := NewCargoDenyTool()
+
+	cmd := tool.BuildCommand(nil, ExecuteOptions{ProjectRoot: "/test/project"})
+	assert.Equal(t, []string{"--format", "json", "check"}, cmd.Args[1:])
+	assert.Equal(t, "/test/project", cmd.Dir)
+
+	// The lockfile among the config patterns is not a cargo-deny config
+	cmd = tool.BuildCommand(nil, ExecuteOptions{ConfigFile: "/test/project/Cargo.lock"})
+	assert.Equal(t, []string{"--format", "json", "check"}, cmd.Args[1:])
+
+	tool.SetAdvisoryDB("/srv/advisory-db")
+	cmd = tool.BuildCommand(nil, ExecuteOptions{ConfigFile: "/test/project/deny.toml", ExtraArgs: []string{"licenses"}})
+	assert.Equal(t, []string{"--format", "json", "check", "--config", "/test/project/deny.toml", "--disable-fetch", "licenses"}, cmd.Args[1:])
+}
+
+func TestCargoDenyTool_Execute(t *testing.T) {
+	root := t.TempDir()
+	writeRustProject(t, root, cargoLockFixture)
+	writeRustProject(t, root, map[string]string{".cargo/deny.toml": "[licenses]\nallow = [\"MIT\"]\n"})
+
+	diagnostics := []string{
+		`{"type":"diagnostic","fields":{"severity":"error","code":"vulnerability","message":"Buffer overflow in SmallVec::insert_many","labels":[{"line":12,"column":1,"message":"security vulnerability detected","span":"smallvec 1.6.0"}],"notes":["ID: RUSTSEC-2021-0003","Solution: Upgrade to >=1.6.1"],"advisory":{"id":"RUSTSEC-2021-0003","title":"Buffer overflow in SmallVec::insert_many"},"graphs":[{"Krate":{"name":"smallvec","version":"1.6.0"},"parents":[]}]}}`,
+		`{"type":"diagnostic","fields":{"severity":"error","code":"rejected","message":"failed to satisfy license requirements","labels":[],"notes":["GPL-3.0 - GNU General Public License v3.0 only"],"graphs":[{"Krate":{"name":"time","version":"0.1.43"}}]}}`,
+		`{"type":"diagnostic","fields":{"severity":"warning","code":"license-not-encountered","message":"license was not encountered","labels":[{"line":2,"column":11,"message":"unmatched license allowance","span":"MIT"}],"notes":[]}}`,
+		`{"type":"summary","fields":{"advisories":{"errors":1,"warnings":0},"licenses":{"errors":1,"warnings":1}}}`,
+	}
+	binDir := t.TempDir()
+	// cargo-deny reports on stderr
+	testutil.WriteExecutable(t, binDir, "cargo-deny", "cat >&2 <<'EOF'\n"+strings.Join(diagnostics, "\n")+"\nEOF\nexit 5\n")
+	t.Setenv("PATH", binDir)
+
+	options := ExecuteOptions{ProjectRoot: root, ConfigFile: filepath.Join(root, ".cargo", "deny.toml")}
+	result, err := NewCargoDenyTool().Execute(context.Background(), nil, options)
+	require.NoError(t, err)
+	assert.Equal(t, StatusIssues, result.Status)
+	require.Len(t, result.Issues, 3)
+
+	assert.Equal(t, Issue{
+		File:       "Cargo.lock",
+		Line:       12,
+		Severity:   "error",
+		Rule:       "RUSTSEC-2021-0003",
+		Message:    "smallvec 1.6.0: Buffer overflow in SmallVec::insert_many",
+		Suggestion: "Upgrade to >=1.6.1",
+		Notes:      []string{"ID: RUSTSEC-2021-0003"},
+	}, result.Issues[0])
+
+	assert.Equal(t, "rejected", result.Issues[1].Rule)
+	assert.Equal(t, "Cargo.toml", result.Issues[1].File)
+	assert.Equal(t, 6, result.Issues[1].Line)
+
+	// Configuration findings point at the config file
+	assert.Equal(t, "license-not-encountered", result.Issues[2].Rule)
+	assert.Equal(t, "warning", result.Issues[2].Severity)
+	assert.Equal(t, filepath.Join(".cargo", "deny.toml"), result.Issues[2].File)
+	assert.Equal(t, 2, result.Issues[2].Line)
+	assert.Equal(t, 11, result.Issues[2].Column)
+}
+
+func TestCargoMacheteTool_BuildCommand(t *testing.T) {
+	tool := NewCargoMacheteTool()
+
+	cmd := tool.BuildCommand([]string{"src/main.rs"}, ExecuteOptions{ProjectRoot: "/test/project"})
+	assert.Equal(t, []string{"machete"}, cmd.Args[1:])
+	assert.Equal(t, "/test/project", cmd.Dir)
+
+	cmd = tool.BuildCommand(nil, ExecuteOptions{Fix: true, ExtraArgs: []string{"--with-metadata"}})
+	assert.Equal(t, []string{"machete", "--fix", "--with-metadata"}, cmd.Args[1:])
+}
+
+func TestCargoMacheteTool_Execute(t *testing.T) {
+	root := t.TempDir()
+	writeRustProject(t, root, map[string]string{
+		"Cargo.toml": "[workspace]\nmembers = [\"crates/*\"]\n",
+		"crates/app/Cargo.toml": `[package]
+name = "app"
+
+[dependencies]
+anyhow = "1"
+serde = { version = "1", features = [
+  "derive",
+] }
+
+[dependencies.log]
+version = "0.4"
+`,
+	})
+
+	report := "Analyzing dependencies of crates in this directory...\n" +
+		"cargo-machete found the following unused dependencies in this directory:\n" +
+		"app -- ./crates/app/Cargo.toml:\n" +
+		"\tserde\n" +
+		"\tlog\n" +
+		"\tgone\n" +
+		"\n" +
+		"If you believe cargo-machete has detected an unused dependency incorrectly,\n" +
+		"you can add the dependency to the list of dependencies to ignore.\n" +
+		"Done!\n"
+	binDir := t.TempDir()
+	testutil.WriteExecutable(t, binDir, "cargo-machete", "cat <<'EOF'\n"+report+"EOF\nexit 1\n")
+	t.Setenv("PATH", binDir)
+
+	result, err := NewCargoMacheteTool().Execute(context.Background(), []string{"crates/app/src/lib.rs"}, ExecuteOptions{ProjectRoot: root})
+	require.NoError(t, err)
+	assert.Equal(t, StatusIssues, result.Status)
+	require.Len(t, result.Issues, 3)
+
+	manifest := filepath.Join("crates", "app", "Cargo.toml")
+	assert.Equal(t, Issue{
+		File:       manifest,
+		Line:       6,
+		EndLine:    8,
+		Severity:   "warning",
+		Rule:       "unused-dependency",
+		Message:    "dependency `serde` in [dependencies] is unused",
+		Suggestion: "remove it from Cargo.toml",
+		Edits: []TextEdit{{
+			File: manifest, StartLine: 6, StartColumn: 1, EndLine: 9, EndColumn: 1, Applicability: ApplicabilityUnsafe,
+		}},
+	}, result.Issues[0])
+
+	// [dependencies.log] is removed with its table
+	assert.Equal(t, 10, result.Issues[1].Line)
+	assert.Equal(t, 11, result.Issues[1].EndLine)
+
+	// Dependencies missing from the manifest are reported without a location
+	assert.Equal(t, manifest, result.Issues[2].File)
+	assert.Equal(t, 0, result.Issues[2].Line)
+	assert.Empty(t, result.Issues[2].Edits)
+
+	// The edits remove the declarations
+	content, err := os.ReadFile(filepath.Join(root, manifest))
+	require.NoError(t, err)
+	for _, i := range []int{1, 0} {
+		start, end, err := result.Issues[i].Edits[0].Resolve(content)
+		require.NoError(t, err)
+		content = append(content[:start:start], content[end:]...)
+	}
+	assert.Equal(t, "[package]\nname = \"app\"\n\n[dependencies]\nanyhow = \"1\"\n\n", string(content))
+}
+
+func TestCargoUdepsTool_Execute(t *testing.T) {
+	t.Setenv("RUSTUP_TOOLCHAIN", "")
+	root := t.TempDir()
+	writeRustProject(t, root, map[string]string{
+		"Cargo.toml": "[package]\nname = \"app\"\n\n[dependencies]\nserde = \"1\"\n\n[dev-dependencies]\nserde = \"1\"\ntempfile = \"3\"\n",
+	})
+
+	report := `{"success":false,"unused_deps":{"app 0.1.0 (path+file://` + root + `)":{"manifest_path":"` + filepath.Join(root, "Cargo.toml") + `","normal":[],"development":["serde","tempfile"],"build":[]}},"note":"Note: They might be false-positive."}`
+	binDir := t.TempDir()
+	calls := filepath.Join(t.TempDir(), "calls")
+	testutil.WriteExecutable(t, binDir, "cargo", "exit 1\n")
+	testutil.WriteExecutable(t, binDir, "cargo-udeps", `echo "$RUSTUP_TOOLCHAIN $*" >> `+calls+`
+echo '`+report+`'
+exit 1
+`)
+	t.Setenv("PATH", binDir)
+	tool := NewCargoUdepsTool()
+
+	// Without any nightly toolchain the tool is skipped
+	testutil.WriteExecutable(t, binDir, "rustup", "echo 'stable-x86_64-unknown-linux-gnu (default)'\n")
+	result, err := tool.Execute(context.Background(), nil, ExecuteOptions{ProjectRoot: root})
+	require.NoError(t, err)
+	assert.Equal(t, StatusSkipped, result.Status)
+	assert.NoFileExists(t, calls)
+
+	// An installed nightly is selected for the run, asking rustup once
+	rustupCalls := filepath.Join(t.TempDir(), "rustup")
+	testutil.WriteExecutable(t, binDir, "rustup", "echo list >> "+rustupCalls+"\necho 'stable-x86_64-unknown-linux-gnu (default)'\necho nightly-x86_64-unknown-linux-gnu\n")
+	result, err = tool.Execute(context.Background(), nil, ExecuteOptions{ProjectRoot: root})
+	require.NoError(t, err)
+	assert.Equal(t, StatusIssues, result.Status)
+
+	runs, err := os.ReadFile(calls)
+	require.NoError(t, err)
+	assert.Equal(t, "nightly-x86_64-unknown-linux-gnu udeps --output json --all-targets\n", string(runs))
+	lists, err := os.ReadFile(rustupCalls)
+	require.NoError(t, err)
+	assert.Equal(t, "list\n", string(lists))
+
+	require.Len(t, result.Issues, 2)
+	assert.Equal(t, "Cargo.toml", result.Issues[0].File)
+	assert.Equal(t, "dependency `serde` in [dev-dependencies] is unused", result.Issues[0].Message)
+	assert.Equal(t, 8, result.Issues[0].Line)
+	assert.Equal(t, 9, result.Issues[1].Line)
+}
+
+func TestCargoUdepsTool_BuildCommand_Toolchain(t *testing.T) {
+	t.Setenv("RUSTUP_TOOLCHAIN", "")
+	root := t.TempDir()
+	writeRustProject(t, root, map[string]string{"Cargo.toml": "[package]\nname = \"app\"\n"})
+	binDir := t.TempDir()
+	testutil.WriteExecutable(t, binDir, "cargo", "exit 1\n")
+	testutil.WriteExecutable(t, binDir, "rustup", "echo nightly-x86_64-unknown-linux-gnu\n")
+	t.Setenv("PATH", binDir)
+	tool := NewCargoUdepsTool()
+
+	// The installed nightly overrides a stable toolchain from options.Env
+	cmd := tool.BuildCommand(nil, ExecuteOptions{ProjectRoot: root, Env: map[string]string{"RUSTUP_TOOLCHAIN": "stable", "CARGO_TARGET_DIR": "target/udeps"}})
+	assert.Equal(t, "nightly-x86_64-unknown-linux-gnu", cmdEnv(cmd, "RUSTUP_TOOLCHAIN"))
+	assert.Equal(t, "target/udeps", cmdEnv(cmd, "CARGO_TARGET_DIR"))
+
+	// A nightly from options.Env is kept, even when the process selects stable
+	t.Setenv("RUSTUP_TOOLCHAIN", "stable")
+	cmd = tool.BuildCommand(nil, ExecuteOptions{ProjectRoot: root, Env: map[string]string{"RUSTUP_TOOLCHAIN": "nightly-2024-06-01"}})
+	assert.Equal(t, "nightly-2024-06-01", cmdEnv(cmd, "RUSTUP_TOOLCHAIN"))
+
+	// Without options.Env the environment is inherited unless a nightly is needed
+	t.Setenv("RUSTUP_TOOLCHAIN", "nightly")
+	assert.Nil(t, tool.BuildCommand(nil, ExecuteOptions{ProjectRoot: root}).Env)
+}
+
+func TestCargoCheckTool_BuildCommand(t *testing.T) {
+	tool := NewCargoCheckTool()
+	assert.Equal(t, "cargo-check", tool.Name())
+	assert.Equal(t, LINT, tool.Type())
+	assert.Equal(t, []string{"clippy"}, tool.SupersededBy())
+
+	root := t.TempDir()
+	writeCargoWorkspace(t, root, map[string]string{"crates/core": "app-core", "crates/cli": "app-cli"})
+
+	cmd := tool.BuildCommand([]string{filepath.Join(root, "crates", "core", "src", "lib.rs")}, ExecuteOptions{ProjectRoot: root, ExtraArgs: []string{"--locked"}})
+	assert.Equal(t, []string{"check", "-p", "app-core", "--message-format", "json", "--all-targets", "--locked"}, cmd.Args[1:])
+	assert.Equal(t, root, cmd.Dir)
+}
+
+func TestCargoCheckTool_Execute(t *testing.T) {
+	binDir := t.TempDir()
+	diagnostic := `{"reason":"compiler-message","message":{"rendered":"error[E0308]: mismatched types","message":"mismatched types","code":{"code":"E0308"},"level":"error","spans":[{"file_name":"src/main.rs","line_start":4,"line_end":4,"column_start":18,"column_end":23,"is_primary":true}],"children":[{"message":"expected ` + "`u32`, found `&str`" + `","level":"note","spans":[],"children":[]}]}}`
+	testutil.WriteExecutable(t, binDir, "cargo", `[ "$1" = metadata ] && exit 1
+echo '{"reason":"compiler-artifact","package_id":"app 0.1.0"}'
+echo '`+diagnostic+`'
+echo '{"reason":"build-finished","success":false}'
+exit 101
+`)
+	t.Setenv("PATH", binDir)
+
+	result, err := NewCargoCheckTool().Execute(context.Background(), nil, ExecuteOptions{ProjectRoot: t.TempDir()})
+	require.NoError(t, err)
+	assert.Equal(t, StatusIssues, result.Status)
+	require.Len(t, result.Issues, 1)
+
+	issue := result.Issues[0]
+	assert.Equal(t, filepath.Join("src", "main.rs"), issue.File)
+	assert.Equal(t, 4, issue.Line)
+	assert.Equal(t, 18, issue.Column)
+	assert.Equal(t, "error", issue.Severity)
+	assert.Equal(t, "E0308", issue.Rule)
+	assert.Equal(t, []string{"note: expected `u32`, found `&str`"}, issue.Notes)
+}
+
+// cmdEnv returns the value of key a command runs with; the last entry wins.
+func cmdEnv(cmd *exec.Cmd, key string) string {
+	value := ""
+	for _, env := range cmd.Env {
+		if v, ok := strings.CutPrefix(env, key+"="); ok {
+			value = v
+		}
+	}
+	return value
+}
+
+func TestRustdocTool_BuildCommand(t *testing.T) {
+	t.Setenv("RUSTDOCFLAGS", "--cfg docsrs")
+	root := t.TempDir()
+	writeCargoWorkspace(t, root, map[string]string{"crates/core": "app-core", "crates/cli": "app-cli"})
+	files := []string{filepath.Join(root, "crates", "cli", "src", "lib.rs")}
+
+	tool := NewRustdocTool()
+	cmd := tool.BuildCommand(files, ExecuteOptions{ProjectRoot: root})
+	assert.Equal(t, []string{"doc", "--no-deps", "-p", "app-cli", "--message-format", "json"}, cmd.Args[1:])
+	assert.Equal(t, "--cfg docsrs -D rustdoc::broken_intra_doc_links", cmdEnv(cmd, "RUSTDOCFLAGS"))
+
+	tool.SetMissingDocs(true)
+	cmd = tool.BuildCommand(files, ExecuteOptions{ProjectRoot: root})
+	assert.Equal(t, "--cfg docsrs -D rustdoc::broken_intra_doc_links -D missing_docs", cmdEnv(cmd, "RUSTDOCFLAGS"))
+
+	// RUSTDOCFLAGS from options.Env replaces the process's and is extended likewise
+	cmd = tool.BuildCommand(files, ExecuteOptions{ProjectRoot: root, Env: map[string]string{"RUSTDOCFLAGS": "--cfg ci", "CARGO_TARGET_DIR": "target/doc-ci"}})
+	assert.Equal(t, "--cfg ci -D rustdoc::broken_intra_doc_links -D missing_docs", cmdEnv(cmd, "RUSTDOCFLAGS"))
+	assert.Equal(t, "target/doc-ci", cmdEnv(cmd, "CARGO_TARGET_DIR"))
+
+	// Lints configured by the [lints] table are left to it
+	member := filepath.Join(root, "crates", "cli")
+	require.NoError(t, os.WriteFile(filepath.Join(member, "Cargo.toml"), []byte(`[package]
+name = "app-cli"
+
+[lints.rust]
+missing_docs = "warn"
+
+[lints.rustdoc]
+broken_intra_doc_links = "allow"
+`), 0o644))
+	cmd = tool.BuildCommand(nil, ExecuteOptions{ProjectRoot: member})
+	assert.Nil(t, cmd.Env)
+}
+
+func TestRustdocTool_Execute(t *testing.T) {
+	t.Setenv("RUSTDOCFLAGS", "")
+	binDir := t.TempDir()
+	calls := filepath.Join(t.TempDir(), "calls")
+	diagnostic := `{"reason":"compiler-message","package_id":"app 0.1.0","message":{"message":"unresolved link to ` + "`Parser`" + `","code":{"code":"rustdoc::broken_intra_doc_links"},"level":"error","spans":[{"file_name":"src/lib.rs","line_start":3,"line_end":3,"column_start":14,"column_end":22,"is_primary":true}],"children":[{"message":"no item named ` + "`Parser`" + ` in scope","level":"note","spans":[],"children":[]}]}}`
+	testutil.WriteExecutable(t, binDir, "cargo", `[ "$1" = metadata ] && exit 1
+echo "$RUSTDOCFLAGS $*" >> `+calls+`
+echo '`+diagnostic+`'
+exit 101
+`)
+	t.Setenv("PATH", binDir)
+
+	result, err := NewRustdocTool().Execute(context.Background(), nil, ExecuteOptions{ProjectRoot: t.TempDir()})
+	require.NoError(t, err)
+
+	runs, err := os.ReadFile(calls)
+	require.NoError(t, err)
+	assert.Equal(t, "-D rustdoc::broken_intra_doc_links doc --no-deps --message-format json\n", string(runs))
+
+	assert.Equal(t, StatusIssues, result.Status)
+	require.Len(t, result.Issues, 1)
+	assert.Equal(t, "rustdoc::broken_intra_doc_links", result.Issues[0].Rule)
+	assert.Equal(t, filepath.Join("src", "lib.rs"), result.Issues[0].File)
+	assert.Equal(t, 3, result.Issues[0].Line)
+	assert.Equal(t, []string{"note: no item named `Parser` in scope"}, result.Issues[0].Notes)
+}
+
+// commitRustProject commits everything in root to a new git repository.
+func commitRustProject(t *testing.T, root string) {
+	t.Helper()
+	for _, args := range [][]string{
+		{"init", "--quiet"},
+		{"add", "-A"},
+		{"-c", "user.name=Test User", "-c", "user.email=test@example.com", "commit", "--quiet", "-m", "baseline"},
+	} {
+		cmd := exec.Command("git", args...)
+		cmd.Dir = root
+		output, err := cmd.CombinedOutput()
+		require.NoError(t, err, string(output))
+	}
+}
+
+func TestCargoUnusedDependencyTools_ProjectInputs(t *testing.T) {
+	root := resolvePath(t.TempDir())
+	writeRustProject(t, root, map[string]string{
+		"Cargo.toml": "[package]\nname = \"app\"\n",
+		"Cargo.lock": "version = 3\n",
+	})
+	files := []string{filepath.Join(root, "src", "lib.rs")}
+
+	// Unused dependencies are reported against Cargo.toml, which must key the cache
+	for _, tool := range []QualityTool{NewCargoMacheteTool(), NewCargoUdepsTool()} {
+		scoped, ok := tool.(ProjectScoped)
+		require.True(t, ok, tool.Name())
+		assert.Equal(t, []string{filepath.Join(root, "Cargo.lock"), filepath.Join(root, "Cargo.toml")},
+			scoped.ProjectInputs(files, root), tool.Name())
+	}
+}
+
+func TestCargoSemverChecksTool_ProjectInputs(t *testing.T) {
+	root := resolvePath(t.TempDir())
+	writeRustProject(t, root, map[string]string{
+		"Cargo.toml":    "[package]\nname = \"lib\"\n",
+		"Cargo.lock":    "version = 3\n",
+		"src/lib.rs":    "pub mod api;\n",
+		"src/api.rs":    "pub fn f() {}\n",
+		"src/README.md": "docs\n",
+		"tests/it.rs":   "\n",
+	})
+
+	// A break may surface in api.rs when only lib.rs changed
+	inputs := NewCargoSemverChecksTool().ProjectInputs([]string{filepath.Join(root, "src", "lib.rs")}, root)
+	assert.Equal(t, []string{
+		filepath.Join(root, "Cargo.lock"),
+		filepath.Join(root, "Cargo.toml"),
+		filepath.Join(root, "src", "api.rs"),
+		filepath.Join(root, "src", "lib.rs"),
+	}, inputs)
+}
+
+func TestCargoSemverChecksTool_BuildCommand(t *testing.T) {
+	root := t.TempDir()
+	writeRustProject(t, root, map[string]string{
+		"Cargo.toml":             "[workspace]\nmembers = [\"crates/*\"]\n",
+		"crates/core/Cargo.toml": "[package]\nname = \"app-core\"\n",
+		"crates/core/src/lib.rs": "pub fn parse() {}\n",
+		"crates/cli/Cargo.toml":  "[package]\nname = \"app-cli\"\n",
+		"crates/cli/src/main.rs": "fn main() {}\n",
+		"crates/ffi/Cargo.toml":  "[package]\nname = \"app-ffi\"\n\n[lib]\npath = \"ffi.rs\"\n",
+		"crates/ffi/ffi.rs":      "pub fn call() {}\n",
+	})
+	tool := NewCargoSemverChecksTool()
+	assert.Equal(t, "cargo-semver-checks", tool.Name())
+	assert.Equal(t, LINT, tool.Type())
+
+	// Binary crates have no public API and are left out
+	files := []string{"crates/core/src/lib.rs", "crates/cli/src/main.rs", "crates/ffi/ffi.rs"}
+	cmd := tool.BuildCommand(files, ExecuteOptions{ProjectRoot: root, BaseRef: "v1.2.0"})
+	assert.Equal(t, []string{"semver-checks", "--baseline-rev", "v1.2.0", "--package", "app-core", "--package", "app-ffi"}, cmd.Args[1:])
+	assert.Equal(t, root, cmd.Dir)
+}
+
+func TestCargoSemverChecksTool_Execute(t *testing.T) {
+	gitPath, err := exec.LookPath("git")
+	require.NoError(t, err)
+
+	root := t.TempDir()
+	writeRustProject(t, root, map[string]string{
+		"Cargo.toml": "[package]\nname = \"demo\"\nversion = \"0.1.0\"\n",
+		"src/lib.rs": "pub fn foo() {}\n\npub fn bar() {}\n",
+	})
+	commitRustProject(t, root)
+	writeRustProject(t, root, map[string]string{
+		"Cargo.toml": "[package]\nname = \"demo\"\nversion = \"0.1.1\"\n",
+		"src/lib.rs": "pub fn bar(x: u32) {}\n",
+	})
+	files := []string{filepath.Join(root, "src", "lib.rs")}
+
+	binDir := t.TempDir()
+	calls := filepath.Join(t.TempDir(), "calls")
+	testutil.WriteExecutable(t, binDir, "git", `exec `+gitPath+` "$@"
+`)
+	// The stub compares against the checked out baseline like the real tool
+	testutil.WriteExecutable(t, binDir, "cargo-semver-checks", `echo "$*" >> `+calls+`
+while [ $# -gt 0 ]; do
+	[ "$1" = --baseline-root ] && baseline="$2"
+	shift
+done
+grep -q "pub fn foo" "$baseline/src/lib.rs" || exit 2
+cat >&2 <<EOF
+    Checking demo v0.1.0 -> v0.1.1 (patch change)
+     Checked [   0.010s] 3 checks: 1 pass, 2 fail, 0 warn, 0 skip
+
+--- failure function_missing: pub fn removed or renamed ---
+
+Description:
+A publicly-visible function cannot be imported by its prior path.
+        ref: https://doc.rust-lang.org/cargo/reference/semver.html#item-remove
+       impl: https://github.com/obi1kenobi/cargo-semver-checks/tree/v0.36.0/src/lints/function_missing.ron
+
+Failed in:
+  function demo::foo, previously in file $baseline/src/lib.rs:1
+
+--- failure function_parameter_count_changed: pub fn parameter count changed ---
+
+Description:
+A publicly-visible function now takes a different number of parameters.
+
+Failed in:
+  demo::bar now takes 1 parameters instead of 0, in $PWD/src/lib.rs:1
+
+     Summary semver requires new major version: 2 major and 0 minor checks failed
+EOF
+exit 1
+`)
+	t.Setenv("PATH", binDir)
+	tool := NewCargoSemverChecksTool()
+
+	// Without a base commit there is nothing to compare against
+	result, err := tool.Execute(context.Background(), files, ExecuteOptions{ProjectRoot: root})
+	require.NoError(t, err)
+	assert.Equal(t, StatusSkipped, result.Status)
+	assert.NoFileExists(t, calls)
+
+	result, err = tool.Execute(context.Background(), files, ExecuteOptions{ProjectRoot: root, BaseRef: "HEAD"})
+	require.NoError(t, err)
+	assert.Equal(t, StatusIssues, result.Status, result.Output)
+
+	runs, err := os.ReadFile(calls)
+	require.NoError(t, err)
+	assert.True(t, strings.HasPrefix(string(runs), "semver-checks --baseline-root "), string(runs))
+	assert.True(t, strings.HasSuffix(string(runs), " --package demo\n"), string(runs))
+
+	require.Len(t, result.Issues, 2)
+	assert.Equal(t, Issue{
+		File:     filepath.Join("src", "lib.rs"),
+		Line:     1,
+		Severity: "error",
+		Rule:     "function_missing",
+		Message:  "pub fn removed or renamed: function demo::foo",
+		Notes: []string{
+			"A publicly-visible function cannot be imported by its prior path.",
+			"see https://doc.rust-lang.org/cargo/reference/semver.html#item-remove",
+		},
+	}, result.Issues[0])
+	assert.Equal(t, "function_parameter_count_changed", result.Issues[1].Rule)
+	assert.Equal(t, "pub fn parameter count changed: demo::bar now takes 1 parameters instead of 0", result.Issues[1].Message)
+	assert.Equal(t, filepath.Join("src", "lib.rs"), result.Issues[1].File)
+
+	// The baseline worktree is removed again
+	cmd := exec.Command(gitPath, "worktree", "list", "--porcelain")
+	cmd.Dir = root
+	output, err := cmd.Output()
+	require.NoError(t, err)
+	assert.Equal(t, 1, strings.Count(string(output), "worktree "))
+}
+
+func TestCargoSemverChecksTool_Execute_BinaryCrate(t *testing.T) {
+	root := t.TempDir()
+	writeRustProject(t, root, map[string]string{
+		"Cargo.toml":  "[package]\nname = \"app\"\n",
+		"src/main.rs": "fn main() {}\n",
+	})
+
+	result, err := NewCargoSemverChecksTool().Execute(context.Background(), []string{"src/main.rs"}, ExecuteOptions{ProjectRoot: root, BaseRef: "HEAD"})
+	require.NoError(t, err)
+	assert.Equal(t, StatusSkipped, result.Status)
+	assert.Equal(t, "no library crate changed", result.Error)
+}
+
+func TestCargoSemverChecksTool_ParseOutput(t *testing.T) {
+	output := `--- warning trait_method_marked_deprecated: trait method #[deprecated] added ---
+
+Description:
+A trait method is now #[deprecated].
+
+Failed in:
+  method parse in trait demo::Parser in file src/lib.rs:12
+  trait demo::Visitor gained a new method
+`
+	issues := NewCargoSemverChecksTool().ParseOutput(output)
+
+	require.Len(t, issues, 2)
+	assert.Equal(t, "warning", issues[0].Severity)
+	assert.Equal(t, "src/lib.rs", issues[0].File)
+	assert.Equal(t, 12, issues[0].Line)
+	assert.Equal(t, "trait method #[deprecated] added: method parse in trait demo::Parser", issues[0].Message)
+
+	// Items without a location point at the manifest
+	assert.Equal(t, "Cargo.toml", issues[1].File)
+	assert.Equal(t, 0, issues[1].Line)
+	assert.Equal(t, "trait_method_marked_deprecated", issues[1].Rule)
+}
@@ -20,7 +20,14 @@ func NewShellcheckTool() *ShellcheckTool {
 		BaseTool: NewBaseTool("shellcheck", "Shell", "shellcheck", LINT),
 	}
 
-	tool.SetInstallCommand([]string{"pacman", "-S", "--noconfirm", "shellcheck"})
+	tool.Bind(tool)
+	// shellcheck exits 1 on issues (2-4 are errors)
+	tool.SetFindingExitCodes(1)
+	tool.AddPackage(PackageApt, "shellcheck")
+	tool.AddPackage(PackageDnf, "ShellCheck")
+	tool.AddPackage(PackagePacman, "shellcheck")
+	tool.AddPackage(PackageBrew, "shellcheck")
+	tool.AddPackage(PackagePip, "shellcheck-py")
 	tool.SetConfigPatterns([]string{".shellcheckrc"})
 
 	return tool
@@ -119,7 +126,10 @@ func NewShfmtTool() *ShfmtTool {
 		BaseTool: NewBaseTool("shfmt", "Shell", "shfmt", FORMAT),
 	}
 
-	tool.SetInstallCommand([]string{"go", "install", "mvdan.cc/sh/v3/cmd/shfmt@latest"})
+	tool.Bind(tool)
+	// shfmt -d exits 1 when files need formatting
+	tool.SetFindingExitCodes(1)
+	tool.AddPackage(PackageGo, "mvdan.cc/sh/v3/cmd/shfmt")
 	tool.SetConfigPatterns([]string{".editorconfig"})
 
 	return tool
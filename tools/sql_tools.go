@@ -20,7 +20,10 @@ func NewSqlfluffTool() *SqlfluffTool {
 		BaseTool: NewBaseTool("sqlfluff", "SQL", "sqlfluff", BOTH),
 	}
 
-	tool.SetInstallCommand([]string{"uv", "tool", "install", "sqlfluff"})
+	tool.Bind(tool)
+	// sqlfluff exits 1 on violations (2 is an error)
+	tool.SetFindingExitCodes(1)
+	tool.AddPackage(PackagePip, "sqlfluff")
 	tool.SetConfigPatterns([]string{".sqlfluff", "setup.cfg", "pyproject.toml"})
 
 	return tool
@@ -21,7 +21,10 @@ func NewTaploTool() *TaploTool {
 		BaseTool: NewBaseTool("taplo", "TOML", "taplo", BOTH),
 	}
 
-	tool.SetInstallCommand([]string{"cargo", "install", "taplo-cli"})
+	tool.Bind(tool)
+	// taplo exits 1 on lint errors or unformatted files
+	tool.SetFindingExitCodes(1)
+	tool.AddPackage(PackageCargo, "taplo-cli")
 	tool.SetConfigPatterns([]string{"taplo.toml", ".taplo.toml"})
 
 	return tool
@@ -21,7 +21,10 @@ func NewYamllintTool() *YamllintTool {
 		BaseTool: NewBaseTool("yamllint", "YAML", "yamllint", LINT),
 	}
 
-	tool.SetInstallCommand([]string{"uv", "tool", "install", "yamllint"})
+	tool.Bind(tool)
+	// yamllint exits 1 on errors and 2 on warnings in strict mode
+	tool.SetFindingExitCodes(1, 2)
+	tool.AddPackage(PackagePip, "yamllint")
 	tool.SetConfigPatterns([]string{".yamllint", ".yamllint.yaml", ".yamllint.yml"})
 
 	return tool